@@ -45,6 +45,26 @@ fn encode_hyphen(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn encode_hyphen_upper(b: &mut Bencher) {
+    let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+    b.iter(|| {
+        let mut buffer = [0_u8; 36];
+        uuid.hyphenated().encode_upper(&mut buffer);
+        buffer
+    });
+}
+
+#[bench]
+fn encode_hyphen_array(b: &mut Bencher) {
+    let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+    b.iter(|| {
+        let mut buffer = [0_u8; 36];
+        uuid.hyphenated().encode_lower_array(&mut buffer);
+        buffer
+    });
+}
+
 #[bench]
 fn encode_simple(b: &mut Bencher) {
     let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
@@ -55,6 +75,26 @@ fn encode_simple(b: &mut Bencher) {
     })
 }
 
+#[bench]
+fn encode_simple_upper(b: &mut Bencher) {
+    let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+    b.iter(|| {
+        let mut buffer = [0_u8; 32];
+        uuid.simple().encode_upper(&mut buffer);
+        buffer
+    })
+}
+
+#[bench]
+fn encode_simple_array(b: &mut Bencher) {
+    let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+    b.iter(|| {
+        let mut buffer = [0_u8; 32];
+        uuid.simple().encode_lower_array(&mut buffer);
+        buffer
+    })
+}
+
 #[bench]
 fn encode_urn(b: &mut Bencher) {
     let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
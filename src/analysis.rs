@@ -0,0 +1,709 @@
+//! Reporting on the makeup of a set of UUIDs.
+//!
+//! This module is useful for auditing UUIDs that were generated somewhere
+//! else, such as an ID column exported from another system, to see what
+//! versions they are, what time range they cover, and whether their
+//! random bits look random.
+//!
+//! For time-based UUIDs, a [`Timeline`] can also reconstruct the order
+//! they were generated in, which is useful when investigating incidents
+//! from tables of stored IDs.
+//!
+//! Note that you need to enable the `analysis` Cargo feature in order to
+//! use this module.
+
+use crate::{
+    std::{collections::HashMap, iter::FromIterator, time::Duration, vec::Vec},
+    timestamp::Timestamp,
+    Uuid, Variant,
+};
+
+// The bits of a version 4 UUID that aren't its version or variant
+const RANDOM_V4: u128 = !0x0000_0000_0000_f000_c000_0000_0000_0000;
+// The bits of a version 7 UUID that aren't its timestamp, version, or variant
+const RANDOM_V7: u128 = RANDOM_V4 & !(0xffff_ffff_ffff << 80);
+
+/// A report on a set of UUIDs.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, Variant, analysis::Report};
+/// let uuids = [
+///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+///     Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?,
+/// ];
+///
+/// let report = Report::analyze(uuids);
+///
+/// assert_eq!(2, report.count());
+/// assert_eq!(1, report.version_count(4));
+/// assert_eq!(2, report.variant_count(Variant::RFC4122));
+/// assert!(report.timestamp_range().is_some());
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Report {
+    count: usize,
+    versions: [usize; 16],
+    variants: [usize; 4],
+    first: Option<Timestamp>,
+    last: Option<Timestamp>,
+    previous: Option<Timestamp>,
+    monotonicity_violations: usize,
+    random_samples: [u64; 128],
+    random_ones: [u64; 128],
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Report {
+    /// Create an empty report.
+    pub const fn new() -> Self {
+        Report {
+            count: 0,
+            versions: [0; 16],
+            variants: [0; 4],
+            first: None,
+            last: None,
+            previous: None,
+            monotonicity_violations: 0,
+            random_samples: [0; 128],
+            random_ones: [0; 128],
+        }
+    }
+
+    /// Create a report on a set of UUIDs.
+    ///
+    /// UUIDs are expected in the order they were generated, which is used
+    /// to check whether their timestamps are monotonic.
+    pub fn analyze(uuids: impl IntoIterator<Item = Uuid>) -> Self {
+        let mut report = Report::new();
+        report.extend(uuids);
+        report
+    }
+
+    /// Add a UUID to the report.
+    pub fn add(&mut self, uuid: Uuid) {
+        self.count += 1;
+        self.versions[uuid.get_version_num()] += 1;
+        self.variants[uuid.get_variant() as usize] += 1;
+
+        if let Some(ts) = timestamp(&uuid) {
+            let key = ts.to_unix();
+
+            match (self.first, self.last, self.previous) {
+                (Some(first), Some(last), Some(previous)) => {
+                    if key < first.to_unix() {
+                        self.first = Some(ts);
+                    }
+                    if key > last.to_unix() {
+                        self.last = Some(ts);
+                    }
+                    if key < previous.to_unix() {
+                        self.monotonicity_violations += 1;
+                    }
+                }
+                _ => {
+                    self.first = Some(ts);
+                    self.last = Some(ts);
+                }
+            }
+
+            self.previous = Some(ts);
+        }
+
+        let mask = match (uuid.get_version_num(), uuid.get_variant()) {
+            (4, Variant::RFC4122) => RANDOM_V4,
+            (7, Variant::RFC4122) => RANDOM_V7,
+            _ => return,
+        };
+
+        let value = uuid.as_u128();
+        for bit in 0..128 {
+            if mask & (1 << bit) != 0 {
+                self.random_samples[bit] += 1;
+                self.random_ones[bit] += (value >> bit) as u64 & 1;
+            }
+        }
+    }
+
+    /// The number of UUIDs in the report.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The number of UUIDs with the given version number.
+    ///
+    /// This is the raw value of the version field, so it also counts
+    /// UUIDs that aren't of the RFC4122 variant.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `version` is greater than 15.
+    pub const fn version_count(&self, version: usize) -> usize {
+        self.versions[version]
+    }
+
+    /// The number of UUIDs with the given variant.
+    pub const fn variant_count(&self, variant: Variant) -> usize {
+        self.variants[variant as usize]
+    }
+
+    /// The earliest and latest timestamps of any UUIDs in the report.
+    ///
+    /// This is `None` if none of the UUIDs carry a timestamp. Without
+    /// `uuid_unstable`, only version 1 UUIDs are considered to.
+    pub fn timestamp_range(&self) -> Option<(Timestamp, Timestamp)> {
+        Some((self.first?, self.last?))
+    }
+
+    /// The number of times a UUID's timestamp was earlier than the
+    /// timestamp of the UUID before it.
+    ///
+    /// UUIDs without a timestamp are skipped over.
+    pub const fn monotonicity_violations(&self) -> usize {
+        self.monotonicity_violations
+    }
+
+    /// A rough estimate of the entropy in the random bits of version 4
+    /// and 7 UUIDs, as a fraction between `0.0` and `1.0`.
+    ///
+    /// This is the average Shannon entropy of each random bit, treating
+    /// each bit independently. Values close to `1.0` mean each bit is set
+    /// about half the time, which is necessary but not sufficient for the
+    /// UUIDs to be random. A small number of UUIDs will give a noisy
+    /// estimate.
+    ///
+    /// This is `None` if there aren't any version 4 or 7 UUIDs in the report.
+    pub fn random_entropy(&self) -> Option<f64> {
+        let mut total = 0.0;
+        let mut bits = 0;
+
+        for (samples, ones) in self.random_samples.iter().zip(self.random_ones.iter()) {
+            if *samples == 0 {
+                continue;
+            }
+
+            let p = *ones as f64 / *samples as f64;
+            total += entropy(p) + entropy(1.0 - p);
+            bits += 1;
+        }
+
+        if bits == 0 {
+            None
+        } else {
+            Some(total / bits as f64)
+        }
+    }
+}
+
+impl Extend<Uuid> for Report {
+    fn extend<I: IntoIterator<Item = Uuid>>(&mut self, iter: I) {
+        for uuid in iter {
+            self.add(uuid);
+        }
+    }
+}
+
+impl FromIterator<Uuid> for Report {
+    fn from_iter<I: IntoIterator<Item = Uuid>>(iter: I) -> Self {
+        Self::analyze(iter)
+    }
+}
+
+/// The reconstructed generation order of a set of time-based UUIDs.
+///
+/// UUIDs are ordered by their timestamp, then by their counter. For
+/// version 1 and 6 UUIDs the counter is the clock sequence, and for
+/// version 7 UUIDs it's the 12 bits following the timestamp, which
+/// generators that follow RFC 9562's fixed-length counter method use
+/// for a counter.
+///
+/// Without `uuid_unstable`, only version 1 UUIDs are considered to have
+/// a timestamp. UUIDs without a timestamp are left out of the timeline.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, analysis::Timeline};
+/// let uuids = [
+///     Uuid::parse_str("20616935-4ba2-11e7-8001-010203040506")?,
+///     Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?,
+///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+/// ];
+///
+/// let timeline = Timeline::reconstruct(uuids);
+///
+/// // The UUIDs in the order they were generated in
+/// let order = timeline.entries().iter().map(|entry| entry.index).collect::<Vec<_>>();
+/// assert_eq!(vec![1, 0], order);
+///
+/// // The version 4 UUID doesn't have a timestamp
+/// assert_eq!(&[2], timeline.skipped());
+///
+/// // Its timestamp went backwards
+/// assert_eq!(1, timeline.regressions().len());
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    entries: Vec<Entry>,
+    skipped: Vec<usize>,
+    runs: usize,
+}
+
+/// A UUID in a [`Timeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Entry {
+    /// The UUID.
+    pub uuid: Uuid,
+    /// The position of the UUID in the input.
+    pub index: usize,
+    /// The timestamp of the UUID.
+    pub timestamp: Timestamp,
+    /// The counter of the UUID.
+    pub counter: u16,
+    /// The run of consecutive counters the UUID belongs to.
+    ///
+    /// See [`Timeline::runs`] for details.
+    pub run: usize,
+}
+
+/// A period of time between two entries in a [`Timeline`] with no UUIDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Gap {
+    /// The position in [`Timeline::entries`] of the entry before the gap.
+    pub before: usize,
+    /// The position in [`Timeline::entries`] of the entry after the gap.
+    pub after: usize,
+    /// The length of the gap.
+    pub duration: Duration,
+}
+
+/// A UUID whose timestamp went backwards from the UUID before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Regression {
+    /// The position in the input of the UUID before the regression.
+    pub previous: usize,
+    /// The position in the input of the UUID whose timestamp went backwards.
+    pub index: usize,
+    /// How far the timestamp went backwards.
+    pub duration: Duration,
+}
+
+impl Timeline {
+    /// Reconstruct the generation order of a set of UUIDs.
+    ///
+    /// UUIDs are expected in the order they were stored, which is used to
+    /// find [`regressions`](Timeline::regressions).
+    pub fn reconstruct(uuids: impl IntoIterator<Item = Uuid>) -> Self {
+        let mut entries = Vec::new();
+        let mut skipped = Vec::new();
+
+        for (index, uuid) in uuids.into_iter().enumerate() {
+            match timestamp(&uuid) {
+                Some(timestamp) => entries.push(Entry {
+                    uuid,
+                    index,
+                    timestamp,
+                    counter: counter(&uuid).0,
+                    run: 0,
+                }),
+                None => skipped.push(index),
+            }
+        }
+
+        entries.sort_by_key(|entry| (entry.timestamp.to_unix(), entry.counter, entry.uuid));
+
+        // Link each entry onto a run that expects its counter next
+        let mut expecting = HashMap::new();
+        let mut runs = 0;
+
+        for entry in &mut entries {
+            let node = node(&entry.uuid);
+            let (counter, width) = counter(&entry.uuid);
+
+            entry.run = match expecting.remove(&(node, counter)) {
+                Some(run) => run,
+                None => {
+                    runs += 1;
+                    runs - 1
+                }
+            };
+
+            expecting.insert((node, (counter + 1) % width), entry.run);
+        }
+
+        Timeline {
+            entries,
+            skipped,
+            runs,
+        }
+    }
+
+    /// The UUIDs with a timestamp, in the order they were generated.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// The positions in the input of UUIDs without a timestamp.
+    pub fn skipped(&self) -> &[usize] {
+        &self.skipped
+    }
+
+    /// The number of runs of consecutive counters.
+    ///
+    /// A run is a sequence of UUIDs with counters that increase by one,
+    /// and for version 1 and 6 UUIDs, the same node ID. UUIDs in the same
+    /// run were most likely made by the same generator, but a generator
+    /// that reseeds its counter, such as a version 7 generator moving to a
+    /// new millisecond, will start a new run.
+    pub fn runs(&self) -> usize {
+        self.runs
+    }
+
+    /// Get the entries in a run, in the order they were generated.
+    pub fn run(&self, run: usize) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| entry.run == run)
+    }
+
+    /// Find periods of at least `min` without any UUIDs.
+    pub fn gaps(&self, min: Duration) -> Vec<Gap> {
+        self.entries
+            .windows(2)
+            .enumerate()
+            .filter_map(|(before, pair)| {
+                let duration = between(&pair[0].timestamp, &pair[1].timestamp)?;
+
+                if duration >= min {
+                    Some(Gap {
+                        before,
+                        after: before + 1,
+                        duration,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Find UUIDs in the input whose timestamp is earlier than the UUID
+    /// stored before them.
+    ///
+    /// For version 1 and 6 UUIDs, only UUIDs with the same node ID are
+    /// compared, since clocks on different nodes can disagree.
+    pub fn regressions(&self) -> Vec<Regression> {
+        let mut by_index = self.entries.iter().collect::<Vec<_>>();
+        by_index.sort_by_key(|entry| entry.index);
+
+        let mut previous = HashMap::new();
+        let mut regressions = Vec::new();
+
+        for entry in by_index {
+            if let Some(prev) = previous.insert(node(&entry.uuid), entry) {
+                if let Some(duration) = between(&entry.timestamp, &prev.timestamp) {
+                    if duration > Duration::from_secs(0) {
+                        regressions.push(Regression {
+                            previous: prev.index,
+                            index: entry.index,
+                            duration,
+                        });
+                    }
+                }
+            }
+        }
+
+        regressions
+    }
+}
+
+// The time from `a` to `b`, if `b` isn't before `a`
+fn between(a: &Timestamp, b: &Timestamp) -> Option<Duration> {
+    let (a_secs, a_nanos) = a.to_unix();
+    let (b_secs, b_nanos) = b.to_unix();
+
+    Duration::new(b_secs, b_nanos).checked_sub(Duration::new(a_secs, a_nanos))
+}
+
+fn node(uuid: &Uuid) -> Option<[u8; 6]> {
+    match uuid.get_version_num() {
+        1 | 6 => {
+            let mut node = [0; 6];
+            node.copy_from_slice(&uuid.as_bytes()[10..]);
+            Some(node)
+        }
+        _ => None,
+    }
+}
+
+// The counter and the number of values it can hold
+fn counter(uuid: &Uuid) -> (u16, u16) {
+    let bytes = uuid.as_bytes();
+
+    match uuid.get_version_num() {
+        7 => (((bytes[6] & 0x0f) as u16) << 8 | bytes[7] as u16, 1 << 12),
+        _ => (((bytes[8] & 0x3f) as u16) << 8 | bytes[9] as u16, 1 << 14),
+    }
+}
+
+fn timestamp(uuid: &Uuid) -> Option<Timestamp> {
+    // Only RFC4122 UUIDs have a meaningful timestamp
+    if uuid.get_variant() == Variant::RFC4122 {
+        uuid.get_timestamp()
+    } else {
+        None
+    }
+}
+
+fn entropy(p: f64) -> f64 {
+    if p > 0.0 {
+        -p * p.log2()
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{std::vec::Vec, Builder};
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    // A small deterministic generator so the tests don't need `rng`
+    fn random(count: usize) -> impl Iterator<Item = Uuid> {
+        let mut state = 0x2545f4914f6cdd1du64;
+
+        (0..count).map(move |_| {
+            let mut bytes = [0; 16];
+            for chunk in bytes.chunks_mut(8) {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+
+                chunk.copy_from_slice(&state.to_be_bytes());
+            }
+
+            Builder::from_random_bytes(bytes).into_uuid()
+        })
+    }
+
+    fn v1(ticks: u64) -> Uuid {
+        v1_from(ticks, 0, 1)
+    }
+
+    fn v1_from(ticks: u64, counter: u16, node: u8) -> Uuid {
+        Builder::from_rfc4122_timestamp(ticks, counter, &[node; 6]).into_uuid()
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_empty() {
+        let report = Report::new();
+
+        assert_eq!(0, report.count());
+        assert_eq!(None, report.timestamp_range());
+        assert_eq!(None, report.random_entropy());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_distribution() {
+        let mut uuids = random(10).collect::<Vec<_>>();
+        uuids.push(v1(0x1e74ba220616934));
+        uuids.push(Uuid::nil());
+        uuids.push(Uuid::from_u128(0x67e5504410b1426fc247bb680e5fe0c8));
+
+        let report = uuids.into_iter().collect::<Report>();
+
+        assert_eq!(13, report.count());
+        assert_eq!(11, report.version_count(4));
+        assert_eq!(1, report.version_count(1));
+        assert_eq!(1, report.version_count(0));
+        assert_eq!(11, report.variant_count(Variant::RFC4122));
+        assert_eq!(1, report.variant_count(Variant::NCS));
+        assert_eq!(1, report.variant_count(Variant::Microsoft));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_timestamps() {
+        let ticks = 0x1e74ba220616934;
+        let report = Report::analyze([
+            v1(ticks),
+            v1(ticks + 10),
+            v1(ticks + 5),
+            v1(ticks + 20),
+            v1(ticks - 10),
+        ]);
+
+        let (first, last) = report.timestamp_range().unwrap();
+
+        assert_eq!(v1(ticks - 10).get_timestamp(), Some(first));
+        assert_eq!(v1(ticks + 20).get_timestamp(), Some(last));
+        assert_eq!(2, report.monotonicity_violations());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_random_entropy() {
+        let random = Report::analyze(random(10_000)).random_entropy().unwrap();
+        assert!(random > 0.99, "{}", random);
+
+        // Only the low byte of these varies
+        let poor = Report::analyze(
+            (0..10_000u128)
+                .map(|i| Builder::from_random_bytes((i % 256).to_be_bytes()).into_uuid()),
+        )
+        .random_entropy()
+        .unwrap();
+        assert!(poor < 0.1, "{}", poor);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_timeline_order() {
+        let ticks = 0x1e74ba220616934;
+        let input = [
+            v1_from(ticks + 20, 2, 1),
+            v1_from(ticks, 0, 1),
+            Uuid::nil(),
+            v1_from(ticks, 1, 1),
+            v1_from(ticks + 10, 7, 2),
+        ];
+
+        let timeline = Timeline::reconstruct(input);
+
+        let order = timeline
+            .entries()
+            .iter()
+            .map(|entry| entry.index)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 3, 4, 0], order);
+        assert_eq!(&[2], timeline.skipped());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_timeline_runs() {
+        let ticks = 0x1e74ba220616934;
+        let timeline = Timeline::reconstruct([
+            v1_from(ticks, 0x3ffe, 1),
+            v1_from(ticks + 1, 0x3fff, 1),
+            v1_from(ticks + 2, 0, 1),
+            v1_from(ticks + 2, 1, 2),
+            v1_from(ticks + 3, 2, 2),
+            v1_from(ticks + 4, 9, 1),
+        ]);
+
+        assert_eq!(3, timeline.runs());
+
+        let runs = timeline
+            .entries()
+            .iter()
+            .map(|entry| entry.run)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0, 0, 0, 1, 1, 2], runs);
+
+        assert_eq!(2, timeline.run(1).count());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_timeline_gaps_and_regressions() {
+        let ticks = 0x1e74ba220616934;
+        let second = 10_000_000;
+        let timeline = Timeline::reconstruct([
+            v1_from(ticks, 0, 1),
+            v1_from(ticks + second, 1, 1),
+            v1_from(ticks + 5 * second, 0, 2),
+            v1_from(ticks + 2 * second, 2, 1),
+            // Earlier than the last UUID, but that was from another node
+            v1_from(ticks + 3 * second, 3, 1),
+            v1_from(ticks + second / 2, 4, 1),
+        ]);
+
+        assert_eq!(
+            vec![Gap {
+                before: 4,
+                after: 5,
+                duration: Duration::from_secs(2),
+            }],
+            timeline.gaps(Duration::from_secs(2))
+        );
+        assert_eq!(3, timeline.gaps(Duration::from_secs(1)).len());
+
+        assert_eq!(
+            vec![Regression {
+                previous: 4,
+                index: 5,
+                duration: Duration::from_millis(2_500),
+            }],
+            timeline.regressions()
+        );
+    }
+}
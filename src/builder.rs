@@ -13,7 +13,7 @@
 //!
 //! [`Uuid`]: ../struct.Uuid.html
 
-use crate::{error::*, timestamp, Bytes, Uuid, Variant, Version};
+use crate::{error::*, std::ptr, timestamp, Bytes, Domain, Uuid, Variant, Version};
 
 /// A builder for creating a UUID.
 ///
@@ -44,7 +44,7 @@ use crate::{error::*, timestamp, Bytes, Uuid, Variant, Version};
 /// ```
 #[allow(missing_copy_implementations)]
 #[derive(Debug)]
-pub struct Builder(Uuid);
+pub struct Builder(Uuid, Option<Error>);
 
 impl Uuid {
     /// The 'nil UUID' (all zeros).
@@ -80,7 +80,7 @@ impl Uuid {
     ///
     /// # References
     ///
-    /// * [Max UUID in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-5.4)
+    /// * [Max UUID in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.10)
     ///
     /// # Examples
     ///
@@ -95,7 +95,6 @@ impl Uuid {
     ///     uuid.hyphenated().to_string(),
     /// );
     /// ```
-    #[cfg(uuid_unstable)]
     pub const fn max() -> Self {
         Uuid::from_bytes([0xFF; 16])
     }
@@ -338,7 +337,7 @@ impl Uuid {
     /// ```
     pub fn from_slice(b: &[u8]) -> Result<Uuid, Error> {
         if b.len() != 16 {
-            return Err(Error(ErrorKind::ByteLength { len: b.len() }));
+            return Err(Error(ErrorRepr::ByteLength { len: b.len() }));
         }
 
         let mut bytes: Bytes = [0; 16];
@@ -379,7 +378,7 @@ impl Uuid {
     /// ```
     pub fn from_slice_le(b: &[u8]) -> Result<Uuid, Error> {
         if b.len() != 16 {
-            return Err(Error(ErrorKind::ByteLength { len: b.len() }));
+            return Err(Error(ErrorRepr::ByteLength { len: b.len() }));
         }
 
         let mut bytes: Bytes = [0; 16];
@@ -487,6 +486,145 @@ impl Uuid {
         unsafe { &*(bytes as *const Bytes as *const Uuid) }
     }
 
+    /// Creates a mutable reference to a UUID from a mutable reference to the
+    /// supplied bytes.
+    ///
+    /// Writes through the returned reference change the original bytes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut bytes = [0; 16];
+    ///
+    /// *Uuid::from_bytes_mut(&mut bytes) = Uuid::from_u128(u128::MAX);
+    ///
+    /// assert_eq!(bytes, [0xff; 16]);
+    /// ```
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut Bytes) -> &mut Uuid {
+        // SAFETY: `Bytes` and `Uuid` have the same ABI
+        unsafe { &mut *(bytes as *mut Bytes as *mut Uuid) }
+    }
+
+    /// Creates a reference to a UUID from a reference to the supplied slice
+    /// without copying it.
+    ///
+    /// `Uuid` has an alignment of 1, so this can view UUIDs in place at any
+    /// offset in a larger buffer, such as a memory-mapped file.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `b` has any length other than 16.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// # use uuid::Uuid;
+    /// let buf = [
+    ///     0x01,
+    ///     0xa1, 0xa2, 0xa3, 0xa4,
+    ///     0xb1, 0xb2,
+    ///     0xc1, 0xc2,
+    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_slice_ref(&buf[1..])?;
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_slice_ref(b: &[u8]) -> Result<&Uuid, Error> {
+        if b.len() != 16 {
+            return Err(Error(ErrorRepr::ByteLength { len: b.len() }));
+        }
+
+        // SAFETY: `b` is 16 bytes long, and `Uuid` has the same ABI as `Bytes`
+        Ok(unsafe { &*(b.as_ptr() as *const Uuid) })
+    }
+
+    /// Reads a UUID from the 16 bytes starting at `offset` in `buf`.
+    ///
+    /// The bytes don't need to be aligned, so this method can be used to
+    /// read UUIDs embedded at arbitrary offsets in packed network headers
+    /// or on-disk formats.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `buf` doesn't contain at least
+    /// 16 bytes starting from `offset`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// # use uuid::Uuid;
+    /// let header = [
+    ///     0x01,
+    ///     0xa1, 0xa2, 0xa3, 0xa4,
+    ///     0xb1, 0xb2,
+    ///     0xc1, 0xc2,
+    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    ///     0xff,
+    /// ];
+    ///
+    /// let uuid = Uuid::read_unaligned_at(&header, 1)?;
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_unaligned_at(buf: &[u8], offset: usize) -> Result<Uuid, Error> {
+        match buf.get(offset..).and_then(|b| b.get(..16)) {
+            Some(b) => Uuid::from_slice(b),
+            None => Err(Error(ErrorRepr::ByteLength {
+                len: buf.len().saturating_sub(offset),
+            })),
+        }
+    }
+
+    /// Reads a UUID from a pointer to 16 bytes that may not be aligned.
+    ///
+    /// Prefer [`Uuid::read_unaligned_at`] unless you're already working
+    /// with raw pointers.
+    ///
+    /// # Safety
+    ///
+    /// `src` must be valid for reads of 16 bytes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let header = [0x01, 0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
+    ///
+    /// // SAFETY: There are 16 bytes in `header` following the first
+    /// let uuid = unsafe { Uuid::read_unaligned(header.as_ptr().add(1)) };
+    ///
+    /// assert_eq!(uuid.as_u128(), 0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8);
+    /// ```
+    #[inline]
+    pub unsafe fn read_unaligned(src: *const u8) -> Uuid {
+        Uuid(ptr::read_unaligned(src as *const Bytes))
+    }
+
     // NOTE: There is no `from_u128_ref` because in little-endian
     // environments the value isn't properly encoded. Callers would
     // need to use `.to_be()` themselves.
@@ -516,7 +654,7 @@ impl Builder {
     /// );
     /// ```
     pub const fn from_bytes(b: Bytes) -> Self {
-        Builder(Uuid::from_bytes(b))
+        Builder(Uuid::from_bytes(b), None)
     }
 
     /// Creates a `Builder` using the supplied bytes in little endian order.
@@ -547,17 +685,80 @@ impl Builder {
     /// # }
     /// ```
     pub const fn from_bytes_le(b: Bytes) -> Self {
-        Builder(Uuid::from_bytes_le(b))
+        Builder(Uuid::from_bytes_le(b), None)
     }
 
     /// Creates a `Builder` for a version 1 UUID using the supplied timestamp and node ID.
+    ///
+    /// Only the low 60 bits of `ticks` and the low 14 bits of `counter` are
+    /// used. If either has higher bits set then [`Builder::try_build`] will
+    /// return an error.
     pub const fn from_rfc4122_timestamp(ticks: u64, counter: u16, node_id: &[u8; 6]) -> Self {
-        Builder(timestamp::encode_rfc4122_timestamp(ticks, counter, node_id))
+        Builder(
+            timestamp::encode_rfc4122_timestamp(ticks, counter, node_id),
+            None,
+        )
+        .check_rfc4122_timestamp(ticks, counter)
+    }
+
+    /// Creates a `Builder` for a version 2 DCE Security UUID using the supplied
+    /// domain, local ID, timestamp, and node ID.
+    ///
+    /// The local ID replaces the low 32 bits of the timestamp, and the domain
+    /// replaces the low 8 bits of the counter, so only bits 32 to 59 of
+    /// `ticks` and the low 6 bits of `counter` are used. If either has
+    /// higher bits set then [`Builder::try_build`] will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Domain, Version};
+    /// let uuid = Builder::from_dce_timestamp(
+    ///     Domain::Person,
+    ///     1000,
+    ///     0x1e7_52a1_f3b4_9a52,
+    ///     42,
+    ///     &[1, 2, 3, 4, 5, 6],
+    /// )
+    /// .try_build()?;
+    ///
+    /// assert_eq!(Some(Version::Dce), uuid.get_version());
+    /// assert_eq!(
+    ///     "000003e8-52a1-21e7-aa00-010203040506",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn from_dce_timestamp(
+        domain: Domain,
+        local_id: u32,
+        ticks: u64,
+        counter: u16,
+        node_id: &[u8; 6],
+    ) -> Self {
+        let builder = Builder(
+            timestamp::encode_dce_timestamp(domain as u8, local_id, ticks, counter, node_id),
+            None,
+        );
+
+        if ticks >> 60 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "timestamp",
+                bits: 60,
+            })
+        } else if counter >> 6 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "counter",
+                bits: 6,
+            })
+        } else {
+            builder
+        }
     }
 
     /// Creates a `Builder` for a version 3 UUID using the supplied MD5 hashed bytes.
     pub const fn from_md5_bytes(md5_bytes: Bytes) -> Self {
-        Builder(Uuid::from_bytes(md5_bytes))
+        Builder(Uuid::from_bytes(md5_bytes), None)
             .with_variant(Variant::RFC4122)
             .with_version(Version::Md5)
     }
@@ -582,17 +783,59 @@ impl Builder {
     /// assert_eq!(Variant::RFC4122, uuid.get_variant());
     /// ```
     pub const fn from_random_bytes(random_bytes: Bytes) -> Self {
-        Builder(Uuid::from_bytes(random_bytes))
+        Builder(Uuid::from_bytes(random_bytes), None)
             .with_variant(Variant::RFC4122)
             .with_version(Version::Random)
     }
 
+    /// Creates a `Builder` for a sequential COMB UUID using the supplied
+    /// Unix timestamp and random bytes.
+    ///
+    /// The timestamp fills the last 6 bytes of the UUID, which are the most
+    /// significant when SQL Server orders `uniqueidentifier` values. The
+    /// rest of the UUID is random, with the version and variant set like
+    /// a version 4 UUID. See [`Uuid::new_comb`] for more details.
+    ///
+    /// Only the low 48 bits of `millis` are used. If it has higher bits set
+    /// then [`Builder::try_build`] will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// # let rng = || [
+    /// #     70, 235, 208, 238, 14, 109, 67, 201, 185, 13
+    /// # ];
+    /// let random_bytes = rng();
+    ///
+    /// let uuid = Builder::from_comb_timestamp_millis(1_497_624_119_812, &random_bytes).into_uuid();
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert_eq!(Variant::RFC4122, uuid.get_variant());
+    /// assert!(uuid.hyphenated().to_string().ends_with("-015cb15a8a04"));
+    /// ```
+    pub const fn from_comb_timestamp_millis(millis: u64, random_bytes: &[u8; 10]) -> Self {
+        let builder = Builder(
+            timestamp::encode_comb_timestamp_millis(millis, random_bytes),
+            None,
+        );
+
+        if millis >> 48 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "timestamp",
+                bits: 48,
+            })
+        } else {
+            builder
+        }
+    }
+
     /// Creates a `Builder` for a version 5 UUID using the supplied SHA-1 hashed bytes.
     ///
     /// This method assumes the bytes are already a SHA-1 hash, it will only set the appropriate
     /// bits for the UUID version and variant.
     pub const fn from_sha1_bytes(sha1_bytes: Bytes) -> Self {
-        Builder(Uuid::from_bytes(sha1_bytes))
+        Builder(Uuid::from_bytes(sha1_bytes), None)
             .with_variant(Variant::RFC4122)
             .with_version(Version::Sha1)
     }
@@ -600,21 +843,30 @@ impl Builder {
     /// Creates a `Builder` for a version 6 UUID using the supplied timestamp and node ID.
     ///
     /// This method will encode the ticks, counter, and node ID in a sortable UUID.
+    ///
+    /// Only the low 60 bits of `ticks` and the low 14 bits of `counter` are
+    /// used. If either has higher bits set then [`Builder::try_build`] will
+    /// return an error.
     #[cfg(uuid_unstable)]
     pub const fn from_sorted_rfc4122_timestamp(
         ticks: u64,
         counter: u16,
         node_id: &[u8; 6],
     ) -> Self {
-        Builder(timestamp::encode_sorted_rfc4122_timestamp(
-            ticks, counter, node_id,
-        ))
+        Builder(
+            timestamp::encode_sorted_rfc4122_timestamp(ticks, counter, node_id),
+            None,
+        )
+        .check_rfc4122_timestamp(ticks, counter)
     }
 
     /// Creates a `Builder` for a version 7 UUID using the supplied Unix timestamp and random bytes.
     ///
     /// This method assumes the bytes are already sufficiently random.
     ///
+    /// Only the low 48 bits of `millis` are used. If it has higher bits set
+    /// then [`Builder::try_build`] will return an error.
+    ///
     /// # Examples
     ///
     /// Creating a UUID using the current system timestamp:
@@ -640,10 +892,19 @@ impl Builder {
     /// ```
     #[cfg(uuid_unstable)]
     pub const fn from_unix_timestamp_millis(millis: u64, random_bytes: &[u8; 10]) -> Self {
-        Builder(timestamp::encode_unix_timestamp_millis(
-            millis,
-            random_bytes,
-        ))
+        let builder = Builder(
+            timestamp::encode_unix_timestamp_millis(millis, random_bytes),
+            None,
+        );
+
+        if millis >> 48 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "timestamp",
+                bits: 48,
+            })
+        } else {
+            builder
+        }
     }
 
     /// Creates a `Builder` for a version 8 UUID using the supplied user-defined bytes.
@@ -657,6 +918,46 @@ impl Builder {
             .with_version(Version::Custom)
     }
 
+    /// Creates a `Builder` for a version 8 UUID using the supplied 122-bit payload.
+    ///
+    /// The payload is spread over the bits of the UUID that aren't taken by
+    /// the version and variant, from its most significant bit to its least.
+    /// Use [`Uuid::get_v8_payload`] to get it back.
+    ///
+    /// Only the low 122 bits of `payload` are used. If it has higher bits set
+    /// then [`Builder::try_build`] will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let uuid = Builder::from_v8_payload(0x2a).try_build()?;
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!("00000000-0000-8000-8000-00000000002a", uuid.hyphenated().to_string());
+    /// assert_eq!(Some(0x2a), uuid.get_v8_payload());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[cfg(uuid_unstable)]
+    pub const fn from_v8_payload(payload: u128) -> Self {
+        let builder = Builder::from_u128(
+            (payload >> 74 & 0xFFFF_FFFF_FFFF) << 80
+                | (payload >> 62 & 0xFFF) << 64
+                | payload & 0x3FFF_FFFF_FFFF_FFFF,
+        )
+        .with_variant(Variant::RFC4122)
+        .with_version(Version::Custom);
+
+        if payload >> 122 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "payload",
+                bits: 122,
+            })
+        } else {
+            builder
+        }
+    }
+
     /// Creates a `Builder` using the supplied bytes.
     ///
     /// # Errors
@@ -687,7 +988,7 @@ impl Builder {
     /// # }
     /// ```
     pub fn from_slice(b: &[u8]) -> Result<Self, Error> {
-        Ok(Builder(Uuid::from_slice(b)?))
+        Ok(Builder(Uuid::from_slice(b)?, None))
     }
 
     /// Creates a `Builder` using the supplied bytes in little endian order.
@@ -722,7 +1023,7 @@ impl Builder {
     /// # }
     /// ```
     pub fn from_slice_le(b: &[u8]) -> Result<Self, Error> {
-        Ok(Builder(Uuid::from_slice_le(b)?))
+        Ok(Builder(Uuid::from_slice_le(b)?, None))
     }
 
     /// Creates a `Builder` from four field values.
@@ -746,7 +1047,194 @@ impl Builder {
     /// );
     /// ```
     pub const fn from_fields(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Self {
-        Builder(Uuid::from_fields(d1, d2, d3, d4))
+        Builder(Uuid::from_fields(d1, d2, d3, d4), None)
+    }
+
+    /// Creates a `Builder` for an RFC4122 UUID from its individual fields.
+    ///
+    /// The fields don't include the version or variant, which are set by
+    /// the builder. `time_hi` is the top 12 bits of the timestamp,
+    /// `clock_seq` is 14 bits, and `node` is 48 bits. If any of them have
+    /// higher bits set then [`Builder::try_build`] will return an error.
+    ///
+    /// This is useful for building UUIDs from fields that were pulled
+    /// apart by some other implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let builder = Builder::from_rfc4122_fields(
+    ///     Version::Mac,
+    ///     0x20616934,
+    ///     0x4ba2,
+    ///     0x1e7,
+    ///     0x2a,
+    ///     0x010203040506,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "20616934-4ba2-11e7-802a-010203040506",
+    ///     builder.try_build()?.hyphenated().to_string(),
+    /// );
+    ///
+    /// // `time_hi` is only 12 bits
+    /// let builder = Builder::from_rfc4122_fields(Version::Mac, 0, 0, 0x11e7, 0, 0);
+    ///
+    /// assert!(builder.try_build().is_err());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn from_rfc4122_fields(
+        version: Version,
+        time_low: u32,
+        time_mid: u16,
+        time_hi: u16,
+        clock_seq: u16,
+        node: u64,
+    ) -> Self {
+        let node_bytes = node.to_be_bytes();
+
+        let builder = Builder::from_fields(
+            time_low,
+            time_mid,
+            time_hi & 0x0fff,
+            &[
+                0,
+                0,
+                node_bytes[2],
+                node_bytes[3],
+                node_bytes[4],
+                node_bytes[5],
+                node_bytes[6],
+                node_bytes[7],
+            ],
+        )
+        .with_version(version)
+        .with_clock_seq(clock_seq);
+
+        if time_hi >> 12 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "time_hi",
+                bits: 12,
+            })
+        } else if node >> 48 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "node",
+                bits: 48,
+            })
+        } else {
+            builder
+        }
+    }
+
+    /// Creates a `Builder` for a Microsoft variant UUID from the fields of
+    /// a GUID.
+    ///
+    /// The fields are the `Data1`, `Data2`, `Data3`, and `Data4` members of
+    /// a Windows `GUID`, in native integer form. The top 3 bits of `data4[0]`
+    /// hold the variant, which is set by the builder.
+    ///
+    /// Note that Windows stores the integer fields of a `GUID` in little
+    /// endian order. To read a GUID from its in-memory bytes, use
+    /// [`Builder::from_bytes_le`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Building the interface ID of COM's `IUnknown`:
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant};
+    /// let uuid = Builder::from_microsoft_fields(
+    ///     0x00000000,
+    ///     0x0000,
+    ///     0x0000,
+    ///     &[0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+    /// )
+    /// .into_uuid();
+    ///
+    /// assert_eq!(Variant::Microsoft, uuid.get_variant());
+    /// assert_eq!(
+    ///     "00000000-0000-0000-c000-000000000046",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_microsoft_fields(
+        data1: u32,
+        data2: u16,
+        data3: u16,
+        data4: &[u8; 8],
+    ) -> Self {
+        Builder::from_fields(data1, data2, data3, data4).with_variant(Variant::Microsoft)
+    }
+
+    /// Creates a `Builder` for an NCS variant UUID from its individual fields.
+    ///
+    /// NCS UUIDs were generated by the Apollo Network Computing System, and
+    /// predate the RFC4122 layout. `timestamp` is the 48-bit count of 4
+    /// microsecond intervals since 1980-01-01, `address_family` is the 7-bit
+    /// network address family of the host, and `host` is its 56-bit address.
+    /// If `timestamp` or `address_family` have higher bits set then
+    /// [`Builder::try_build`] will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant};
+    /// let builder = Builder::from_ncs_fields(
+    ///     0x333a_2276_0000,
+    ///     0x0d,
+    ///     &[0x00, 0x00, 0x80, 0x9c, 0x00, 0x00, 0x00],
+    /// );
+    /// let uuid = builder.try_build()?;
+    ///
+    /// assert_eq!(Variant::NCS, uuid.get_variant());
+    /// assert_eq!(
+    ///     "333a2276-0000-0000-0d00-00809c000000",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    ///
+    /// // The top bit of the address family is taken by the variant
+    /// let builder = Builder::from_ncs_fields(0, 0x80, &[0; 7]);
+    ///
+    /// assert!(builder.try_build().is_err());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn from_ncs_fields(timestamp: u64, address_family: u8, host: &[u8; 7]) -> Self {
+        let timestamp_bytes = timestamp.to_be_bytes();
+
+        let builder = Builder::from_bytes([
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            timestamp_bytes[4],
+            timestamp_bytes[5],
+            timestamp_bytes[6],
+            timestamp_bytes[7],
+            0,
+            0,
+            address_family,
+            host[0],
+            host[1],
+            host[2],
+            host[3],
+            host[4],
+            host[5],
+            host[6],
+        ])
+        .with_variant(Variant::NCS);
+
+        if timestamp >> 48 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "timestamp",
+                bits: 48,
+            })
+        } else if address_family >> 7 != 0 {
+            builder.with_error(ErrorRepr::Range {
+                field: "address_family",
+                bits: 7,
+            })
+        } else {
+            builder
+        }
     }
 
     /// Creates a `Builder` from four field values.
@@ -770,7 +1258,7 @@ impl Builder {
     /// );
     /// ```
     pub const fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Self {
-        Builder(Uuid::from_fields_le(d1, d2, d3, d4))
+        Builder(Uuid::from_fields_le(d1, d2, d3, d4), None)
     }
 
     /// Creates a `Builder` from a 128bit value.
@@ -791,7 +1279,7 @@ impl Builder {
     /// );
     /// ```
     pub const fn from_u128(v: u128) -> Self {
-        Builder(Uuid::from_u128(v))
+        Builder(Uuid::from_u128(v), None)
     }
 
     /// Creates a UUID from a 128bit value in little-endian order.
@@ -812,7 +1300,7 @@ impl Builder {
     /// );
     /// ```
     pub const fn from_u128_le(v: u128) -> Self {
-        Builder(Uuid::from_u128_le(v))
+        Builder(Uuid::from_u128_le(v), None)
     }
 
     /// Creates a `Builder` with an initial [`Uuid::nil`].
@@ -831,12 +1319,12 @@ impl Builder {
     /// );
     /// ```
     pub const fn nil() -> Self {
-        Builder(Uuid::nil())
+        Builder(Uuid::nil(), None)
     }
 
     /// Specifies the variant of the UUID.
     pub fn set_variant(&mut self, v: Variant) -> &mut Self {
-        *self = Builder(self.0).with_variant(v);
+        *self = Builder(self.0, self.1.take()).with_variant(v);
         self
     }
 
@@ -856,7 +1344,7 @@ impl Builder {
 
     /// Specifies the version number of the UUID.
     pub fn set_version(&mut self, v: Version) -> &mut Self {
-        *self = Builder(self.0).with_version(v);
+        *self = Builder(self.0, self.1.take()).with_version(v);
         self
     }
 
@@ -867,6 +1355,94 @@ impl Builder {
         self
     }
 
+    /// Specifies the node ID of the UUID.
+    ///
+    /// The node ID is the last 6 bytes of a version 1 or 6 UUID. If
+    /// `node_id` isn't 6 bytes long then the UUID is left unchanged and
+    /// [`Builder::try_build`] will return an error.
+    pub fn set_node_id(&mut self, node_id: &[u8]) -> &mut Self {
+        *self = Builder(self.0, self.1.take()).with_node_id(node_id);
+        self
+    }
+
+    /// Specifies the node ID of the UUID.
+    ///
+    /// The node ID is the last 6 bytes of a version 1 or 6 UUID. If
+    /// `node_id` isn't 6 bytes long then the UUID is left unchanged and
+    /// [`Builder::try_build`] will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let builder = Builder::from_rfc4122_timestamp(0x1e7_4ba2_2061_6934, 1, &[0; 6])
+    ///     .with_node_id(&[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(
+    ///     "20616934-4ba2-11e7-8001-010203040506",
+    ///     builder.try_build()?.hyphenated().to_string(),
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn with_node_id(mut self, node_id: &[u8]) -> Self {
+        if node_id.len() != 6 {
+            return self.with_error(ErrorRepr::NodeLength { len: node_id.len() });
+        }
+
+        let mut i = 0;
+        while i < 6 {
+            (self.0).0[10 + i] = node_id[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Specifies the clock sequence of the UUID.
+    ///
+    /// The clock sequence is the 14 bits after the variant of a version 1
+    /// or 6 UUID. This also sets the variant to [`Variant::RFC4122`]. If
+    /// `clock_seq` has higher bits set then they're ignored and
+    /// [`Builder::try_build`] will return an error.
+    pub fn set_clock_seq(&mut self, clock_seq: u16) -> &mut Self {
+        *self = Builder(self.0, self.1.take()).with_clock_seq(clock_seq);
+        self
+    }
+
+    /// Specifies the clock sequence of the UUID.
+    ///
+    /// The clock sequence is the 14 bits after the variant of a version 1
+    /// or 6 UUID. This also sets the variant to [`Variant::RFC4122`]. If
+    /// `clock_seq` has higher bits set then they're ignored and
+    /// [`Builder::try_build`] will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let builder = Builder::from_rfc4122_timestamp(0x1e7_4ba2_2061_6934, 0, &[1, 2, 3, 4, 5, 6])
+    ///     .with_clock_seq(0x2a);
+    ///
+    /// assert_eq!(
+    ///     "20616934-4ba2-11e7-802a-010203040506",
+    ///     builder.try_build()?.hyphenated().to_string(),
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn with_clock_seq(mut self, clock_seq: u16) -> Self {
+        (self.0).0[8] = (((clock_seq >> 8) & 0x3f) as u8) | 0x80;
+        (self.0).0[9] = clock_seq as u8;
+
+        if clock_seq >> 14 != 0 {
+            self.with_error(ErrorRepr::Range {
+                field: "clock_seq",
+                bits: 14,
+            })
+        } else {
+            self
+        }
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -904,4 +1480,89 @@ impl Builder {
     pub const fn into_uuid(self) -> Uuid {
         self.0
     }
+
+    /// Convert the builder into a [`Uuid`], returning an error if any of
+    /// the inputs it was given didn't fit.
+    ///
+    /// [`Builder::into_uuid`] silently truncates values that are too large
+    /// for their fields, like a timestamp that's out of range for its
+    /// version or a counter wider than 14 bits. This method is useful when
+    /// UUIDs are being constructed from untrusted parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Builder, ErrorKind};
+    /// let node_id = [1, 2, 3, 4, 5, 6];
+    ///
+    /// assert!(Builder::from_rfc4122_timestamp(0x1e7_4ba2_2061_6934, 1, &node_id)
+    ///     .try_build()
+    ///     .is_ok());
+    ///
+    /// let err = Builder::from_rfc4122_timestamp(0x1e7_4ba2_2061_6934, 1 << 14, &node_id)
+    ///     .try_build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(ErrorKind::OutOfRange, err.kind());
+    /// ```
+    pub const fn try_build(self) -> Result<Uuid, Error> {
+        match self.1 {
+            Some(err) => Err(err),
+            None => Ok(self.0),
+        }
+    }
+
+    /// Convert the builder into a [`Uuid`], rejecting UUIDs that RFC 9562
+    /// doesn't describe.
+    ///
+    /// See [`Uuid::check_strict`] for the UUIDs that are rejected.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let bytes = [
+    ///     0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ];
+    ///
+    /// assert!(Builder::from_bytes(bytes).into_strict_uuid().is_err());
+    /// assert!(Builder::from_random_bytes(bytes).into_strict_uuid().is_ok());
+    /// ```
+    ///
+    /// [`Uuid::check_strict`]: ../struct.Uuid.html#method.check_strict
+    pub const fn into_strict_uuid(self) -> Result<Uuid, Error> {
+        match self.0.check_strict() {
+            Ok(()) => Ok(self.0),
+            Err(err) => Err(err),
+        }
+    }
+
+    const fn check_rfc4122_timestamp(self, ticks: u64, counter: u16) -> Self {
+        if ticks >> 60 != 0 {
+            self.with_error(ErrorRepr::Range {
+                field: "timestamp",
+                bits: 60,
+            })
+        } else if counter >> 14 != 0 {
+            self.with_error(ErrorRepr::Range {
+                field: "counter",
+                bits: 14,
+            })
+        } else {
+            self
+        }
+    }
+
+    // Only the first error is kept
+    const fn with_error(mut self, err: ErrorRepr) -> Self {
+        if self.1.is_none() {
+            self.1 = Some(Error(err));
+        }
+
+        self
+    }
 }
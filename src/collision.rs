@@ -0,0 +1,294 @@
+//! Checking sets of UUIDs for duplicates.
+//!
+//! This is used by the `migrate` module to report UUIDs that collide
+//! after they're migrated, and is re-exported from `test_util` for soak
+//! tests of custom generators.
+
+// `migrate` only needs part of the checker when `test_util` isn't built
+#![cfg_attr(not(feature = "test-util"), allow(dead_code))]
+
+use crate::{
+    std::{
+        collections::{HashMap, HashSet},
+        vec::Vec,
+    },
+    Uuid,
+};
+
+// Bits in the filter per expected UUID, and the number of bits set for each
+const BITS_PER_UUID: usize = 16;
+const HASHES: u64 = 3;
+
+/// A checker for duplicate UUIDs.
+///
+/// UUIDs are checked against a bloom filter as they're inserted, so that
+/// only UUIDs that might be duplicates need to be looked at again when
+/// reporting [`collisions`](CollisionChecker::collisions). The reported
+/// collisions are always exact; the filter only affects how much work
+/// that takes.
+///
+/// Each inserted UUID is given an index, counting from zero in the order
+/// they're inserted.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// # #[cfg(feature = "test-util")] {
+/// # use uuid::{Uuid, test_util::{Collision, CollisionChecker}};
+/// let a = Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+/// let b = Uuid::from_u128(0xf9168c5eceb24faab6bf329bf39fa1e4);
+///
+/// let mut checker = CollisionChecker::new();
+/// checker.extend([a, b, a]);
+///
+/// assert_eq!(
+///     checker.collisions(),
+///     [Collision {
+///         uuid: a,
+///         first: 0,
+///         duplicate: 2,
+///     }]
+/// );
+/// # }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CollisionChecker {
+    filter: Vec<u64>,
+    uuids: Vec<Uuid>,
+    candidates: Vec<usize>,
+}
+
+/// A UUID that was inserted into a [`CollisionChecker`] more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Collision {
+    /// The duplicated UUID.
+    pub uuid: Uuid,
+    /// The index the UUID was first inserted at.
+    pub first: usize,
+    /// The index of the duplicate.
+    pub duplicate: usize,
+}
+
+impl Default for CollisionChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CollisionChecker {
+    /// Create an empty checker.
+    pub fn new() -> Self {
+        Self::with_capacity(1024)
+    }
+
+    /// Create an empty checker sized for the given number of UUIDs.
+    ///
+    /// The checker will still accept more UUIDs than this, but will need
+    /// to resize as it goes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        CollisionChecker {
+            filter: crate::std::vec![0; filter_len(capacity)],
+            uuids: Vec::with_capacity(capacity),
+            candidates: Vec::new(),
+        }
+    }
+
+    /// Insert a UUID, returning its index.
+    pub fn insert(&mut self, uuid: Uuid) -> usize {
+        if self.uuids.len() * BITS_PER_UUID >= self.filter.len() * 64 {
+            self.grow();
+        }
+
+        let index = self.uuids.len();
+
+        if !set(&mut self.filter, uuid) {
+            self.candidates.push(index);
+        }
+
+        self.uuids.push(uuid);
+
+        index
+    }
+
+    /// The number of UUIDs inserted so far.
+    pub fn len(&self) -> usize {
+        self.uuids.len()
+    }
+
+    /// Whether any UUIDs have been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.uuids.is_empty()
+    }
+
+    /// Whether any UUID has been inserted more than once.
+    pub fn has_collisions(&self) -> bool {
+        !self.collisions().is_empty()
+    }
+
+    /// Get every duplicate inserted so far, in the order they were inserted.
+    ///
+    /// A UUID inserted three times is reported twice, each time against the
+    /// index it was first inserted at.
+    pub fn collisions(&self) -> Vec<Collision> {
+        if self.candidates.is_empty() {
+            return Vec::new();
+        }
+
+        let candidates = self
+            .candidates
+            .iter()
+            .map(|index| self.uuids[*index])
+            .collect::<HashSet<_>>();
+
+        let mut first = HashMap::with_capacity(candidates.len());
+        let mut collisions = Vec::new();
+
+        for (index, uuid) in self.uuids.iter().enumerate() {
+            if !candidates.contains(uuid) {
+                continue;
+            }
+
+            match first.get(uuid) {
+                Some(first) => collisions.push(Collision {
+                    uuid: *uuid,
+                    first: *first,
+                    duplicate: index,
+                }),
+                None => {
+                    first.insert(*uuid, index);
+                }
+            }
+        }
+
+        collisions
+    }
+
+    // Rebuild the filter at twice the size, so it doesn't fill up
+    fn grow(&mut self) {
+        let mut filter = crate::std::vec![0; self.filter.len() * 2];
+
+        self.candidates.clear();
+        for (index, uuid) in self.uuids.iter().enumerate() {
+            if !set(&mut filter, *uuid) {
+                self.candidates.push(index);
+            }
+        }
+
+        self.filter = filter;
+    }
+}
+
+impl Extend<Uuid> for CollisionChecker {
+    fn extend<I: IntoIterator<Item = Uuid>>(&mut self, iter: I) {
+        for uuid in iter {
+            self.insert(uuid);
+        }
+    }
+}
+
+fn filter_len(capacity: usize) -> usize {
+    (capacity * BITS_PER_UUID / 64).max(1).next_power_of_two()
+}
+
+// Set the bits for a UUID in the filter, returning whether any of them
+// weren't already set
+fn set(filter: &mut [u64], uuid: Uuid) -> bool {
+    let (hi, lo) = uuid.as_u64_pair();
+
+    // Mix both halves so UUIDs that only differ in a few bits, like
+    // those with a shared timestamp, still spread across the filter
+    let h1 = mix(hi ^ lo.rotate_left(32));
+    let h2 = mix(lo ^ hi.rotate_left(17)) | 1;
+
+    let mask = (filter.len() * 64 - 1) as u64;
+    let mut new = false;
+
+    for i in 0..HASHES {
+        let bit = h1.wrapping_add(i.wrapping_mul(h2)) & mask;
+        let (word, bit) = ((bit / 64) as usize, bit % 64);
+
+        new |= filter[word] & (1 << bit) == 0;
+        filter[word] |= 1 << bit;
+    }
+
+    new
+}
+
+pub(crate) fn mix(mut x: u64) -> u64 {
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51afd7ed558ccd);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xc4ceb9fe1a85ec53);
+    x ^ (x >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_no_collisions() {
+        let mut checker = CollisionChecker::with_capacity(16);
+        checker.extend((0..10_000u128).map(Uuid::from_u128));
+
+        assert_eq!(checker.len(), 10_000);
+        assert!(!checker.has_collisions());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_collisions() {
+        let mut checker = CollisionChecker::new();
+        checker.extend((0..5_000u128).map(Uuid::from_u128));
+
+        assert_eq!(checker.insert(Uuid::from_u128(42)), 5_000);
+        checker.extend((5_000..10_000u128).map(Uuid::from_u128));
+        checker.insert(Uuid::from_u128(42));
+        checker.insert(Uuid::from_u128(9_999));
+
+        assert_eq!(
+            checker.collisions(),
+            [
+                Collision {
+                    uuid: Uuid::from_u128(42),
+                    first: 42,
+                    duplicate: 5_000,
+                },
+                Collision {
+                    uuid: Uuid::from_u128(42),
+                    first: 42,
+                    duplicate: 10_001,
+                },
+                Collision {
+                    uuid: Uuid::from_u128(9_999),
+                    first: 10_000,
+                    duplicate: 10_002,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Draft and experimental UUID layouts.
+//!
+//! This module is a home for identifier layouts that aren't settled yet,
+//! such as those from IETF drafts that haven't been published as RFCs, or
+//! vendor profiles of version 8 UUIDs. Everything in it can change or be
+//! removed between minor releases, so it's only available when building
+//! with `--cfg uuid_unstable`:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg uuid_unstable"
+//! ```
+//!
+//! The whole module is declared behind `#[cfg(uuid_unstable)]` in the crate
+//! root. Each layout is additionally gated on the Cargo feature for the UUID
+//! version it produces, so the `sharded` profile needs the `v8` feature.
+//!
+//! # Promotion
+//!
+//! A layout is promoted out of this module once the specification it
+//! follows is published and its API has been used without changes for at
+//! least one release. On promotion its code moves out of `src/draft/`:
+//!
+//! - a layout for a new UUID version gets its own `src/vN.rs` module, with
+//!   its constructors on [`Uuid`](../struct.Uuid.html) and
+//!   [`Builder`](../struct.Builder.html) like the other versions.
+//! - a profile of an existing version moves into that version's module and
+//!   its types are re-exported from the crate root.
+//!
+//! Its old path here is kept for one release as a deprecated re-export, so
+//! code using it keeps building while it migrates:
+//!
+//! ```text
+//! #[deprecated(note = "use `uuid::ShardedId` instead")]
+//! pub use crate::v8::ShardedId;
+//! ```
+//!
+//! The re-export is removed in the following minor release. Promotion
+//! doesn't lift the `uuid_unstable` gate on its own; that happens when the
+//! version's feature is stabilized.
+//!
+//! # Adding a layout
+//!
+//! Each layout gets its own submodule, named after the draft or profile it
+//! implements, that documents the revision of the specification it follows.
+//! Layouts should only be built on the stable [`Builder`](../struct.Builder.html)
+//! API, or on [`V8Layout`](../struct.V8Layout.html) for version 8 profiles,
+//! so promoting them doesn't need changes elsewhere in the crate.
+
+#[cfg(feature = "v8")]
+pub mod sharded;
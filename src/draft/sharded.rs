@@ -0,0 +1,87 @@
+//! A version 8 profile for identifiers sharded across database nodes.
+//!
+//! This profile isn't from a published specification. It's an example of a
+//! vendor layout built on [`V8Layout`], packing a Unix timestamp in
+//! milliseconds, a shard number, and random bits:
+//!
+//! | Field       | Bits |
+//! |-------------|------|
+//! | `timestamp` | 48   |
+//! | `shard`     | 16   |
+//! | `random`    | 58   |
+//!
+//! Like version 7 UUIDs, identifiers from the same shard sort by the time
+//! they were created.
+//!
+//! Note that usage of this module requires the `v8` feature of this crate
+//! to be enabled, and building with `--cfg uuid_unstable`.
+
+use crate::{Error, Uuid, V8Layout};
+
+/// The layout of a sharded identifier.
+pub const LAYOUT: V8Layout<3> = V8Layout::new([("timestamp", 48), ("shard", 16), ("random", 58)]);
+
+/// Create a sharded identifier.
+///
+/// # Errors
+///
+/// This function will return an error if `millis` doesn't fit in 48 bits
+/// or `random` doesn't fit in 58 bits.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::draft::sharded;
+/// let uuid = sharded::new(1_645_557_742_000, 7, 0x1234_5678_9abc)?;
+///
+/// assert_eq!("017f22e2-79b0-8000-9c00-123456789abc", uuid.hyphenated().to_string());
+/// assert_eq!(Some((1_645_557_742_000, 7, 0x1234_5678_9abc)), sharded::decode(&uuid));
+/// # Ok::<(), uuid::Error>(())
+/// ```
+pub const fn new(millis: u64, shard: u16, random: u64) -> Result<Uuid, Error> {
+    LAYOUT.encode([millis as u128, shard as u128, random as u128])
+}
+
+/// Get the timestamp, shard, and random bits of a sharded identifier.
+///
+/// Returns `None` if the UUID isn't a version 8 UUID.
+pub const fn decode(uuid: &Uuid) -> Option<(u64, u16, u64)> {
+    match LAYOUT.decode(uuid) {
+        Some([millis, shard, random]) => Some((millis as u64, shard as u16, random as u64)),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sharded() {
+        let early = new(1_645_557_742_000, 3, u64::MAX >> 6).unwrap();
+        let late = new(1_645_557_742_001, 3, 0).unwrap();
+
+        assert!(early < late);
+        assert_eq!(Some((1_645_557_742_001, 3, 0)), decode(&late));
+
+        assert!(new(1 << 48, 0, 0).is_err());
+        assert!(new(0, 0, 1 << 58).is_err());
+
+        assert_eq!(None, decode(&Uuid::nil()));
+    }
+}
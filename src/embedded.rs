@@ -0,0 +1,285 @@
+//! A global UUID generator for embedded targets.
+//!
+//! Firmware often needs to generate UUIDs from both the main loop and
+//! interrupt handlers, but doesn't have an operating system to supply
+//! randomness or the current time. The [`Generator`] returned by [`generator`]
+//! combines an entropy callback, a clock source, and a clock sequence into a
+//! single object that's configured once at startup and can then be used from
+//! anywhere.
+//!
+//! Access to the generator's state is synchronized with the
+//! [`critical-section`] crate, so it's safe to use from interrupt service
+//! routines and threads alike. Your target needs to provide a critical section
+//! implementation, such as the one in `cortex-m` with its
+//! `critical-section-single-core` feature.
+//!
+//! Note that you need to enable the `critical-section` Cargo feature
+//! in order to use this module.
+//!
+//! # Examples
+//!
+//! ```
+//! # fn read_hardware_rng(buf: &mut [u8]) { buf.copy_from_slice(&[0x42; 16][..buf.len()]) }
+//! # fn read_rtc() -> (u64, u32) { (1_496_854_535, 812_946_000) }
+//! use uuid::embedded::{self, Config};
+//!
+//! embedded::generator()
+//!     .configure(Config::new(read_hardware_rng, read_rtc))
+//!     .expect("the generator is only configured once");
+//!
+//! let id = embedded::generator().new_v4();
+//! ```
+//!
+//! [`critical-section`]: https://docs.rs/critical-section
+
+use crate::{std::cell::RefCell, Builder, ClockSequence, Uuid};
+
+use critical_section::Mutex;
+
+static GENERATOR: Generator = Generator {
+    state: Mutex::new(RefCell::new(None)),
+};
+
+/// Get the global [`Generator`].
+///
+/// The generator needs to be configured with [`Generator::configure`] before
+/// it can produce UUIDs.
+pub fn generator() -> &'static Generator {
+    &GENERATOR
+}
+
+/// The sources of entropy and time used by a [`Generator`].
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    entropy: fn(&mut [u8]),
+    clock: fn() -> (u64, u32),
+}
+
+impl Config {
+    /// Create a configuration from an entropy callback and a clock source.
+    ///
+    /// The `entropy` callback must completely fill the given buffer with
+    /// random bytes. The `clock` callback must return the current time as
+    /// a number of seconds and subsecond nanoseconds since the Unix epoch.
+    ///
+    /// Both callbacks may be called from interrupt handlers, but are never
+    /// called while a critical section is held.
+    pub const fn new(entropy: fn(&mut [u8]), clock: fn() -> (u64, u32)) -> Self {
+        Config { entropy, clock }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    config: Config,
+    counter: u16,
+}
+
+/// An interrupt-safe UUID generator.
+///
+/// There's a single global instance of this type, returned by [`generator`].
+#[derive(Debug)]
+pub struct Generator {
+    state: Mutex<RefCell<Option<State>>>,
+}
+
+impl Generator {
+    /// Configure the generator with its sources of entropy and time.
+    ///
+    /// The generator can only be configured once. If it has already been
+    /// configured then the given `config` is returned as an error and the
+    /// existing configuration is kept.
+    ///
+    /// The clock sequence used for version 1 and 6 UUIDs is seeded from
+    /// the entropy callback.
+    pub fn configure(&self, config: Config) -> Result<(), Config> {
+        if self.is_configured() {
+            return Err(config);
+        }
+
+        let mut seed = [0; 2];
+        (config.entropy)(&mut seed);
+
+        critical_section::with(|cs| {
+            let mut state = self.state.borrow(cs).borrow_mut();
+
+            // Another caller may have configured the generator while
+            // we were reading the seed
+            if state.is_some() {
+                return Err(config);
+            }
+
+            *state = Some(State {
+                config,
+                counter: u16::from_be_bytes(seed),
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Whether or not [`Generator::configure`] has been called.
+    pub fn is_configured(&self) -> bool {
+        critical_section::with(|cs| self.state.borrow(cs).borrow().is_some())
+    }
+
+    /// Create a random version 4 UUID using the configured entropy callback.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the generator hasn't been configured.
+    pub fn new_v4(&self) -> Uuid {
+        let mut bytes = [0; 16];
+        (self.config().entropy)(&mut bytes);
+
+        Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    /// Create a version 1 UUID using the configured clock source and the given node ID.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the generator hasn't been configured.
+    #[cfg(feature = "v1")]
+    pub fn now_v1(&self, node_id: impl Into<crate::NodeId>) -> Uuid {
+        let (seconds, nanos) = (self.config().clock)();
+
+        Uuid::new_v1(crate::Timestamp::from_unix(self, seconds, nanos), node_id)
+    }
+
+    /// Create a version 6 UUID using the configured clock source and the given node ID.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the generator hasn't been configured.
+    #[cfg(all(uuid_unstable, feature = "v6"))]
+    pub fn now_v6(&self, node_id: impl Into<crate::NodeId>) -> Uuid {
+        let (seconds, nanos) = (self.config().clock)();
+
+        Uuid::new_v6(crate::Timestamp::from_unix(self, seconds, nanos), node_id)
+    }
+
+    /// Create a version 7 UUID using the configured clock source and entropy callback.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the generator hasn't been configured.
+    #[cfg(uuid_unstable)]
+    pub fn now_v7(&self) -> Uuid {
+        let config = self.config();
+
+        let (seconds, nanos) = (config.clock)();
+        // Only 48 bits of the timestamp fit in the UUID
+        let millis = seconds
+            .saturating_mul(1_000)
+            .saturating_add(nanos as u64 / 1_000_000)
+            .min(0xFFFF_FFFF_FFFF);
+
+        let mut random_bytes = [0; 10];
+        (config.entropy)(&mut random_bytes);
+
+        Builder::from_unix_timestamp_millis(millis, &random_bytes).into_uuid()
+    }
+
+    fn config(&self) -> Config {
+        critical_section::with(|cs| {
+            self.state
+                .borrow(cs)
+                .borrow()
+                .as_ref()
+                .map(|state| state.config)
+        })
+        .expect("the uuid generator must be configured before it's used")
+    }
+}
+
+impl ClockSequence for Generator {
+    type Output = u16;
+
+    fn generate_sequence(&self, _seconds: u64, _nanos: u32) -> Self::Output {
+        critical_section::with(|cs| {
+            let mut state = self.state.borrow(cs).borrow_mut();
+            let state = state
+                .as_mut()
+                .expect("the uuid generator must be configured before it's used");
+
+            let counter = state.counter;
+            state.counter = counter.wrapping_add(1);
+
+            // Wrap in the same way as `Context` so the sequence fits in 14 bits
+            counter % (u16::MAX >> 2)
+        })
+    }
+
+    fn usable_bits(&self) -> usize {
+        14
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Variant, Version};
+
+    fn entropy(buf: &mut [u8]) {
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+    }
+
+    fn clock() -> (u64, u32) {
+        (1_496_854_535, 812_946_000)
+    }
+
+    #[test]
+    fn test_generator() {
+        let generator = generator();
+
+        generator.configure(Config::new(entropy, clock)).unwrap();
+
+        assert!(generator.is_configured());
+        assert!(generator.configure(Config::new(entropy, clock)).is_err());
+
+        let uuid = generator.new_v4();
+
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+
+        // The clock sequence is seeded from the entropy callback
+        assert_eq!(generator.generate_sequence(0, 0), 1);
+        assert_eq!(generator.generate_sequence(0, 0), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unconfigured() {
+        let generator = Generator {
+            state: Mutex::new(RefCell::new(None)),
+        };
+
+        generator.new_v4();
+    }
+
+    #[test]
+    #[cfg(uuid_unstable)]
+    fn test_now_v7_far_future() {
+        fn far_future() -> (u64, u32) {
+            (u64::MAX / 2, 0)
+        }
+
+        let generator = Generator {
+            state: Mutex::new(RefCell::new(None)),
+        };
+
+        generator
+            .configure(Config::new(entropy, far_future))
+            .unwrap();
+
+        // Seconds that overflow when converted to milliseconds don't panic,
+        // and times past the end of the 48 bit timestamp are clamped
+        let uuid = generator.now_v7();
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!([0xff; 6], uuid.as_bytes()[..6]);
+    }
+}
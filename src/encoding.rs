@@ -0,0 +1,283 @@
+//! Canonical wire formats for UUIDs.
+//!
+//! Protocols and file formats disagree on how UUIDs are stored. This module
+//! gives each common layout a name, so a crate implementing a format can
+//! declare how its UUIDs are stored by referring to one of them:
+//!
+//! ```
+//! use uuid::{encoding::{DecodeUuid, EncodeUuid, MixedEndian}, Uuid};
+//!
+//! // UUIDs in this format are stored the way Windows stores GUIDs
+//! type Wire = MixedEndian;
+//!
+//! let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+//!
+//! let bytes = Wire::encode(&uuid);
+//! assert_eq!(
+//!     [0xa4, 0xa3, 0xa2, 0xa1, 0xb2, 0xb1, 0xc2, 0xc1, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8],
+//!     bytes,
+//! );
+//!
+//! assert_eq!(uuid, Wire::decode(&bytes)?);
+//! # Ok::<(), uuid::Error>(())
+//! ```
+//!
+//! The text formats are implemented by the adapters in the
+//! [`fmt`](../fmt/index.html) module. They always encode lowercase digits,
+//! and decode either case.
+
+use crate::{error::*, Uuid};
+
+/// A format that UUIDs can be encoded into.
+pub trait EncodeUuid {
+    /// The encoded UUID.
+    type Output: AsRef<[u8]>;
+
+    /// Encode a UUID.
+    fn encode(uuid: &Uuid) -> Self::Output;
+}
+
+/// A format that UUIDs can be decoded from.
+pub trait DecodeUuid {
+    /// Decode a UUID.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `bytes` isn't a UUID in this
+    /// format, including if it has any trailing bytes.
+    fn decode(bytes: &[u8]) -> Result<Uuid, Error>;
+}
+
+/// The 16 bytes of a UUID in big-endian order.
+///
+/// This is the layout described by RFC 9562, and the one used by
+/// [`Uuid::as_bytes`].
+///
+/// [`Uuid::as_bytes`]: ../struct.Uuid.html#method.as_bytes
+#[derive(Debug, Clone, Copy)]
+pub enum BigEndian {}
+
+/// The 16 bytes of a UUID in little-endian order.
+///
+/// This is the big-endian layout reversed, as if the UUID were a
+/// little-endian 128-bit integer. It's the layout used by
+/// [`Uuid::to_u128_le`].
+///
+/// [`Uuid::to_u128_le`]: ../struct.Uuid.html#method.to_u128_le
+#[derive(Debug, Clone, Copy)]
+pub enum LittleEndian {}
+
+/// The 16 bytes of a UUID with its first three fields in little-endian
+/// order.
+///
+/// This is the layout Microsoft uses for GUIDs, and the one used by
+/// [`Uuid::to_bytes_le`].
+///
+/// [`Uuid::to_bytes_le`]: ../struct.Uuid.html#method.to_bytes_le
+#[derive(Debug, Clone, Copy)]
+pub enum MixedEndian {}
+
+impl EncodeUuid for BigEndian {
+    type Output = [u8; 16];
+
+    fn encode(uuid: &Uuid) -> Self::Output {
+        *uuid.as_bytes()
+    }
+}
+
+impl DecodeUuid for BigEndian {
+    fn decode(bytes: &[u8]) -> Result<Uuid, Error> {
+        Uuid::from_slice(bytes)
+    }
+}
+
+impl EncodeUuid for LittleEndian {
+    type Output = [u8; 16];
+
+    fn encode(uuid: &Uuid) -> Self::Output {
+        uuid.as_u128().to_le_bytes()
+    }
+}
+
+impl DecodeUuid for LittleEndian {
+    fn decode(bytes: &[u8]) -> Result<Uuid, Error> {
+        let uuid = Uuid::from_slice(bytes)?;
+
+        Ok(Uuid::from_u128(uuid.to_u128_le()))
+    }
+}
+
+impl EncodeUuid for MixedEndian {
+    type Output = [u8; 16];
+
+    fn encode(uuid: &Uuid) -> Self::Output {
+        uuid.to_bytes_le()
+    }
+}
+
+impl DecodeUuid for MixedEndian {
+    fn decode(bytes: &[u8]) -> Result<Uuid, Error> {
+        Uuid::from_slice_le(bytes)
+    }
+}
+
+#[cfg(not(uuid_core_only))]
+mod text {
+    use super::*;
+    use crate::fmt::{Braced, Hyphenated, Simple, Urn};
+
+    macro_rules! impl_text_encoding {
+        ($($T:ident),*) => {$(
+            impl EncodeUuid for $T {
+                type Output = [u8; $T::LENGTH];
+
+                fn encode(uuid: &Uuid) -> Self::Output {
+                    let mut buf = [0; $T::LENGTH];
+                    $T::from_uuid(*uuid).encode_lower(&mut buf);
+                    buf
+                }
+            }
+        )*}
+    }
+
+    macro_rules! impl_text_decoding {
+        ($($T:ident),*) => {$(
+            impl DecodeUuid for $T {
+                fn decode(bytes: &[u8]) -> Result<Uuid, Error> {
+                    // Check the length first so other formats aren't accepted
+                    if bytes.len() != $T::LENGTH {
+                        return Err(Error(ErrorRepr::EncodedLength {
+                            expected: $T::LENGTH,
+                            len: bytes.len(),
+                        }));
+                    }
+
+                    Uuid::try_parse_ascii(bytes).map_err(|_| InvalidUuid(bytes).into_err())
+                }
+            }
+        )*}
+    }
+
+    impl_text_encoding!(Hyphenated, Simple, Braced, Urn);
+
+    // With the `tiny` feature, only the hyphenated format can be parsed
+    impl_text_decoding!(Hyphenated);
+    #[cfg(not(feature = "tiny"))]
+    impl_text_decoding!(Simple, Braced, Urn);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn uuid() -> Uuid {
+        Uuid::from_u128(0xa1a2a3a4_b1b2_c1c2_d1d2_d3d4d5d6d7d8)
+    }
+
+    fn roundtrip<E: EncodeUuid + DecodeUuid>() -> E::Output {
+        let encoded = E::encode(&uuid());
+
+        assert_eq!(uuid(), E::decode(encoded.as_ref()).unwrap());
+
+        encoded
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_binary() {
+        assert_eq!(
+            [
+                0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6,
+                0xd7, 0xd8,
+            ],
+            roundtrip::<BigEndian>()
+        );
+        assert_eq!(
+            [
+                0xd8, 0xd7, 0xd6, 0xd5, 0xd4, 0xd3, 0xd2, 0xd1, 0xc2, 0xc1, 0xb2, 0xb1, 0xa4, 0xa3,
+                0xa2, 0xa1,
+            ],
+            roundtrip::<LittleEndian>()
+        );
+        assert_eq!(
+            [
+                0xa4, 0xa3, 0xa2, 0xa1, 0xb2, 0xb1, 0xc2, 0xc1, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6,
+                0xd7, 0xd8,
+            ],
+            roundtrip::<MixedEndian>()
+        );
+
+        assert!(BigEndian::decode(&[0; 17]).is_err());
+        assert!(LittleEndian::decode(&[0; 15]).is_err());
+        assert!(MixedEndian::decode(&[]).is_err());
+    }
+
+    #[test]
+    #[cfg(not(uuid_core_only))]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_text() {
+        use crate::fmt::Hyphenated;
+
+        assert_eq!(
+            b"a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+            &roundtrip::<Hyphenated>()
+        );
+
+        assert_eq!(
+            uuid(),
+            Hyphenated::decode(b"A1A2A3A4-B1B2-C1C2-D1D2-D3D4D5D6D7D8").unwrap()
+        );
+
+        // Other formats aren't accepted
+        assert_eq!(
+            Hyphenated::decode(b"a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidLength
+        );
+
+        // With the `tiny` feature, only the hyphenated format can be decoded
+        #[cfg(not(feature = "tiny"))]
+        {
+            use crate::fmt::{Braced, Simple, Urn};
+
+            assert_eq!(b"a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8", &roundtrip::<Simple>());
+            assert_eq!(
+                b"{a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8}",
+                &roundtrip::<Braced>()
+            );
+            assert_eq!(
+                b"urn:uuid:a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+                &roundtrip::<Urn>()
+            );
+
+            assert_eq!(
+                Simple::decode(b"a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7dg")
+                    .unwrap_err()
+                    .kind(),
+                ErrorKind::InvalidCharacter { index: 32 }
+            );
+        }
+    }
+}
@@ -1,11 +1,100 @@
-use crate::std::fmt;
+use crate::{std::fmt, Variant};
 
 /// A general error that can occur when working with UUIDs.
+///
+/// Use [`Error::kind`] to find out what caused the error.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Error(pub(crate) ErrorKind);
+pub struct Error(pub(crate) ErrorRepr);
+
+/// The cause of an [`Error`].
+///
+/// New kinds may be added in future releases, so matches on this type
+/// need a wildcard arm.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input wasn't the right length.
+    ///
+    /// This is returned for strings that don't contain enough digits for
+    /// their format, and for byte slices that don't contain 16 bytes.
+    InvalidLength,
+    /// The input contained an invalid character.
+    InvalidCharacter {
+        /// The 1-based position of the character in the input.
+        index: usize,
+    },
+    /// A hyphenated string didn't contain 5 groups.
+    InvalidGroupCount,
+    /// A group in a hyphenated string wasn't the right length.
+    InvalidGroupLength {
+        /// The 0-based index of the group.
+        group: usize,
+    },
+    /// The input wasn't valid UTF8.
+    InvalidUtf8,
+    /// The UUID had a variant other than the one described in RFC 9562.
+    UnsupportedVariant,
+    /// The UUID had a version that isn't described in RFC 9562.
+    UnsupportedVersion,
+    /// A value was too large for the field it was written to.
+    OutOfRange,
+    /// A timestamp couldn't be produced or represented.
+    ClockError,
+    /// Some other error occurred.
+    Other,
+}
+
+impl Error {
+    /// Get the kind of error this is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ErrorKind, Uuid};
+    /// let err = Uuid::parse_str("67e5504410b1426f9247bb680e5fe0cg").unwrap_err();
+    ///
+    /// assert_eq!(ErrorKind::InvalidCharacter { index: 32 }, err.kind());
+    /// ```
+    pub const fn kind(&self) -> ErrorKind {
+        match self.0 {
+            ErrorRepr::Char { index, .. } => ErrorKind::InvalidCharacter { index },
+            #[cfg(all(any(feature = "v1", feature = "v6"), not(uuid_core_only)))]
+            ErrorRepr::NodeIdChar { index, .. } => ErrorKind::InvalidCharacter { index },
+            #[cfg(not(uuid_core_only))]
+            ErrorRepr::Rfc3339 { index } => ErrorKind::InvalidCharacter { index },
+            ErrorRepr::SimpleLength { .. }
+            | ErrorRepr::ByteLength { .. }
+            | ErrorRepr::NodeLength { .. }
+            | ErrorRepr::EncodedLength { .. } => ErrorKind::InvalidLength,
+            #[cfg(all(any(feature = "v1", feature = "v6"), not(uuid_core_only)))]
+            ErrorRepr::NodeIdLength { .. } => ErrorKind::InvalidLength,
+            ErrorRepr::GroupCount { .. } => ErrorKind::InvalidGroupCount,
+            ErrorRepr::GroupLength { group, .. } => ErrorKind::InvalidGroupLength { group },
+            ErrorRepr::InvalidUTF8 => ErrorKind::InvalidUtf8,
+            ErrorRepr::UnsupportedVariant { .. } => ErrorKind::UnsupportedVariant,
+            ErrorRepr::UnsupportedVersion { .. } => ErrorKind::UnsupportedVersion,
+            #[cfg(not(uuid_core_only))]
+            ErrorRepr::VersionName => ErrorKind::UnsupportedVersion,
+            #[cfg(all(uuid_unstable, any(feature = "v6", feature = "v7")))]
+            ErrorRepr::NotSortable { .. } => ErrorKind::UnsupportedVersion,
+            ErrorRepr::Range { .. } => ErrorKind::OutOfRange,
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            ErrorRepr::TimestampRange => ErrorKind::ClockError,
+            ErrorRepr::TimestampBeforeEpoch => ErrorKind::ClockError,
+            #[cfg(any(feature = "v1", feature = "v6"))]
+            ErrorRepr::ClockSequenceOverflow => ErrorKind::ClockError,
+            #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+            ErrorRepr::MachineId => ErrorKind::Other,
+            #[cfg(all(any(feature = "v1", feature = "v6"), feature = "mac"))]
+            ErrorRepr::NetworkInterface => ErrorKind::Other,
+            ErrorRepr::Other => ErrorKind::Other,
+        }
+    }
+}
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub(crate) enum ErrorKind {
+#[cfg_attr(uuid_core_only, allow(dead_code))]
+pub(crate) enum ErrorRepr {
     /// Invalid character in the [`Uuid`] string.
     ///
     /// [`Uuid`]: ../struct.Uuid.html
@@ -30,6 +119,55 @@ pub(crate) enum ErrorKind {
     },
     /// The input was not a valid UTF8 string
     InvalidUTF8,
+    /// A [`Uuid`] had a variant other than the one described in RFC 9562
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    UnsupportedVariant { variant: Variant },
+    /// A [`Uuid`] had a version that isn't described in RFC 9562
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    UnsupportedVersion { version: usize },
+    /// A string wasn't the name or number of a [`Version`]
+    ///
+    /// [`Version`]: ../enum.Version.html
+    #[cfg(not(uuid_core_only))]
+    VersionName,
+    /// A [`Uuid`] wasn't a version that sorts by its timestamp
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(all(uuid_unstable, any(feature = "v6", feature = "v7")))]
+    NotSortable { version: usize },
+    /// A value was too wide for the field it was written to
+    Range { field: &'static str, bits: u32 },
+    /// A timestamp couldn't be represented in some other time type
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    TimestampRange,
+    /// An RFC4122 timestamp was before the Unix epoch
+    TimestampBeforeEpoch,
+    /// Every clock sequence was already used for a timestamp
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    ClockSequenceOverflow,
+    /// There was no machine ID to read
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+    MachineId,
+    /// A network interface didn't exist or had no hardware address
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "mac"))]
+    NetworkInterface,
+    /// An RFC3339 timestamp string was malformed or had a field out of range
+    #[cfg(not(uuid_core_only))]
+    Rfc3339 { index: usize },
+    /// A node ID didn't contain 6 bytes
+    NodeLength { len: usize },
+    /// A node ID string didn't contain 17 characters
+    #[cfg(all(any(feature = "v1", feature = "v6"), not(uuid_core_only)))]
+    NodeIdLength { len: usize },
+    /// Invalid character in a node ID string
+    #[cfg(all(any(feature = "v1", feature = "v6"), not(uuid_core_only)))]
+    NodeIdChar { character: char, index: usize },
+    /// An encoded [`Uuid`] wasn't the right length for its format
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    EncodedLength { expected: usize, len: usize },
     /// Some other error occurred.
     Other,
 }
@@ -41,16 +179,18 @@ pub(crate) enum ErrorKind {
 /// details. To get details, use `InvalidUuid::into_err`.
 ///
 /// [`Uuid`]: ../struct.Uuid.html
+#[cfg(not(uuid_core_only))]
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct InvalidUuid<'a>(pub(crate) &'a [u8]);
 
+#[cfg(not(uuid_core_only))]
 impl<'a> InvalidUuid<'a> {
     /// Converts the lightweight error type into detailed diagnostics.
     pub fn into_err(self) -> Error {
         // Check whether or not the input was ever actually a valid UTF8 string
         let input_str = match std::str::from_utf8(self.0) {
             Ok(s) => s,
-            Err(_) => return Error(ErrorKind::InvalidUTF8),
+            Err(_) => return Error(ErrorRepr::InvalidUTF8),
         };
 
         let (uuid_str, offset, simple) = match input_str.as_bytes() {
@@ -72,7 +212,7 @@ impl<'a> InvalidUuid<'a> {
             let byte = character as u8;
             if character as u32 - byte as u32 > 0 {
                 // Multibyte char
-                return Error(ErrorKind::Char {
+                return Error(ErrorRepr::Char {
                     character,
                     index: index + offset + 1,
                 });
@@ -84,7 +224,7 @@ impl<'a> InvalidUuid<'a> {
                 hyphen_count += 1;
             } else if !matches!(byte, b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F') {
                 // Non-hex char
-                return Error(ErrorKind::Char {
+                return Error(ErrorRepr::Char {
                     character: byte as char,
                     index: index + offset + 1,
                 });
@@ -95,13 +235,13 @@ impl<'a> InvalidUuid<'a> {
             // This means that we tried and failed to parse a simple uuid.
             // Since we verified that all the characters are valid, this means
             // that it MUST have an invalid length.
-            Error(ErrorKind::SimpleLength {
+            Error(ErrorRepr::SimpleLength {
                 len: input_str.len(),
             })
         } else if hyphen_count != 4 {
             // We tried to parse a hyphenated variant, but there weren't
             // 5 groups (4 hyphen splits).
-            Error(ErrorKind::GroupCount {
+            Error(ErrorRepr::GroupCount {
                 count: hyphen_count + 1,
             })
         } else {
@@ -109,7 +249,7 @@ impl<'a> InvalidUuid<'a> {
             const BLOCK_STARTS: [usize; 5] = [0, 9, 14, 19, 24];
             for i in 0..4 {
                 if group_bounds[i] != BLOCK_STARTS[i + 1] - 1 {
-                    return Error(ErrorKind::GroupLength {
+                    return Error(ErrorRepr::GroupLength {
                         group: i,
                         len: group_bounds[i] - BLOCK_STARTS[i],
                         index: offset + BLOCK_STARTS[i] + 1,
@@ -118,7 +258,7 @@ impl<'a> InvalidUuid<'a> {
             }
 
             // The last group must be too long
-            Error(ErrorKind::GroupLength {
+            Error(ErrorRepr::GroupLength {
                 group: 4,
                 len: input_str.len() - BLOCK_STARTS[4],
                 index: offset + BLOCK_STARTS[4] + 1,
@@ -131,25 +271,25 @@ impl<'a> InvalidUuid<'a> {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.0 {
-            ErrorKind::Char {
+            ErrorRepr::Char {
                 character, index, ..
             } => {
                 write!(f, "invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found `{}` at {}", character, index)
             }
-            ErrorKind::SimpleLength { len } => {
+            ErrorRepr::SimpleLength { len } => {
                 write!(
                     f,
                     "invalid length: expected length 32 for simple format, found {}",
                     len
                 )
             }
-            ErrorKind::ByteLength { len } => {
+            ErrorRepr::ByteLength { len } => {
                 write!(f, "invalid length: expected 16 bytes, found {}", len)
             }
-            ErrorKind::GroupCount { count } => {
+            ErrorRepr::GroupCount { count } => {
                 write!(f, "invalid group count: expected 5, found {}", count)
             }
-            ErrorKind::GroupLength { group, len, .. } => {
+            ErrorRepr::GroupLength { group, len, .. } => {
                 let expected = [8, 4, 4, 4, 12][group];
                 write!(
                     f,
@@ -157,8 +297,99 @@ impl fmt::Display for Error {
                     group, expected, len
                 )
             }
-            ErrorKind::InvalidUTF8 => write!(f, "non-UTF8 input"),
-            ErrorKind::Other => write!(f, "failed to parse a UUID"),
+            ErrorRepr::InvalidUTF8 => write!(f, "non-UTF8 input"),
+            ErrorRepr::UnsupportedVariant { variant } => {
+                write!(
+                    f,
+                    "unsupported variant: expected the RFC 9562 variant, found {:?}",
+                    variant
+                )
+            }
+            ErrorRepr::UnsupportedVersion { version } => {
+                write!(
+                    f,
+                    "unsupported version: expected one of 1, 3, 4, 5, 6, 7, or 8, found {}",
+                    version
+                )
+            }
+            #[cfg(not(uuid_core_only))]
+            ErrorRepr::VersionName => {
+                write!(
+                    f,
+                    "invalid version: expected a name like `Random` or a number like `v4`"
+                )
+            }
+            #[cfg(all(uuid_unstable, any(feature = "v6", feature = "v7")))]
+            ErrorRepr::NotSortable { version } => {
+                write!(
+                    f,
+                    "unsupported version: expected a sortable version 6 or 7 UUID, found {}",
+                    version
+                )
+            }
+            ErrorRepr::Range { field, bits } => {
+                write!(f, "invalid {}: expected at most {} bits", field, bits)
+            }
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            ErrorRepr::TimestampRange => write!(f, "timestamp out of range"),
+            ErrorRepr::TimestampBeforeEpoch => {
+                write!(
+                    f,
+                    "timestamp out of range: expected a time after the Unix epoch"
+                )
+            }
+            #[cfg(not(uuid_core_only))]
+            ErrorRepr::Rfc3339 { index } => {
+                write!(
+                    f,
+                    "invalid RFC3339 timestamp: expected a time like `2017-06-16T14:41:59.000Z`, found an unexpected value at {}",
+                    index
+                )
+            }
+            #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+            ErrorRepr::MachineId => write!(f, "no machine ID is available on this system"),
+            #[cfg(all(any(feature = "v1", feature = "v6"), feature = "mac"))]
+            ErrorRepr::NetworkInterface => {
+                write!(f, "the network interface has no hardware address")
+            }
+            #[cfg(any(feature = "v1", feature = "v6"))]
+            ErrorRepr::ClockSequenceOverflow => {
+                write!(
+                    f,
+                    "clock sequence overflow: every clock sequence was already used for the timestamp"
+                )
+            }
+            ErrorRepr::NodeLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected 6 bytes for a node ID, found {}",
+                    len
+                )
+            }
+            #[cfg(all(any(feature = "v1", feature = "v6"), not(uuid_core_only)))]
+            ErrorRepr::NodeIdLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected 17 characters for a node ID like `01:23:45:67:89:ab`, found {}",
+                    len
+                )
+            }
+            #[cfg(all(any(feature = "v1", feature = "v6"), not(uuid_core_only)))]
+            ErrorRepr::NodeIdChar { character, index } => {
+                write!(
+                    f,
+                    "invalid character in node ID: expected [0-9a-fA-F:], found `{}` at {}",
+                    character, index
+                )
+            }
+            ErrorRepr::EncodedLength { expected, len } => {
+                write!(
+                    f,
+                    "invalid length: expected {} bytes, found {}",
+                    expected, len
+                )
+            }
+            ErrorRepr::Other => write!(f, "failed to parse a UUID"),
         }
     }
 }
@@ -166,7 +397,13 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 mod std_support {
     use super::*;
-    use crate::std::error;
+    use crate::std::{error, io};
 
     impl error::Error for Error {}
+
+    impl From<Error> for io::Error {
+        fn from(err: Error) -> Self {
+            io::Error::new(io::ErrorKind::InvalidData, err)
+        }
+    }
 }
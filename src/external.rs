@@ -2,7 +2,17 @@
 pub(crate) mod arbitrary_support;
 #[cfg(all(uuid_unstable, feature = "borsh"))]
 pub(crate) mod borsh_support;
-#[cfg(feature = "serde")]
+#[cfg(feature = "chrono")]
+pub(crate) mod chrono_support;
+#[cfg(all(feature = "log", not(uuid_core_only)))]
+pub(crate) mod log_support;
+#[cfg(feature = "rand")]
+pub(crate) mod rand_support;
+#[cfg(all(feature = "serde", not(uuid_core_only)))]
 pub(crate) mod serde_support;
-#[cfg(feature = "slog")]
+#[cfg(all(feature = "slog", not(uuid_core_only)))]
 pub(crate) mod slog_support;
+#[cfg(feature = "time")]
+pub(crate) mod time_support;
+#[cfg(all(feature = "valuable", not(uuid_core_only)))]
+pub(crate) mod valuable_support;
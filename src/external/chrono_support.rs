@@ -0,0 +1,103 @@
+use crate::{
+    error::{Error, ErrorRepr},
+    std::convert::TryFrom,
+    ClockSequence, Timestamp,
+};
+
+use chrono::{DateTime, TimeZone, Utc};
+
+impl Timestamp {
+    /// Construct a `Timestamp` from a `chrono` date-time.
+    ///
+    /// This method will return an error if `datetime` is before the Unix
+    /// epoch, since that can't be represented by a `Timestamp`.
+    ///
+    /// Note that usage of this method requires the `chrono` feature of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{TimeZone, Utc};
+    /// # use uuid::{NoContext, Timestamp};
+    /// let datetime = Utc.timestamp_opt(1645557742, 0).unwrap();
+    ///
+    /// let ts = Timestamp::from_chrono(NoContext, datetime)?;
+    ///
+    /// assert_eq!((1645557742, 0), ts.to_unix());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub fn from_chrono(
+        context: impl ClockSequence<Output = impl Into<u128>>,
+        datetime: DateTime<Utc>,
+    ) -> Result<Self, Error> {
+        let seconds =
+            u64::try_from(datetime.timestamp()).map_err(|_| Error(ErrorRepr::TimestampRange))?;
+
+        // `chrono` represents leap seconds as a nanosecond value past
+        // the end of the second, which isn't valid here
+        let nanos = datetime.timestamp_subsec_nanos().min(999_999_999);
+
+        Ok(Timestamp::from_unix(context, seconds, nanos))
+    }
+
+    /// Get the value of the timestamp as a `chrono` date-time.
+    ///
+    /// This method will return an error if the timestamp is too far in the
+    /// future to be represented by `chrono`. That can't happen for
+    /// timestamps read from UUIDs.
+    ///
+    /// Note that usage of this method requires the `chrono` feature of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{TimeZone, Utc};
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// let ts = uuid.get_timestamp().expect("v7 UUIDs have a timestamp");
+    ///
+    /// assert_eq!(Utc.timestamp_opt(1645557742, 0).unwrap(), ts.to_chrono()?);
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub fn to_chrono(&self) -> Result<DateTime<Utc>, Error> {
+        let (seconds, nanos) = self.to_unix();
+
+        i64::try_from(seconds)
+            .ok()
+            .and_then(|seconds| Utc.timestamp_opt(seconds, nanos).single())
+            .ok_or(Error(ErrorRepr::TimestampRange))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NoContext;
+
+    #[test]
+    fn test_chrono_roundtrip() {
+        let datetime = Utc.timestamp_opt(1_645_557_742, 123_456_789).unwrap();
+
+        let ts = Timestamp::from_chrono(NoContext, datetime).unwrap();
+
+        assert_eq!((1_645_557_742, 123_456_789), ts.to_unix());
+        assert_eq!(datetime, ts.to_chrono().unwrap());
+    }
+
+    #[test]
+    fn test_chrono_out_of_range() {
+        let datetime = Utc.timestamp_opt(-1, 0).unwrap();
+
+        assert_eq!(
+            Err(Error(ErrorRepr::TimestampRange)),
+            Timestamp::from_chrono(NoContext, datetime)
+        );
+        assert_eq!(
+            Err(Error(ErrorRepr::TimestampRange)),
+            Timestamp::from_unix(NoContext, u64::MAX, 0).to_chrono()
+        );
+    }
+}
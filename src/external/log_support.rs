@@ -0,0 +1,22 @@
+use crate::Uuid;
+
+use log::kv::{ToValue, Value};
+
+impl ToValue for Uuid {
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{std::string::ToString, tests::new};
+
+    #[test]
+    fn test_log_kv() {
+        let uuid = new();
+
+        assert_eq!(uuid.to_value().to_string(), uuid.hyphenated().to_string());
+    }
+}
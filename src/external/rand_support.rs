@@ -0,0 +1,47 @@
+use crate::Uuid;
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl Distribution<Uuid> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Uuid {
+        Uuid::new_v4_with(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Variant, Version};
+
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn test_sample() {
+        let uuid: Uuid = StepRng::new(42, 7).gen();
+
+        assert_eq!(Some(Version::Random), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(Uuid::new_v4_with(&mut StepRng::new(42, 7)), uuid);
+    }
+
+    #[test]
+    fn test_sample_generic() {
+        fn sample<T, R: Rng>(rng: &mut R) -> T
+        where
+            Standard: Distribution<T>,
+        {
+            rng.gen()
+        }
+
+        let mut rng = StepRng::new(42, 7);
+
+        let a: Uuid = sample(&mut rng);
+        let b: Uuid = sample(&mut rng);
+
+        assert_ne!(a, b);
+    }
+}
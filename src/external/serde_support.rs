@@ -127,6 +127,220 @@ impl<'de> Deserialize<'de> for Uuid {
     }
 }
 
+#[cfg(any(feature = "v1", feature = "v6"))]
+impl Serialize for crate::ContextState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.count)
+    }
+}
+
+#[cfg(any(feature = "v1", feature = "v6"))]
+impl<'de> Deserialize<'de> for crate::ContextState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(crate::ContextState {
+            count: u16::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[cfg(any(feature = "v1", feature = "v6"))]
+impl Serialize for crate::NodeId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+#[cfg(any(feature = "v1", feature = "v6"))]
+impl<'de> Deserialize<'de> for crate::NodeId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use crate::NodeId;
+
+        fn de_error<E: de::Error>(e: Error) -> E {
+            E::custom(format_args!("node ID parsing failed: {}", e))
+        }
+
+        struct NodeIdVisitor;
+
+        impl<'vi> de::Visitor<'vi> for NodeIdVisitor {
+            type Value = NodeId;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a node ID string or bytes")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<NodeId, E> {
+                value.parse::<NodeId>().map_err(de_error)
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<NodeId, E> {
+                NodeId::from_slice(value).map_err(de_error)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(NodeIdVisitor)
+        } else {
+            deserializer.deserialize_bytes(NodeIdVisitor)
+        }
+    }
+}
+
+impl Serialize for crate::Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut ts = serializer.serialize_struct("Timestamp", 4)?;
+
+        ts.serialize_field("seconds", &self.seconds)?;
+        ts.serialize_field("nanos", &self.nanos)?;
+        ts.serialize_field("counter", &self.counter)?;
+        ts.serialize_field("usable_counter_bits", &self.usable_counter_bits)?;
+
+        ts.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use crate::Timestamp;
+
+        const FIELDS: &[&str] = &["seconds", "nanos", "counter", "usable_counter_bits"];
+
+        enum Field {
+            Seconds,
+            Nanos,
+            Counter,
+            UsableCounterBits,
+        }
+
+        impl<'fi> Deserialize<'fi> for Field {
+            fn deserialize<D: Deserializer<'fi>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FieldVisitor;
+
+                impl<'vi> de::Visitor<'vi> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(formatter, "a timestamp field")
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                        match value {
+                            "seconds" => Ok(Field::Seconds),
+                            "nanos" => Ok(Field::Nanos),
+                            "counter" => Ok(Field::Counter),
+                            "usable_counter_bits" => Ok(Field::UsableCounterBits),
+                            _ => Err(E::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        fn to_timestamp<E: de::Error>(
+            seconds: u64,
+            nanos: u32,
+            counter: u128,
+            usable_counter_bits: u8,
+        ) -> Result<Timestamp, E> {
+            if nanos >= 1_000_000_000 {
+                return Err(E::custom(format_args!(
+                    "timestamp nanoseconds out of range: {}",
+                    nanos
+                )));
+            }
+
+            if usable_counter_bits > 128 {
+                return Err(E::custom(format_args!(
+                    "timestamp counter bits out of range: {}",
+                    usable_counter_bits
+                )));
+            }
+
+            Ok(Timestamp {
+                seconds,
+                nanos,
+                counter,
+                usable_counter_bits,
+            })
+        }
+
+        struct TimestampVisitor;
+
+        impl<'vi> de::Visitor<'vi> for TimestampVisitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a timestamp")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Timestamp, A::Error>
+            where
+                A: de::SeqAccess<'vi>,
+            {
+                let seconds = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let nanos = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                let counter = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(2, &self))?;
+                let usable_counter_bits = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(3, &self))?;
+
+                to_timestamp(seconds, nanos, counter, usable_counter_bits)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Timestamp, A::Error>
+            where
+                A: de::MapAccess<'vi>,
+            {
+                let mut seconds = None;
+                let mut nanos = None;
+                let mut counter = None;
+                let mut usable_counter_bits = None;
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Seconds if seconds.is_none() => seconds = Some(map.next_value()?),
+                        Field::Nanos if nanos.is_none() => nanos = Some(map.next_value()?),
+                        Field::Counter if counter.is_none() => counter = Some(map.next_value()?),
+                        Field::UsableCounterBits if usable_counter_bits.is_none() => {
+                            usable_counter_bits = Some(map.next_value()?)
+                        }
+                        Field::Seconds => return Err(A::Error::duplicate_field("seconds")),
+                        Field::Nanos => return Err(A::Error::duplicate_field("nanos")),
+                        Field::Counter => return Err(A::Error::duplicate_field("counter")),
+                        Field::UsableCounterBits => {
+                            return Err(A::Error::duplicate_field("usable_counter_bits"))
+                        }
+                    }
+                }
+
+                to_timestamp(
+                    seconds.ok_or_else(|| A::Error::missing_field("seconds"))?,
+                    nanos.ok_or_else(|| A::Error::missing_field("nanos"))?,
+                    // Timestamps without a counter, like those from version 7 UUIDs,
+                    // can leave it out
+                    counter.unwrap_or(0),
+                    usable_counter_bits.unwrap_or(0),
+                )
+            }
+        }
+
+        deserializer.deserialize_struct("Timestamp", FIELDS, TimestampVisitor)
+    }
+}
+
 pub mod compact {
     //! Serialize a [`Uuid`] as a `[u8; 16]`.
     //!
@@ -207,6 +421,90 @@ pub mod compact {
     }
 }
 
+pub mod strict {
+    //! Deserialize a [`Uuid`], rejecting UUIDs that RFC 9562 doesn't describe.
+    //!
+    //! See [`Uuid::check_strict`] for the UUIDs that are rejected.
+    //! Serialization is the same as the default.
+    //!
+    //! [`Uuid`]: ../../struct.Uuid.html
+    //! [`Uuid::check_strict`]: ../../struct.Uuid.html#method.check_strict
+
+    /// Serialize a [`Uuid`] using its default format
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &crate::Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(u, serializer)
+    }
+
+    /// Deserialize a [`Uuid`] using its default format, then check it
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<crate::Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+
+        let uuid: crate::Uuid = serde::Deserialize::deserialize(deserializer)?;
+
+        uuid.check_strict().map_err(D::Error::custom)?;
+
+        Ok(uuid)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_derive::*;
+        use serde_test::{self, Configure};
+
+        #[derive(Serialize, Debug, Deserialize, PartialEq)]
+        struct UuidContainer {
+            #[serde(with = "crate::serde::strict")]
+            u: crate::Uuid,
+        }
+
+        #[test]
+        fn test_de_strict() {
+            let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+            let container = UuidContainer {
+                u: crate::Uuid::parse_str(uuid_str).unwrap(),
+            };
+
+            serde_test::assert_tokens(
+                &container.readable(),
+                &[
+                    serde_test::Token::Struct {
+                        name: "UuidContainer",
+                        len: 1,
+                    },
+                    serde_test::Token::Str("u"),
+                    serde_test::Token::Str(uuid_str),
+                    serde_test::Token::StructEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_strict_failure() {
+            serde_test::assert_de_tokens_error::<serde_test::Readable<UuidContainer>>(
+                &[
+                    serde_test::Token::Struct {
+                        name: "UuidContainer",
+                        len: 1,
+                    },
+                    serde_test::Token::Str("u"),
+                    serde_test::Token::Str("f9168c5e-ceb2-4faa-d6bf-329bf39fa1e4"),
+                ],
+                "unsupported variant: expected the RFC 9562 variant, found Microsoft",
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod serde_tests {
     use super::*;
@@ -310,4 +608,90 @@ mod serde_tests {
             "UUID parsing failed: invalid length: expected 16 bytes, found 11",
         );
     }
+
+    #[test]
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    fn test_node_id() {
+        let node_id = crate::NodeId::from_bytes([0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+
+        serde_test::assert_tokens(&node_id.readable(), &[Token::Str("01:23:45:67:89:ab")]);
+        serde_test::assert_tokens(
+            &node_id.compact(),
+            &[Token::Bytes(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xab])],
+        );
+
+        serde_test::assert_de_tokens_error::<Compact<crate::NodeId>>(
+            &[Token::Bytes(&[0x01, 0x23])],
+            "node ID parsing failed: invalid length: expected 6 bytes for a node ID, found 2",
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    fn test_context_state() {
+        use crate::{ClockSequence, Context, ContextState};
+
+        let context = Context::new(42);
+        context.generate_sequence(0, 0);
+
+        let state = context.to_state();
+
+        serde_test::assert_tokens(&state, &[Token::U16(43)]);
+
+        let context = Context::from_state(ContextState::from_bytes([0, 43]));
+        assert_eq!(43, context.generate_sequence(0, 0));
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let ts = crate::Timestamp {
+            seconds: 1_496_854_535,
+            nanos: 812_946_000,
+            counter: 42,
+            usable_counter_bits: 14,
+        };
+
+        // `serde_test` doesn't support 128-bit integers, so round-trip through JSON
+        let json = serde_json::to_string(&ts).unwrap();
+
+        assert_eq!(
+            r#"{"seconds":1496854535,"nanos":812946000,"counter":42,"usable_counter_bits":14}"#,
+            json
+        );
+        assert_eq!(ts, serde_json::from_str::<crate::Timestamp>(&json).unwrap());
+
+        serde_test::assert_de_tokens(
+            &crate::Timestamp {
+                counter: 0,
+                usable_counter_bits: 0,
+                ..ts
+            },
+            &[
+                Token::Struct {
+                    name: "Timestamp",
+                    len: 2,
+                },
+                Token::Str("seconds"),
+                Token::U64(1_496_854_535),
+                Token::Str("nanos"),
+                Token::U32(812_946_000),
+                Token::StructEnd,
+            ],
+        );
+
+        serde_test::assert_de_tokens_error::<crate::Timestamp>(
+            &[
+                Token::Struct {
+                    name: "Timestamp",
+                    len: 2,
+                },
+                Token::Str("seconds"),
+                Token::U64(1_496_854_535),
+                Token::Str("nanos"),
+                Token::U32(1_000_000_000),
+                Token::StructEnd,
+            ],
+            "timestamp nanoseconds out of range: 1000000000",
+        );
+    }
 }
@@ -0,0 +1,106 @@
+use crate::{
+    error::{Error, ErrorRepr},
+    std::convert::TryFrom,
+    ClockSequence, Timestamp,
+};
+
+use time::OffsetDateTime;
+
+impl Timestamp {
+    /// Construct a `Timestamp` from a `time` date-time.
+    ///
+    /// This method will return an error if `datetime` is before the Unix
+    /// epoch, since that can't be represented by a `Timestamp`.
+    ///
+    /// Note that usage of this method requires the `time` feature of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use time::OffsetDateTime;
+    /// # use uuid::{NoContext, Timestamp};
+    /// let datetime = OffsetDateTime::from_unix_timestamp(1645557742).unwrap();
+    ///
+    /// let ts = Timestamp::from_offset_datetime(NoContext, datetime)?;
+    ///
+    /// assert_eq!((1645557742, 0), ts.to_unix());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub fn from_offset_datetime(
+        context: impl ClockSequence<Output = impl Into<u128>>,
+        datetime: OffsetDateTime,
+    ) -> Result<Self, Error> {
+        let seconds = u64::try_from(datetime.unix_timestamp())
+            .map_err(|_| Error(ErrorRepr::TimestampRange))?;
+
+        Ok(Timestamp::from_unix(
+            context,
+            seconds,
+            datetime.nanosecond(),
+        ))
+    }
+
+    /// Get the value of the timestamp as a `time` date-time in UTC.
+    ///
+    /// This method will return an error if the timestamp is too far in the
+    /// future to be represented by `time`. That can happen for timestamps
+    /// read from version 1 and 6 UUIDs past the year 9999 unless `time`'s
+    /// `large-dates` feature is enabled.
+    ///
+    /// Note that usage of this method requires the `time` feature of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use time::OffsetDateTime;
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// let ts = uuid.get_timestamp().expect("v7 UUIDs have a timestamp");
+    ///
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp(1645557742).unwrap(),
+    ///     ts.to_offset_datetime()?,
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub fn to_offset_datetime(&self) -> Result<OffsetDateTime, Error> {
+        let (seconds, nanos) = self.to_unix();
+
+        OffsetDateTime::from_unix_timestamp_nanos(seconds as i128 * 1_000_000_000 + nanos as i128)
+            .map_err(|_| Error(ErrorRepr::TimestampRange))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NoContext;
+
+    #[test]
+    fn test_offset_datetime_roundtrip() {
+        let datetime =
+            OffsetDateTime::from_unix_timestamp_nanos(1_645_557_742_123_456_789).unwrap();
+
+        let ts = Timestamp::from_offset_datetime(NoContext, datetime).unwrap();
+
+        assert_eq!((1_645_557_742, 123_456_789), ts.to_unix());
+        assert_eq!(datetime, ts.to_offset_datetime().unwrap());
+    }
+
+    #[test]
+    fn test_offset_datetime_out_of_range() {
+        let datetime = OffsetDateTime::from_unix_timestamp(-1).unwrap();
+
+        assert_eq!(
+            Err(Error(ErrorRepr::TimestampRange)),
+            Timestamp::from_offset_datetime(NoContext, datetime)
+        );
+        assert_eq!(
+            Err(Error(ErrorRepr::TimestampRange)),
+            Timestamp::from_unix(NoContext, u64::MAX, 0).to_offset_datetime()
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use crate::Uuid;
+
+use valuable::{Valuable, Value, Visit};
+
+// `Value::String` has to borrow from the value itself, and a `Uuid` only
+// stores its raw bytes, so it's recorded as its 128-bit value instead.
+impl Valuable for Uuid {
+    fn as_value(&self) -> Value<'_> {
+        Value::U128(self.as_u128())
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_value(self.as_value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::new;
+
+    #[test]
+    fn test_valuable() {
+        let uuid = new();
+
+        match uuid.as_value() {
+            Value::U128(v) => assert_eq!(Uuid::from_u128(v), uuid),
+            _ => panic!("expected a u128 value"),
+        }
+    }
+}
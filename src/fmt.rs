@@ -12,8 +12,8 @@
 //! Adapters for alternative string formats.
 
 use crate::{
-    std::{borrow::Borrow, fmt, ptr, str},
-    Uuid, Variant,
+    std::{borrow::Borrow, fmt, str},
+    Uuid, Variant, Version,
 };
 
 impl std::fmt::Debug for Uuid {
@@ -29,6 +29,16 @@ impl fmt::Display for Uuid {
     }
 }
 
+// This avoids going through `fmt::Display`, which `ToString` can't
+// be specialized to skip on stable Rust
+#[cfg(feature = "alloc")]
+impl From<Uuid> for alloc::string::String {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self::from(uuid.hyphenated())
+    }
+}
+
 impl fmt::Display for Variant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -40,6 +50,26 @@ impl fmt::Display for Variant {
     }
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Version::Nil => write!(f, "Nil"),
+            Version::Mac => write!(f, "Mac"),
+            Version::Dce => write!(f, "Dce"),
+            Version::Md5 => write!(f, "Md5"),
+            Version::Random => write!(f, "Random"),
+            Version::Sha1 => write!(f, "Sha1"),
+            #[cfg(uuid_unstable)]
+            Version::SortMac => write!(f, "SortMac"),
+            #[cfg(uuid_unstable)]
+            Version::SortRand => write!(f, "SortRand"),
+            #[cfg(uuid_unstable)]
+            Version::Custom => write!(f, "Custom"),
+            Version::Max => write!(f, "Max"),
+        }
+    }
+}
+
 impl fmt::LowerHex for Uuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::LowerHex::fmt(self.as_hyphenated(), f)
@@ -131,78 +161,159 @@ impl Uuid {
     }
 }
 
-const UPPER: [u8; 16] = [
+#[cfg(not(feature = "tiny"))]
+pub(crate) const UPPER: [u8; 16] = [
     b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F',
 ];
-const LOWER: [u8; 16] = [
+#[cfg(not(feature = "tiny"))]
+pub(crate) const LOWER: [u8; 16] = [
     b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b', b'c', b'd', b'e', b'f',
 ];
 
+// Each byte is encoded as a pair of hex digits with a single lookup.
+// The first 256 entries are lowercase and the last 256 are uppercase.
+#[cfg(not(feature = "tiny"))]
+const HEX_PAIRS: [u16; 512] = {
+    let mut table = [0; 512];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = u16::from_ne_bytes([LOWER[i >> 4], LOWER[i & 0x0f]]);
+        table[i + 256] = u16::from_ne_bytes([UPPER[i >> 4], UPPER[i & 0x0f]]);
+        i += 1;
+    }
+    table
+};
+
+#[cfg(not(feature = "tiny"))]
+#[inline]
+const fn hex_pair(x: u8, upper: bool) -> [u8; 2] {
+    let offset = if upper { 256 } else { 0 };
+    HEX_PAIRS[x as usize + offset].to_ne_bytes()
+}
+
+#[cfg(not(feature = "tiny"))]
 #[inline]
 const fn format_simple(src: &[u8; 16], upper: bool) -> [u8; 32] {
-    let lut = if upper { &UPPER } else { &LOWER };
     let mut dst = [0; 32];
     let mut i = 0;
     while i < 16 {
-        let x = src[i];
-        dst[i * 2] = lut[(x >> 4) as usize];
-        dst[i * 2 + 1] = lut[(x & 0x0f) as usize];
+        let [hi, lo] = hex_pair(src[i], upper);
+        dst[i * 2] = hi;
+        dst[i * 2 + 1] = lo;
         i += 1;
     }
     dst
 }
 
+#[cfg(not(feature = "tiny"))]
 #[inline]
 const fn format_hyphenated(src: &[u8; 16], upper: bool) -> [u8; 36] {
-    let lut = if upper { &UPPER } else { &LOWER };
-    let groups = [(0, 8), (9, 13), (14, 18), (19, 23), (24, 36)];
+    // The position in the output of the digits for each byte in the input
+    const OFFSETS: [usize; 16] = [0, 2, 4, 6, 9, 11, 14, 16, 19, 21, 24, 26, 28, 30, 32, 34];
+
     let mut dst = [0; 36];
+    dst[8] = b'-';
+    dst[13] = b'-';
+    dst[18] = b'-';
+    dst[23] = b'-';
 
-    let mut group_idx = 0;
     let mut i = 0;
-    while group_idx < 5 {
-        let (start, end) = groups[group_idx];
-        let mut j = start;
-        while j < end {
-            let x = src[i];
-            i += 1;
-
-            dst[j] = lut[(x >> 4) as usize];
-            dst[j + 1] = lut[(x & 0x0f) as usize];
-            j += 2;
-        }
-        if group_idx < 4 {
-            dst[end] = b'-';
-        }
-        group_idx += 1;
+    while i < 16 {
+        let [hi, lo] = hex_pair(src[i], upper);
+        dst[OFFSETS[i]] = hi;
+        dst[OFFSETS[i] + 1] = lo;
+        i += 1;
     }
     dst
 }
 
+#[cfg(not(feature = "tiny"))]
+#[inline]
+fn write_simple(src: &[u8; 16], dst: &mut [u8], upper: bool) {
+    let dst = &mut dst[..Simple::LENGTH];
+
+    #[cfg(feature = "simd")]
+    let encoded =
+        crate::simd::format_simple(src, upper).unwrap_or_else(|| format_simple(src, upper));
+    #[cfg(not(feature = "simd"))]
+    let encoded = format_simple(src, upper);
+
+    // SAFETY: `dst` is guaranteed to be at least `LEN` bytes
+    unsafe { std::ptr::write(dst.as_mut_ptr().cast(), encoded) }
+}
+
+#[cfg(not(feature = "tiny"))]
+#[inline]
+fn write_hyphenated(src: &[u8; 16], dst: &mut [u8], upper: bool) {
+    let dst = &mut dst[..Hyphenated::LENGTH];
+
+    #[cfg(feature = "simd")]
+    let encoded =
+        crate::simd::format_hyphenated(src, upper).unwrap_or_else(|| format_hyphenated(src, upper));
+    #[cfg(not(feature = "simd"))]
+    let encoded = format_hyphenated(src, upper);
+
+    // SAFETY: `dst` is guaranteed to be at least `LEN` bytes
+    unsafe { std::ptr::write(dst.as_mut_ptr().cast(), encoded) }
+}
+
+// With the `tiny` feature, hex digits are computed one byte at a time
+// and written directly into the output instead of going through lookup
+// tables and intermediate arrays. This is slower, but smaller.
+
+#[cfg(feature = "tiny")]
+#[inline]
+const fn hex_digit(nibble: u8, upper: bool) -> u8 {
+    match nibble {
+        0..=9 => b'0' + nibble,
+        _ if upper => b'A' + nibble - 10,
+        _ => b'a' + nibble - 10,
+    }
+}
+
+#[cfg(feature = "tiny")]
+fn write_simple(src: &[u8; 16], dst: &mut [u8], upper: bool) {
+    let dst = &mut dst[..Simple::LENGTH];
+
+    for (i, x) in src.iter().enumerate() {
+        dst[i * 2] = hex_digit(x >> 4, upper);
+        dst[i * 2 + 1] = hex_digit(x & 0x0f, upper);
+    }
+}
+
+#[cfg(feature = "tiny")]
+fn write_hyphenated(src: &[u8; 16], dst: &mut [u8], upper: bool) {
+    let dst = &mut dst[..Hyphenated::LENGTH];
+
+    let mut j = 0;
+    for (i, x) in src.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            dst[j] = b'-';
+            j += 1;
+        }
+
+        dst[j] = hex_digit(x >> 4, upper);
+        dst[j + 1] = hex_digit(x & 0x0f, upper);
+        j += 2;
+    }
+}
+
 #[inline]
 fn encode_simple<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
     let buf = &mut buffer[..Simple::LENGTH];
-    let dst = buf.as_mut_ptr();
+    write_simple(src, buf, upper);
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
     // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        ptr::write(dst.cast(), format_simple(src, upper));
-        str::from_utf8_unchecked_mut(buf)
-    }
+    unsafe { str::from_utf8_unchecked_mut(buf) }
 }
 
 #[inline]
 fn encode_hyphenated<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
     let buf = &mut buffer[..Hyphenated::LENGTH];
-    let dst = buf.as_mut_ptr();
+    write_hyphenated(src, buf, upper);
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
     // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        ptr::write(dst.cast(), format_hyphenated(src, upper));
-        str::from_utf8_unchecked_mut(buf)
-    }
+    unsafe { str::from_utf8_unchecked_mut(buf) }
 }
 
 #[inline]
@@ -210,30 +321,20 @@ fn encode_braced<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b m
     let buf = &mut buffer[..Braced::LENGTH];
     buf[0] = b'{';
     buf[Braced::LENGTH - 1] = b'}';
+    write_hyphenated(src, &mut buf[1..], upper);
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
     // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        let dst = buf.as_mut_ptr().add(1);
-
-        ptr::write(dst.cast(), format_hyphenated(src, upper));
-        str::from_utf8_unchecked_mut(buf)
-    }
+    unsafe { str::from_utf8_unchecked_mut(buf) }
 }
 
 #[inline]
 fn encode_urn<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
     let buf = &mut buffer[..Urn::LENGTH];
     buf[..9].copy_from_slice(b"urn:uuid:");
+    write_hyphenated(src, &mut buf[9..], upper);
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
     // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        let dst = buf.as_mut_ptr().add(9);
-
-        ptr::write(dst.cast(), format_hyphenated(src, upper));
-        str::from_utf8_unchecked_mut(buf)
-    }
+    unsafe { str::from_utf8_unchecked_mut(buf) }
 }
 
 impl Hyphenated {
@@ -275,7 +376,7 @@ impl Hyphenated {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936DA01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936DA01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -326,7 +427,7 @@ impl Hyphenated {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -352,6 +453,106 @@ impl Hyphenated {
         encode_hyphenated(self.0.as_bytes(), buffer, true)
     }
 
+    /// Writes the [`Uuid`] as a lower-case hyphenated string to an exactly
+    /// sized `buffer`, and returns the buffer as a string.
+    ///
+    /// This is the same as [`encode_lower`], but since the buffer's length
+    /// is known at compile time it can't panic, and the compiler doesn't
+    /// need to check its bounds.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`encode_lower`]: #method.encode_lower
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Hyphenated, Uuid};
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
+    ///
+    ///     let mut buf = [0; Hyphenated::LENGTH];
+    ///     assert_eq!(
+    ///         uuid.hyphenated().encode_lower_array(&mut buf),
+    ///         "936da01f-9abd-4d9d-80c7-02af85c822a8"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_lower_array<'buf>(&self, buffer: &'buf mut [u8; 36]) -> &'buf mut str {
+        encode_hyphenated(self.0.as_bytes(), buffer, false)
+    }
+
+    /// Writes the [`Uuid`] as an upper-case hyphenated string to an exactly
+    /// sized `buffer`, and returns the buffer as a string.
+    ///
+    /// This is the same as [`encode_upper`], but since the buffer's length
+    /// is known at compile time it can't panic, and the compiler doesn't
+    /// need to check its bounds.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`encode_upper`]: #method.encode_upper
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Hyphenated, Uuid};
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
+    ///
+    ///     let mut buf = [0; Hyphenated::LENGTH];
+    ///     assert_eq!(
+    ///         uuid.hyphenated().encode_upper_array(&mut buf),
+    ///         "936DA01F-9ABD-4D9D-80C7-02AF85C822A8"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_upper_array<'buf>(&self, buffer: &'buf mut [u8; 36]) -> &'buf mut str {
+        encode_hyphenated(self.0.as_bytes(), buffer, true)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case hyphenated string followed by a
+    /// NUL terminator to `buffer`, and returns the buffer as a C string.
+    ///
+    /// This is useful when passing UUIDs across FFI boundaries that expect
+    /// NUL-terminated strings, since it avoids allocating a `CString`.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936DA01f-9abd-4d9d-80c7-02af85c822a8")?;
+    ///
+    ///     let mut buf = [0; 37];
+    ///     assert_eq!(
+    ///         uuid.hyphenated().encode_cstr(&mut buf).to_bytes_with_nul(),
+    ///         b"936da01f-9abd-4d9d-80c7-02af85c822a8\0"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn encode_cstr<'buf>(&self, buffer: &'buf mut [u8; 37]) -> &'buf std::ffi::CStr {
+        encode_hyphenated(self.0.as_bytes(), buffer, false);
+        buffer[Self::LENGTH] = 0;
+
+        // SAFETY: The encoded buffer is ASCII hex digits and hyphens
+        // followed by a single NUL terminator
+        unsafe { std::ffi::CStr::from_bytes_with_nul_unchecked(buffer) }
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -420,7 +621,7 @@ impl Braced {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936DA01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936DA01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -471,7 +672,7 @@ impl Braced {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -564,7 +765,7 @@ impl Simple {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936DA01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936DA01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -612,7 +813,7 @@ impl Simple {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -640,6 +841,70 @@ impl Simple {
         encode_simple(self.0.as_bytes(), buffer, true)
     }
 
+    /// Writes the [`Uuid`] as a lower-case simple string to an exactly
+    /// sized `buffer`, and returns the buffer as a string.
+    ///
+    /// This is the same as [`encode_lower`], but since the buffer's length
+    /// is known at compile time it can't panic, and the compiler doesn't
+    /// need to check its bounds.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`encode_lower`]: #method.encode_lower
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Simple, Uuid};
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
+    ///
+    ///     let mut buf = [0; Simple::LENGTH];
+    ///     assert_eq!(
+    ///         uuid.simple().encode_lower_array(&mut buf),
+    ///         "936da01f9abd4d9d80c702af85c822a8"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_lower_array<'buf>(&self, buffer: &'buf mut [u8; 32]) -> &'buf mut str {
+        encode_simple(self.0.as_bytes(), buffer, false)
+    }
+
+    /// Writes the [`Uuid`] as an upper-case simple string to an exactly
+    /// sized `buffer`, and returns the buffer as a string.
+    ///
+    /// This is the same as [`encode_upper`], but since the buffer's length
+    /// is known at compile time it can't panic, and the compiler doesn't
+    /// need to check its bounds.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`encode_upper`]: #method.encode_upper
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Simple, Uuid};
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
+    ///
+    ///     let mut buf = [0; Simple::LENGTH];
+    ///     assert_eq!(
+    ///         uuid.simple().encode_upper_array(&mut buf),
+    ///         "936DA01F9ABD4D9D80C702AF85C822A8"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_upper_array<'buf>(&self, buffer: &'buf mut [u8; 32]) -> &'buf mut str {
+        encode_simple(self.0.as_bytes(), buffer, true)
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -708,7 +973,7 @@ impl Urn {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936DA01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936DA01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -762,7 +1027,7 @@ impl Urn {
     /// use uuid::Uuid;
     ///
     /// fn main() -> Result<(), uuid::Error> {
-    ///     let uuid = Uuid::parse_str("936da01f9abd4d9d80c702af85c822a8")?;
+    ///     let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
     ///
     ///     // the encoded portion is returned
     ///     assert_eq!(
@@ -860,6 +1125,15 @@ macro_rules! impl_fmt_from {
             }
         }
 
+        #[cfg(feature = "alloc")]
+        impl From<$T> for alloc::string::String {
+            #[inline]
+            fn from(f: $T) -> Self {
+                // Format on the stack, then copy once into an exactly sized string
+                Self::from(f.encode_lower(&mut [0; $T::LENGTH]))
+            }
+        }
+
         impl AsRef<Uuid> for $T {
             #[inline]
             fn as_ref(&self) -> &Uuid {
@@ -916,6 +1190,26 @@ impl_fmt_traits! {
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn string_from() {
+        use crate::std::string::{String, ToString};
+
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+
+        let s = String::from(uuid);
+        assert_eq!(s, uuid.to_string());
+        assert_eq!(s.capacity(), Hyphenated::LENGTH);
+
+        assert_eq!(
+            String::from(uuid.hyphenated()),
+            uuid.hyphenated().to_string()
+        );
+        assert_eq!(String::from(uuid.simple()), uuid.simple().to_string());
+        assert_eq!(String::from(uuid.urn()), uuid.urn().to_string());
+        assert_eq!(String::from(uuid.braced()), uuid.braced().to_string());
+    }
+
     #[test]
     fn hyphenated_trailing() {
         let mut buf = [b'x'; 100];
@@ -1004,6 +1298,19 @@ mod tests {
         Uuid::nil().braced().encode_lower(&mut [0; 37]);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn hyphenated_cstr() {
+        let mut buf = [b'x'; 37];
+        let cstr = Uuid::nil().hyphenated().encode_cstr(&mut buf);
+
+        assert_eq!(
+            cstr.to_bytes(),
+            b"00000000-0000-0000-0000-000000000000" as &[_]
+        );
+        assert_eq!(buf[Hyphenated::LENGTH], 0);
+    }
+
     #[test]
     fn hyphenated_to_inner() {
         let hyphenated = Uuid::nil().hyphenated();
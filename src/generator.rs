@@ -0,0 +1,188 @@
+//! Rerouting the generation of new UUIDs through your own source.
+//!
+//! Note that you need to enable the `custom-generator` Cargo feature in
+//! order to use this module.
+
+use crate::{
+    std::{
+        cell::UnsafeCell,
+        hint,
+        sync::atomic::{AtomicBool, Ordering},
+    },
+    Uuid, Version,
+};
+
+// The installed source
+static GENERATOR: Slot = Slot {
+    locked: AtomicBool::new(false),
+    source: UnsafeCell::new(None),
+};
+
+// A reference to a source is too wide to store in a single atomic, so it's
+// kept behind a spin lock. The lock is only held while the reference is
+// copied in or out, never while a source is generating UUIDs
+struct Slot {
+    locked: AtomicBool,
+    source: UnsafeCell<Option<&'static dyn UuidSource>>,
+}
+
+// SAFETY: The source is only accessed by the thread holding the lock
+unsafe impl Sync for Slot {}
+
+impl Slot {
+    fn with<R>(&self, f: impl FnOnce(&mut Option<&'static dyn UuidSource>) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            hint::spin_loop();
+        }
+
+        // SAFETY: The lock is held, so nothing else is accessing the source
+        let result = f(unsafe { &mut *self.source.get() });
+
+        self.locked.store(false, Ordering::Release);
+
+        result
+    }
+}
+
+/// A source of new UUIDs that can be installed with [`set_generator`].
+///
+/// Returning `None` from [`UuidSource::generate`] falls back to the
+/// default generator for that version, so a source only needs to handle
+/// the versions it cares about.
+pub trait UuidSource: Sync {
+    /// Generate a new UUID of the given version.
+    ///
+    /// This is called with [`Version::Random`] in place of [`Uuid::new_v4`],
+    /// and with `Version::SortRand` in place of `Uuid::now_v7`. The UUID
+    /// returned should be of the requested version.
+    fn generate(&self, version: Version) -> Option<Uuid>;
+}
+
+/// Install a source for every new UUID created through [`Uuid::new_v4`]
+/// and `Uuid::now_v7`, including those created in batches.
+///
+/// This is useful for integration tests and deterministic replay, where
+/// every UUID an application creates needs to be controlled without
+/// threading a generator through every layer that creates them. Installing
+/// another source replaces the previous one. Use [`reset_generator`] to go
+/// back to the default generators.
+///
+/// The source is process-wide, so tests that install one shouldn't run in
+/// parallel with tests that depend on the default generators.
+///
+/// Note that you need to enable the `custom-generator` Cargo feature
+/// in order to use this function.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{UuidSource, Uuid, Version};
+/// struct Fixed;
+///
+/// impl UuidSource for Fixed {
+///     fn generate(&self, version: Version) -> Option<Uuid> {
+///         match version {
+///             Version::Random => Some(uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8")),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// uuid::set_generator(&Fixed);
+///
+/// assert_eq!(uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8"), Uuid::new_v4());
+///
+/// uuid::reset_generator();
+///
+/// assert_ne!(uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8"), Uuid::new_v4());
+/// ```
+pub fn set_generator(source: &'static dyn UuidSource) {
+    GENERATOR.with(|installed| *installed = Some(source));
+}
+
+/// Remove the source installed by [`set_generator`], going back to the
+/// default generators.
+///
+/// Note that you need to enable the `custom-generator` Cargo feature
+/// in order to use this function.
+pub fn reset_generator() {
+    GENERATOR.with(|installed| *installed = None);
+}
+
+// Whether a source has been installed
+#[cfg(any(
+    feature = "v4",
+    all(
+        uuid_unstable,
+        feature = "v7",
+        any(
+            feature = "std",
+            all(feature = "uefi", target_os = "uefi"),
+            feature = "custom-clock"
+        )
+    )
+))]
+pub(crate) fn is_set() -> bool {
+    GENERATOR.with(|installed| installed.is_some())
+}
+
+// Get a UUID from the installed source, if there is one
+#[cfg(any(
+    feature = "v4",
+    all(
+        uuid_unstable,
+        feature = "v7",
+        any(
+            feature = "std",
+            all(feature = "uefi", target_os = "uefi"),
+            feature = "custom-clock"
+        )
+    )
+))]
+pub(crate) fn generate(version: Version) -> Option<Uuid> {
+    // The lock is released before generating, so sources can create
+    // UUIDs of other versions themselves
+    let source = GENERATOR.with(|installed| *installed)?;
+
+    source.generate(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "v4")]
+    fn test_default_generator() {
+        // Installing a source is tested in its own binary, so the default
+        // generators are always used here
+        assert!(!is_set());
+        assert_eq!(None, generate(Version::Random));
+
+        let mut uuids = [Uuid::nil(); 4];
+        Uuid::fill_v4(&mut uuids);
+
+        for uuid in uuids.iter().chain(&[Uuid::new_v4()]) {
+            assert_eq!(Some(Version::Random), uuid.get_version());
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Incrementally hashing names into UUIDs.
+//!
+//! Note that you need to enable the `v3` or `v5` Cargo feature in order to
+//! use this module.
+
+use crate::{std::fmt, Builder, Uuid};
+
+/// A streaming hasher for name-based UUIDs.
+///
+/// [`Uuid::new_v3`] and [`Uuid::new_v5`] need the whole name up-front.
+/// A `NameHasher` accepts the name in chunks instead, so UUIDs can be
+/// derived from large files or network streams without buffering them.
+/// Feeding a name through a `NameHasher` produces the same UUID as passing
+/// it to `new_v3` or `new_v5` in one go, regardless of how it's split up.
+///
+/// When the `std` feature is enabled, `NameHasher` also implements
+/// [`std::io::Write`], so it can be used with [`std::io::copy`].
+///
+/// # Examples
+///
+/// Hashing a name in chunks:
+///
+/// ```
+/// # use uuid::{NameHasher, Uuid};
+/// let uuid = NameHasher::new_v5(&Uuid::NAMESPACE_DNS)
+///     .update(b"rust-")
+///     .update(b"lang.org")
+///     .finish();
+///
+/// assert_eq!(Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org"), uuid);
+/// ```
+#[derive(Clone)]
+pub struct NameHasher(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    #[cfg(feature = "v3")]
+    Md5(crate::md5::Hasher),
+    #[cfg(feature = "v5")]
+    Sha1(crate::sha1::Hasher),
+}
+
+impl NameHasher {
+    /// Start hashing a name from a namespace for a version 3 UUID, based
+    /// on the MD5 hash.
+    ///
+    /// Note that usage of this method requires the `v3` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NameHasher, Uuid, Version};
+    /// let mut hasher = NameHasher::new_v3(&Uuid::NAMESPACE_URL);
+    ///
+    /// for chunk in ["https://", "rust-lang.org"] {
+    ///     hasher.update(chunk.as_bytes());
+    /// }
+    ///
+    /// let uuid = hasher.finish();
+    ///
+    /// assert_eq!(Some(Version::Md5), uuid.get_version());
+    /// assert_eq!(Uuid::new_v3(&Uuid::NAMESPACE_URL, b"https://rust-lang.org"), uuid);
+    /// ```
+    #[cfg(feature = "v3")]
+    pub fn new_v3(namespace: &Uuid) -> Self {
+        NameHasher(Repr::Md5(crate::md5::Hasher::new(namespace.as_bytes())))
+    }
+
+    /// Start hashing a name from a namespace for a version 5 UUID, based
+    /// on the SHA-1 hash.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NameHasher, Uuid, Version};
+    /// let mut hasher = NameHasher::new_v5(&Uuid::NAMESPACE_URL);
+    ///
+    /// for chunk in ["https://", "rust-lang.org"] {
+    ///     hasher.update(chunk.as_bytes());
+    /// }
+    ///
+    /// let uuid = hasher.finish();
+    ///
+    /// assert_eq!(Some(Version::Sha1), uuid.get_version());
+    /// assert_eq!(Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://rust-lang.org"), uuid);
+    /// ```
+    #[cfg(feature = "v5")]
+    pub fn new_v5(namespace: &Uuid) -> Self {
+        NameHasher(Repr::Sha1(crate::sha1::Hasher::new(namespace.as_bytes())))
+    }
+
+    /// Feed the next chunk of the name into the hasher.
+    pub fn update(&mut self, chunk: &[u8]) -> &mut Self {
+        match self.0 {
+            #[cfg(feature = "v3")]
+            Repr::Md5(ref mut hasher) => hasher.update(chunk),
+            #[cfg(feature = "v5")]
+            Repr::Sha1(ref mut hasher) => hasher.update(chunk),
+        }
+
+        self
+    }
+
+    /// Get the UUID for the name hashed so far.
+    ///
+    /// The hasher isn't consumed, so more of the name can be fed in
+    /// afterwards.
+    pub fn finish(&self) -> Uuid {
+        match self.0 {
+            #[cfg(feature = "v3")]
+            Repr::Md5(ref hasher) => Builder::from_md5_bytes(hasher.finish()).into_uuid(),
+            #[cfg(feature = "v5")]
+            Repr::Sha1(ref hasher) => Builder::from_sha1_bytes(hasher.finish()).into_uuid(),
+        }
+    }
+}
+
+impl fmt::Debug for NameHasher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let algorithm = match self.0 {
+            #[cfg(feature = "v3")]
+            Repr::Md5(_) => "md5",
+            #[cfg(feature = "v5")]
+            Repr::Sha1(_) => "sha1",
+        };
+
+        f.debug_struct("NameHasher")
+            .field("algorithm", &algorithm)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for NameHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    const NAME: &[u8] = b"a name that is long enough to be split up into several chunks";
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "v3")]
+    fn test_new_v3_chunked() {
+        let expected = Uuid::new_v3(&Uuid::NAMESPACE_OID, NAME);
+
+        for size in 1..=NAME.len() {
+            let mut hasher = NameHasher::new_v3(&Uuid::NAMESPACE_OID);
+
+            for chunk in NAME.chunks(size) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(expected, hasher.finish());
+        }
+
+        assert_eq!(
+            Uuid::new_v3(&Uuid::NAMESPACE_OID, b""),
+            NameHasher::new_v3(&Uuid::NAMESPACE_OID).finish()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "v5")]
+    fn test_new_v5_chunked() {
+        let expected = Uuid::new_v5(&Uuid::NAMESPACE_OID, NAME);
+
+        for size in 1..=NAME.len() {
+            let mut hasher = NameHasher::new_v5(&Uuid::NAMESPACE_OID);
+
+            for chunk in NAME.chunks(size) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(expected, hasher.finish());
+        }
+
+        assert_eq!(
+            Uuid::new_v5(&Uuid::NAMESPACE_OID, b""),
+            NameHasher::new_v5(&Uuid::NAMESPACE_OID).finish()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "v5"))]
+    fn test_io_write() {
+        let mut hasher = NameHasher::new_v5(&Uuid::NAMESPACE_URL);
+
+        std::io::copy(&mut &NAME[..], &mut hasher).unwrap();
+
+        assert_eq!(Uuid::new_v5(&Uuid::NAMESPACE_URL, NAME), hasher.finish());
+    }
+}
@@ -0,0 +1,329 @@
+//! Mapping UUIDs to dense integer handles.
+//!
+//! This module is useful for graph and analytics workloads that repeatedly
+//! join on UUID keys. Interning each UUID once gives a small integer handle
+//! that can be stored, compared, and used as an index far more cheaply than
+//! the UUID itself. Handles count up from zero, so they can index directly
+//! into a `Vec` of per-UUID data.
+//!
+//! Note that you need to enable the `intern` Cargo feature in order to use
+//! this module.
+
+use crate::{
+    std::{collections::HashMap, fmt, hash::Hash, vec::Vec},
+    Uuid,
+};
+
+mod private {
+    pub trait Sealed: Sized {
+        fn from_index(index: usize) -> Option<Self>;
+        fn index(self) -> usize;
+    }
+}
+
+/// An integer type that can be used as a handle for an interned UUID.
+///
+/// This trait is implemented for `u32` and `u64`. It's sealed, so it can't
+/// be implemented for other types.
+pub trait Handle: Copy + Eq + Ord + Hash + fmt::Debug + private::Sealed {}
+
+macro_rules! impl_handle {
+    ($($T:ty),*) => {
+        $(
+            impl private::Sealed for $T {
+                fn from_index(index: usize) -> Option<Self> {
+                    use crate::std::convert::TryFrom;
+
+                    <$T>::try_from(index).ok()
+                }
+
+                fn index(self) -> usize {
+                    self as usize
+                }
+            }
+
+            impl Handle for $T {}
+        )*
+    };
+}
+
+impl_handle!(u32, u64);
+
+/// A two-way mapping between UUIDs and dense integer handles.
+///
+/// Each distinct UUID is given the next handle in order, starting from
+/// zero. Interning a UUID that's already been seen returns the handle it
+/// was given the first time.
+///
+/// Once no more UUIDs need to be interned, the interner can be
+/// [frozen](UuidInterner::freeze) into a [`FrozenInterner`], which uses
+/// less memory and can be shared between threads for lookups.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, intern::UuidInterner};
+/// let a = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+/// let b = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?;
+///
+/// let mut interner = UuidInterner::<u32>::new();
+///
+/// assert_eq!(0, interner.intern(a));
+/// assert_eq!(1, interner.intern(b));
+/// assert_eq!(0, interner.intern(a));
+///
+/// assert_eq!(Some(b), interner.resolve(1));
+/// assert_eq!(Some(1), interner.get(&b));
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct UuidInterner<H = u32> {
+    handles: HashMap<Uuid, H>,
+    uuids: Vec<Uuid>,
+}
+
+/// A read-only snapshot of a [`UuidInterner`].
+///
+/// A frozen interner keeps the same handles as the interner it was made
+/// from, but drops the interner's hash map. Alongside the UUIDs it only
+/// keeps their handles sorted by UUID, so lookups by UUID are a binary
+/// search.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, intern::UuidInterner};
+/// let a = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+/// let b = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?;
+///
+/// let mut interner = UuidInterner::<u64>::new();
+/// interner.extend([b, a]);
+///
+/// let frozen = interner.freeze();
+///
+/// assert_eq!(Some(1), frozen.get(&a));
+/// assert_eq!(Some(a), frozen.resolve(1));
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrozenInterner<H = u32> {
+    // The UUID for each handle, in handle order
+    uuids: Vec<Uuid>,
+    // Every handle, sorted by the UUID it was given to
+    sorted: Vec<H>,
+}
+
+impl<H: Handle> Default for UuidInterner<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Handle> UuidInterner<H> {
+    /// Create an empty interner.
+    pub fn new() -> Self {
+        UuidInterner {
+            handles: HashMap::new(),
+            uuids: Vec::new(),
+        }
+    }
+
+    /// Create an empty interner sized for the given number of UUIDs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        UuidInterner {
+            handles: HashMap::with_capacity(capacity),
+            uuids: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Get the handle for a UUID, giving it a new one if it hasn't been
+    /// interned before.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if every handle has already been given out.
+    /// That's only possible for `u32` handles, after interning more than
+    /// four billion UUIDs.
+    pub fn intern(&mut self, uuid: Uuid) -> H {
+        if let Some(handle) = self.handles.get(&uuid) {
+            return *handle;
+        }
+
+        let handle = H::from_index(self.uuids.len()).expect("ran out of UUID handles");
+
+        self.handles.insert(uuid, handle);
+        self.uuids.push(uuid);
+
+        handle
+    }
+
+    /// Get the handle for a UUID, if it's been interned.
+    pub fn get(&self, uuid: &Uuid) -> Option<H> {
+        self.handles.get(uuid).copied()
+    }
+
+    /// Get the UUID for a handle, if it was given out by this interner.
+    pub fn resolve(&self, handle: H) -> Option<Uuid> {
+        self.uuids.get(handle.index()).copied()
+    }
+
+    /// Get every interned UUID, in handle order.
+    ///
+    /// The UUID for a handle is at the index of that handle.
+    pub fn uuids(&self) -> &[Uuid] {
+        &self.uuids
+    }
+
+    /// The number of distinct UUIDs interned.
+    pub fn len(&self) -> usize {
+        self.uuids.len()
+    }
+
+    /// Whether any UUIDs have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.uuids.is_empty()
+    }
+
+    /// Freeze the interner into a read-only snapshot.
+    pub fn freeze(self) -> FrozenInterner<H> {
+        let uuids = self.uuids;
+
+        let mut sorted = self.handles.into_values().collect::<Vec<_>>();
+        sorted.sort_unstable_by_key(|handle| uuids[handle.index()]);
+
+        FrozenInterner { uuids, sorted }
+    }
+}
+
+impl<H: Handle> Extend<Uuid> for UuidInterner<H> {
+    fn extend<I: IntoIterator<Item = Uuid>>(&mut self, iter: I) {
+        for uuid in iter {
+            self.intern(uuid);
+        }
+    }
+}
+
+impl<H: Handle> FrozenInterner<H> {
+    /// Get the handle for a UUID, if it was interned.
+    pub fn get(&self, uuid: &Uuid) -> Option<H> {
+        self.sorted
+            .binary_search_by(|handle| self.uuids[handle.index()].cmp(uuid))
+            .ok()
+            .map(|index| self.sorted[index])
+    }
+
+    /// Get the UUID for a handle, if it was given out by the interner.
+    pub fn resolve(&self, handle: H) -> Option<Uuid> {
+        self.uuids.get(handle.index()).copied()
+    }
+
+    /// Get every interned UUID, in handle order.
+    ///
+    /// The UUID for a handle is at the index of that handle.
+    pub fn uuids(&self) -> &[Uuid] {
+        &self.uuids
+    }
+
+    /// The number of distinct UUIDs interned.
+    pub fn len(&self) -> usize {
+        self.uuids.len()
+    }
+
+    /// Whether any UUIDs were interned.
+    pub fn is_empty(&self) -> bool {
+        self.uuids.is_empty()
+    }
+
+    /// Thaw the snapshot so more UUIDs can be interned.
+    ///
+    /// Handles that were already given out stay the same.
+    pub fn thaw(self) -> UuidInterner<H> {
+        let uuids = self.uuids;
+
+        UuidInterner {
+            handles: self
+                .sorted
+                .into_iter()
+                .map(|handle| (uuids[handle.index()], handle))
+                .collect(),
+            uuids,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn uuids() -> impl Iterator<Item = Uuid> {
+        // Interleave duplicates so handles aren't just the input index
+        (0..1_000u128).map(|i| Uuid::from_u128((i % 300).wrapping_mul(0x9e3779b97f4a7c15)))
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_intern() {
+        let mut interner = UuidInterner::<u32>::with_capacity(300);
+        let handles = uuids()
+            .map(|uuid| interner.intern(uuid))
+            .collect::<Vec<_>>();
+
+        assert_eq!(300, interner.len());
+
+        for (uuid, handle) in uuids().zip(handles) {
+            assert_eq!(Some(handle), interner.get(&uuid));
+            assert_eq!(Some(uuid), interner.resolve(handle));
+            assert_eq!(uuid, interner.uuids()[handle as usize]);
+        }
+
+        assert_eq!(None, interner.get(&Uuid::from_u128(u128::MAX)));
+        assert_eq!(None, interner.resolve(300));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_freeze() {
+        let mut interner = UuidInterner::<u64>::new();
+        interner.extend(uuids());
+
+        let expected = interner.clone();
+        let frozen = interner.freeze();
+
+        assert_eq!(expected.len(), frozen.len());
+
+        for uuid in uuids() {
+            let handle = frozen.get(&uuid).unwrap();
+
+            assert_eq!(expected.get(&uuid), Some(handle));
+            assert_eq!(Some(uuid), frozen.resolve(handle));
+        }
+
+        assert_eq!(None, frozen.get(&Uuid::from_u128(u128::MAX)));
+
+        let mut thawed = frozen.thaw();
+
+        assert_eq!(Some(5), thawed.get(&expected.uuids()[5]));
+        assert_eq!(300, thawed.intern(Uuid::from_u128(u128::MAX)));
+    }
+}
@@ -77,8 +77,12 @@
 //! are Cargo features that enable generating them:
 //!
 //! * `v1` - Version 1 UUIDs using a timestamp and monotonic counter.
+//! * `v2` - Version 2 DCE Security UUIDs using a timestamp, POSIX ID, and node ID.
 //! * `v3` - Version 3 UUIDs based on the MD5 hash of some data.
-//! * `v4` - Version 4 UUIDs with random data.
+//! * `v4` - Version 4 UUIDs with random data. By default each UUID's 16
+//!   random bytes are read straight from the operating system through
+//!   `getrandom`, which is the only dependency this adds. See `fast-rng`,
+//!   `buffered-rng`, and `rand` below for alternatives.
 //! * `v5` - Version 5 UUIDs based on the SHA1 hash of some data.
 //!
 //! Versions that are in draft are also supported. See the _unstable features_ section for details.
@@ -105,9 +109,61 @@
 //!   `serde`.
 //! * `arbitrary` - adds an `Arbitrary` trait implementation to `Uuid` for
 //!   fuzzing.
+//! * `log` - adds a `ToValue` trait implementation to `Uuid`, so it can be used
+//!   as a value in the `log` crate's structured key-value pairs.
+//! * `chrono` - adds conversions between `Timestamp` and `chrono`'s
+//!   `DateTime<Utc>`, so timestamps read from UUIDs can be displayed and
+//!   compared with `chrono`.
+//! * `time` - adds conversions between `Timestamp` and `time`'s
+//!   `OffsetDateTime`, like the `chrono` feature.
+//! * `valuable` - adds a `Valuable` trait implementation to `Uuid`, so it can be
+//!   recorded as a structured field value by `tracing` subscribers. UUIDs are
+//!   recorded as their `u128` value.
 //! * `fast-rng` - uses a faster algorithm for generating random UUIDs.
+//!   Instead of asking the operating system for random bytes for each UUID,
+//!   a userspace CSPRNG (`rand`'s thread-local ChaCha generator) is seeded
+//!   per thread from the operating system and then used to generate UUIDs.
+//!   It's periodically reseeded from the operating system as well.
 //!   This feature requires more dependencies to compile, but is just as suitable for
-//!   UUIDs as the default algorithm.
+//!   UUIDs as the default algorithm. It's the recommended mode for workloads that
+//!   generate UUIDs at a high rate. Without it, the operating system's RNG is used.
+//! * `rand` - adds `Uuid::new_v4_with` for generating random UUIDs with your
+//!   own `rand::RngCore`, such as a seeded or hardware-backed generator. It
+//!   also implements `Distribution<Uuid>` for `rand`'s `Standard`
+//!   distribution, so `rng.gen::<Uuid>()` works in generic code. This
+//!   doesn't need the `v4` feature, so the crate won't touch the operating
+//!   system's RNG unless something else enables it.
+//! * `striped-context` - makes `Uuid::now_v1` and `Uuid::now_v6` use a
+//!   `StripedContext` instead of a single shared `Context`. This avoids
+//!   contention on the clock sequence when many threads generate version 1 or
+//!   version 6 UUIDs at once.
+//! * `mac` - adds `NodeId::from_interface` and `NodeId::first_available` for reading
+//!   node IDs for `v1` and `v6` UUIDs from the hardware addresses of network interfaces
+//!   on Linux, macOS, and Windows. UUIDs generated with these node IDs reveal the
+//!   MAC address of the machine that generated them.
+//! * `buffered-rng` - draws random bytes for `v4` and `v7` UUIDs from a
+//!   thread-local buffer that's refilled from the operating system 4KiB at a
+//!   time, instead of making a system call for each UUID. This has no effect
+//!   when `fast-rng` is also enabled. **Security note:** buffered bytes are
+//!   copied into a child process by `fork`, so a parent and child that both
+//!   generate UUIDs after forking without `exec` can produce the same values.
+//!   Don't enable this feature in processes that fork, or where random bytes
+//!   waiting in memory are a concern.
+//! * `analysis` - adds the `analysis` module, for reporting on the versions,
+//!   time range, and randomness of a set of UUIDs generated elsewhere.
+//! * `intern` - adds the `intern` module, for mapping UUIDs to small integer
+//!   handles that are cheaper to store and compare in graph and analytics
+//!   workloads.
+//! * `test-util` - adds the `test_util` module, with helpers for checking
+//!   generated UUIDs are unique, producing the same UUIDs on every run, and
+//!   `test_util::freeze_clock` for pinning the time read by `Timestamp::now`
+//!   and the `now_*` methods, so assertions about time-ordered UUIDs don't
+//!   depend on the wall clock.
+//! * `simd` - formats UUIDs using SIMD instructions where they're available.
+//!   On x86 and x86_64, AVX2 and SSSE3 support is detected at runtime when the
+//!   `std` feature is enabled, and at compile time otherwise. On AArch64, NEON
+//!   is always used. This feature has no effect when `tiny` is also enabled.
+//!   Using it on AArch64 requires Rust 1.59 or newer.
 //!
 //! # Unstable features
 //!
@@ -122,6 +178,10 @@
 //! * `borsh` - adds the ability to serialize and deserialize a UUID using
 //!   `borsh`.
 //!
+//! Layouts from drafts that haven't been published yet live in the `draft`
+//! module, which is only available as an unstable feature. So is the
+//! `migrate` module for converting stored version 1 UUIDs to version 6.
+//!
 //! Unstable features may break between minor releases.
 //!
 //! To allow unstable features, you'll need to enable the Cargo feature as
@@ -132,6 +192,21 @@
 //! RUSTFLAGS="--cfg uuid_unstable"
 //! ```
 //!
+//! # Core-only builds
+//!
+//! Kernels and firmware that only need to move 16-byte UUIDs around can build
+//! `uuid` without any of its formatting or parsing code. This leaves the [`Uuid`]
+//! type, its byte and integer conversions, and the [`Builder`]. Since removing
+//! code can break other libraries in the same build, this isn't a Cargo feature.
+//! Instead, it's opted into through your environment:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg uuid_core_only"
+//! ```
+//!
+//! The `serde`, `slog`, `log`, and `valuable` features, and the `uuid!` macro, aren't available in
+//! core-only builds.
+//!
 //! # Building for other targets
 //!
 //! ## WebAssembly
@@ -148,6 +223,58 @@
 //! ]
 //! ```
 //!
+//! The `js` feature also reads the current time for `Timestamp::now` and the `now_*` methods
+//! from `Date.now()`, since `SystemTime::now` panics on `wasm32-unknown-unknown`. When
+//! `performance.now()` is available, it's used for sub-millisecond precision.
+//!
+//! Version 1 and 6 UUIDs need a node ID, which isn't available in the browser. The `node`
+//! module can generate a random node ID and persist it in `localStorage`, so UUIDs from the
+//! same browser profile remain correlated across sessions.
+//!
+//! When targeting single-threaded WebAssembly (without the `atomics` target feature),
+//! `Context` uses plain cells instead of atomics to track its clock sequence.
+//!
+//! ## Internet Computer
+//!
+//! For canisters on the Internet Computer, enable the `ic` feature:
+//!
+//! ```toml
+//! [dependencies.uuid]
+//! version = "1.4.1"
+//! features = [
+//!     "v6",
+//!     "v7",
+//!     "ic",
+//! ]
+//! ```
+//!
+//! The `ic` feature reads the current time for `Timestamp::now` and the `now_*` methods
+//! from `ic_cdk::api::time()` when building for `wasm32`, since `SystemTime` isn't available
+//! inside canisters. The time doesn't advance while a single message is being handled, so
+//! UUIDs generated within the same message are kept unique by their context's counter
+//! or clock sequence instead. Canisters don't have a source of randomness that `getrandom`
+//! can use, so random UUIDs need a [custom `getrandom` backend] seeded from the
+//! management canister's `raw_rand`.
+//!
+//! ## UEFI
+//!
+//! For UEFI applications, such as boot managers or provisioning tools, enable the `uefi` feature:
+//!
+//! ```toml
+//! [dependencies.uuid]
+//! version = "1.4.1"
+//! default-features = false
+//! features = [
+//!     "v4",
+//!     "v7",
+//!     "uefi",
+//! ]
+//! ```
+//!
+//! On UEFI targets, random bytes are sourced from the firmware's `EFI_RNG_PROTOCOL`
+//! and the current time is read from UEFI Runtime Services. Boot services must
+//! still be available when generating random UUIDs.
+//!
 //! ## Embedded
 //!
 //! For embedded targets without the standard library, you'll need to
@@ -163,6 +290,17 @@
 //!
 //! * `v1`, `v3`, `v5`, `v6`, and `v8`.
 //! * `serde`.
+//! * `alloc` - adds conversions from UUIDs into `String`s on targets with an allocator.
+//!   This is enabled by `std`.
+//! * `tiny` - formats and parses UUIDs one byte at a time without lookup tables. This is
+//!   slower, but reduces code size on targets where every kilobyte matters. Only the
+//!   hyphenated format can be parsed, so simple, braced, and URN strings are rejected.
+//! * `critical-section` - adds a global generator in the [`embedded`] module that can be
+//!   used from interrupt handlers, using your own sources of entropy and time.
+//! * `custom-clock` - adds [`timestamp::clock::register_clock`] for supplying your own
+//!   source of time, like a real-time clock, to [`Timestamp::now`] and the `now_*` methods.
+//! * `custom-generator` - adds [`set_generator`] for rerouting [`Uuid::new_v4`] and
+//!   `Uuid::now_v7` through your own source of UUIDs, like in tests or for deterministic replay.
 //!
 //! If you need to use `v4` or `v7` in a no-std environment, you'll need to
 //! follow [`getrandom`'s docs] on configuring a source of randomness
@@ -177,9 +315,11 @@
 //! ```
 //! # use uuid::Uuid;
 //! # fn main() -> Result<(), uuid::Error> {
+//! # #[cfg(not(feature = "tiny"))] {
 //! let my_uuid = Uuid::parse_str("a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8")?;
 //!
 //! println!("{}", my_uuid.urn());
+//! # }
 //! # Ok(())
 //! # }
 //! ```
@@ -207,6 +347,7 @@
 //! [`wasm-bindgen`]: https://crates.io/crates/wasm-bindgen
 //! [`cargo-web`]: https://crates.io/crates/cargo-web
 //! [`getrandom`'s docs]: https://docs.rs/getrandom
+//! [custom `getrandom` backend]: https://docs.rs/getrandom/0.2/getrandom/macro.register_custom_getrandom.html
 
 #![no_std]
 #![deny(missing_debug_implementations, missing_docs)]
@@ -224,29 +365,80 @@ extern crate std;
 #[macro_use]
 extern crate core as std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(all(uuid_unstable, feature = "zerocopy"))]
 use zerocopy::{AsBytes, FromBytes, Unaligned};
 
 mod builder;
+#[cfg(any(feature = "test-util", all(uuid_unstable, feature = "std")))]
+mod collision;
 mod error;
+#[cfg(not(uuid_core_only))]
 mod parser;
 
+#[cfg(feature = "analysis")]
+pub mod analysis;
+#[cfg(uuid_unstable)]
+pub mod draft;
+pub mod encoding;
+#[cfg(not(uuid_core_only))]
 pub mod fmt;
+#[cfg(any(feature = "v3", feature = "v5"))]
+mod hasher;
+#[cfg(feature = "intern")]
+pub mod intern;
+#[cfg(all(uuid_unstable, feature = "std"))]
+pub mod migrate;
+#[cfg(feature = "v5")]
+mod namespace;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod timestamp;
 
-pub use timestamp::{context::NoContext, ClockSequence, Timestamp};
+#[cfg(feature = "critical-section")]
+pub mod embedded;
+
+#[cfg(feature = "custom-generator")]
+mod generator;
+
+#[cfg(any(feature = "v1", feature = "v6"))]
+pub mod node;
+
+pub use timestamp::{context::NoContext, ClockSequence, ClockSource, Timestamp};
 
 #[cfg(any(feature = "v1", feature = "v6"))]
-pub use timestamp::context::Context;
+pub use crate::{
+    node::NodeId,
+    timestamp::context::{CheckedContext, Context, ContextState, OverflowPolicy, RollbackContext},
+};
+
+#[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+pub use timestamp::context::StripedContext;
+
+#[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+pub use timestamp::context::{ShardedContext, ThreadLocalContext};
+
+#[cfg(all(uuid_unstable, feature = "v7"))]
+pub use timestamp::context::{ContextV7, SubMillisecondContext, WideCounterContext};
+
+#[cfg(all(uuid_unstable, feature = "v7"))]
+pub use crate::v7::V7Generator;
+
+#[cfg(feature = "custom-generator")]
+pub use crate::generator::{reset_generator, set_generator, UuidSource};
 
 #[cfg(feature = "v1")]
 #[doc(hidden)]
 // Soft-deprecated (Rust doesn't support deprecating re-exports)
 // Use `Context` from the crate root instead
 pub mod v1;
+#[cfg(feature = "v2")]
+mod v2;
 #[cfg(feature = "v3")]
 mod v3;
-#[cfg(feature = "v4")]
+#[cfg(any(feature = "v4", feature = "rand"))]
 mod v4;
 #[cfg(feature = "v5")]
 mod v5;
@@ -257,6 +449,12 @@ mod v7;
 #[cfg(all(uuid_unstable, feature = "v8"))]
 mod v8;
 
+#[cfg(all(uuid_unstable, any(feature = "v6", feature = "v7")))]
+mod sortable;
+
+#[cfg(all(feature = "simd", not(feature = "tiny"), not(uuid_core_only)))]
+mod simd;
+
 #[cfg(feature = "md5")]
 mod md5;
 #[cfg(feature = "rng")]
@@ -266,6 +464,7 @@ mod sha1;
 
 mod external;
 
+#[cfg(not(uuid_core_only))]
 #[macro_use]
 mod macros;
 
@@ -278,12 +477,49 @@ pub mod __macro_support {
     pub use crate::std::result::Result::{Err, Ok};
 }
 
-use crate::std::convert;
+use crate::std::{convert, time::Duration};
+
+pub use crate::{
+    builder::Builder,
+    error::{Error, ErrorKind},
+};
+
+#[cfg(any(feature = "v3", feature = "v5"))]
+pub use crate::hasher::NameHasher;
 
-pub use crate::{builder::Builder, error::Error};
+#[cfg(feature = "v5")]
+pub use crate::namespace::Namespace;
+
+#[cfg(all(uuid_unstable, any(feature = "v6", feature = "v7")))]
+pub use crate::sortable::SortableId;
+
+#[cfg(all(uuid_unstable, feature = "v8"))]
+pub use crate::v8::V8Layout;
 
 /// A 128-bit (16 byte) buffer containing the UUID.
 ///
+/// # Inspecting bytes
+///
+/// `Bytes` is a plain array, so the bytes returned by [`Uuid::as_bytes`]
+/// can be indexed by range, iterated, and split into chunks in place,
+/// without copying them somewhere else first:
+///
+/// ```
+/// # use uuid::Uuid;
+/// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+/// let bytes = uuid.as_bytes();
+///
+/// // The variant is in the top bits of octet 8
+/// assert_eq!(0b11, bytes[8] >> 6);
+///
+/// // The node ID is the last 6 octets
+/// assert_eq!([0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8], bytes[10..]);
+///
+/// // Octets can be walked in fixed-size chunks
+/// assert!(bytes.chunks_exact(4).all(|chunk| chunk.len() == 4));
+/// # Ok::<(), uuid::Error>(())
+/// ```
+///
 /// # ABI
 ///
 /// The `Bytes` type is always guaranteed to be have the same ABI as [`Uuid`].
@@ -291,6 +527,20 @@ pub type Bytes = [u8; 16];
 
 /// The version of the UUID, denoting the generating algorithm.
 ///
+/// Versions are displayed using their name. They can be parsed from their
+/// name, ignoring case, or from their number, like `4` or `v4`.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::Version;
+/// assert_eq!("Random", Version::Random.to_string());
+///
+/// assert_eq!(Ok(Version::Random), "random".parse());
+/// assert_eq!(Ok(Version::Random), "v4".parse());
+/// assert!("v9".parse::<Version>().is_err());
+/// ```
+///
 /// # References
 ///
 /// * [Version in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.1.3)
@@ -320,7 +570,6 @@ pub enum Version {
     #[cfg(uuid_unstable)]
     Custom = 8,
     /// The "max" (all ones) UUID.
-    #[cfg(uuid_unstable)]
     Max = 0xff,
 }
 
@@ -329,7 +578,7 @@ pub enum Version {
 /// # References
 ///
 /// * [Variant in RFC4122](http://tools.ietf.org/html/rfc4122#section-4.1.1)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Variant {
@@ -343,6 +592,26 @@ pub enum Variant {
     Future,
 }
 
+/// The local domain of a version 2 DCE Security UUID.
+///
+/// The domain says what kind of POSIX identifier the local ID in the UUID
+/// is, such as a user or group ID.
+///
+/// # References
+///
+/// * [DCE 1.1: Authentication and Security Services](https://pubs.opengroup.org/onlinepubs/9696989899/chap5.htm#tagcjh_08_02_01_01)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Domain {
+    /// The local ID is a POSIX user ID (UID).
+    Person = 0u8,
+    /// The local ID is a POSIX group ID (GID).
+    Group,
+    /// The local ID is an organization ID.
+    Org,
+}
+
 /// A Universally Unique Identifier (UUID).
 ///
 /// # Examples
@@ -352,9 +621,11 @@ pub enum Variant {
 /// ```
 /// # use uuid::Uuid;
 /// # fn main() -> Result<(), uuid::Error> {
+/// # #[cfg(not(feature = "tiny"))] {
 /// let my_uuid = Uuid::parse_str("a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8")?;
 ///
 /// println!("{}", my_uuid.urn());
+/// # }
 /// # Ok(())
 /// # }
 /// ```
@@ -389,7 +660,7 @@ pub enum Variant {
 /// ```
 /// # use uuid::Uuid;
 /// # fn main() -> Result<(), uuid::Error> {
-/// let my_uuid = Uuid::parse_str("a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8")?;
+/// let my_uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
 ///
 /// assert_eq!(
 ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
@@ -404,7 +675,7 @@ pub enum Variant {
 /// ```
 /// # use uuid::Uuid;
 /// # fn main() -> Result<(), uuid::Error> {
-/// let my_uuid = Uuid::parse_str("a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8")?;
+/// let my_uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
 ///
 /// assert_eq!(
 ///     "urn:uuid:a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
@@ -437,7 +708,7 @@ pub enum Variant {
 /// # ABI
 ///
 /// The `Uuid` type is always guaranteed to be have the same ABI as [`Bytes`].
-#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
 #[cfg_attr(
     all(uuid_unstable, feature = "zerocopy"),
     derive(AsBytes, FromBytes, Unaligned)
@@ -474,6 +745,109 @@ impl Uuid {
         0xc8,
     ]);
 
+    /// Hash space ID for SHA2-224, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA2_224: Self = Uuid([
+        0x59, 0x03, 0x1c, 0xa3, 0xfb, 0xdb, 0x47, 0xfb, 0x9f, 0x6c, 0x0f, 0x30, 0xe2, 0xe8, 0x31,
+        0x45,
+    ]);
+
+    /// Hash space ID for SHA2-256, for name-based version 8 UUIDs.
+    ///
+    /// RFC 9562 suggests that name-based version 8 UUIDs using a hash
+    /// other than MD5 or SHA-1 include a hash space ID, which identifies the
+    /// hash algorithm, before the namespace ID and name that are hashed.
+    ///
+    /// # References
+    ///
+    /// * [Name-Based UUID Generation in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-6.5)
+    /// * [Namespace ID Usage and Allocation in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-6.6)
+    pub const HASHSPACE_SHA2_256: Self = Uuid([
+        0x3f, 0xb3, 0x27, 0x80, 0x95, 0x3c, 0x44, 0x64, 0x9c, 0xfd, 0xe8, 0x5d, 0xbb, 0xe9, 0x84,
+        0x3d,
+    ]);
+
+    /// Hash space ID for SHA2-384, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA2_384: Self = Uuid([
+        0xe6, 0x80, 0x05, 0x81, 0xf3, 0x33, 0x48, 0x4b, 0x87, 0x78, 0x60, 0x1f, 0xf2, 0xb5, 0x8d,
+        0xa8,
+    ]);
+
+    /// Hash space ID for SHA2-512, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA2_512: Self = Uuid([
+        0x0f, 0xde, 0x22, 0xf2, 0xe7, 0xba, 0x4f, 0xd1, 0x97, 0x53, 0x9c, 0x2e, 0xa8, 0x8f, 0xa3,
+        0xf9,
+    ]);
+
+    /// Hash space ID for SHA2-512/224, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA2_512_224: Self = Uuid([
+        0x00, 0x3c, 0x20, 0x38, 0xc4, 0xfe, 0x4b, 0x95, 0xa6, 0x72, 0x0c, 0x26, 0xc1, 0xb7, 0x95,
+        0x42,
+    ]);
+
+    /// Hash space ID for SHA2-512/256, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA2_512_256: Self = Uuid([
+        0x94, 0x75, 0xad, 0x00, 0x37, 0x69, 0x4c, 0x07, 0x96, 0x42, 0x5e, 0x73, 0x83, 0x73, 0x23,
+        0x06,
+    ]);
+
+    /// Hash space ID for SHA3-224, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA3_224: Self = Uuid([
+        0x97, 0x68, 0x76, 0x1f, 0xac, 0x5a, 0x41, 0x9e, 0xa1, 0x80, 0x7c, 0xa2, 0x39, 0xe8, 0x02,
+        0x5a,
+    ]);
+
+    /// Hash space ID for SHA3-256, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA3_256: Self = Uuid([
+        0x20, 0x34, 0xd6, 0x6b, 0x40, 0x47, 0x45, 0x53, 0x8f, 0x80, 0x70, 0xe5, 0x93, 0x17, 0x68,
+        0x77,
+    ]);
+
+    /// Hash space ID for SHA3-384, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA3_384: Self = Uuid([
+        0x87, 0x2f, 0xb3, 0x39, 0x26, 0x36, 0x4b, 0xdd, 0xbd, 0xa6, 0xb6, 0xdc, 0x2a, 0x82, 0xb1,
+        0xb3,
+    ]);
+
+    /// Hash space ID for SHA3-512, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHA3_512: Self = Uuid([
+        0xa4, 0x92, 0x0a, 0x5d, 0xa8, 0xa6, 0x42, 0x6c, 0x8d, 0x14, 0xa6, 0xca, 0xfb, 0xe6, 0x4c,
+        0x7b,
+    ]);
+
+    /// Hash space ID for SHAKE128, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHAKE_128: Self = Uuid([
+        0x7e, 0xa2, 0x18, 0xf6, 0x62, 0x9a, 0x42, 0x5f, 0x9f, 0x88, 0x74, 0x39, 0xd6, 0x32, 0x96,
+        0xbb,
+    ]);
+
+    /// Hash space ID for SHAKE256, for name-based version 8 UUIDs.
+    ///
+    /// See [`Uuid::HASHSPACE_SHA2_256`] for how hash space IDs are used.
+    pub const HASHSPACE_SHAKE_256: Self = Uuid([
+        0x2e, 0x7f, 0xc6, 0xa4, 0x29, 0x19, 0x4e, 0xdc, 0xb0, 0xba, 0x7d, 0x70, 0x62, 0xce, 0x4f,
+        0x0a,
+    ]);
+
     /// Returns the variant of the UUID structure.
     ///
     /// This determines the interpretation of the structure of the UUID.
@@ -510,6 +884,172 @@ impl Uuid {
         }
     }
 
+    /// If the UUID is the correct version (v8) this will return the 122 bits
+    /// of its custom payload.
+    ///
+    /// The payload is every bit of the UUID that isn't taken by the version
+    /// and variant, packed into the low bits of a `u128`. Returns `None` if
+    /// the UUID isn't a version 8 UUID with the RFC4122 variant. This is the
+    /// reverse of [`Builder::from_v8_payload`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("00000000-0000-8000-8000-00000000002a")?;
+    ///
+    /// assert_eq!(Some(0x2a), uuid.get_v8_payload());
+    ///
+    /// // The version and variant bits are skipped over
+    /// let uuid = Uuid::parse_str("ffffffff-ffff-8fff-bfff-ffffffffffff")?;
+    ///
+    /// assert_eq!(Some((1 << 122) - 1), uuid.get_v8_payload());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[cfg(uuid_unstable)]
+    pub const fn get_v8_payload(&self) -> Option<u128> {
+        match (self.get_version(), self.get_variant()) {
+            (Some(Version::Custom), Variant::RFC4122) => {
+                let uuid = self.as_u128();
+
+                Some((uuid >> 80) << 74 | (uuid >> 64 & 0xFFF) << 62 | uuid & 0x3FFF_FFFF_FFFF_FFFF)
+            }
+            _ => None,
+        }
+    }
+
+    /// If the UUID is the correct version (v2) this will return the domain
+    /// of its local ID.
+    ///
+    /// Returns `None` if the UUID isn't a version 2 UUID, or its domain
+    /// isn't one of the [`Domain`]s defined by DCE.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Domain, Uuid};
+    /// let uuid = Uuid::parse_str("000003e8-52a1-21e7-aa00-010203040506")?;
+    ///
+    /// assert_eq!(Some(Domain::Person), uuid.get_domain());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn get_domain(&self) -> Option<Domain> {
+        match self.get_version() {
+            Some(Version::Dce) => match self.as_bytes()[9] {
+                0 => Some(Domain::Person),
+                1 => Some(Domain::Group),
+                2 => Some(Domain::Org),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// If the UUID is the correct version (v2) this will return its local
+    /// ID, such as a POSIX user or group ID.
+    ///
+    /// Returns `None` if the UUID isn't a version 2 UUID. Use
+    /// [`Uuid::get_domain`] to find out what kind of ID it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("000003e8-52a1-21e7-aa00-010203040506")?;
+    ///
+    /// assert_eq!(Some(1000), uuid.get_local_id());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn get_local_id(&self) -> Option<u32> {
+        match self.get_version() {
+            Some(Version::Dce) => {
+                let bytes = self.as_bytes();
+
+                Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            }
+            _ => None,
+        }
+    }
+
+    /// If the UUID is the Microsoft variant this will return the fields of
+    /// the GUID it represents.
+    ///
+    /// The fields are the `Data1`, `Data2`, `Data3`, and `Data4` members of
+    /// a Windows `GUID`, in native integer form. Returns `None` for any other
+    /// variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("00000000-0000-0000-c000-000000000046")?;
+    ///
+    /// assert_eq!(
+    ///     Some((0, 0, 0, [0xc0, 0, 0, 0, 0, 0, 0, 0x46])),
+    ///     uuid.get_microsoft_fields(),
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn get_microsoft_fields(&self) -> Option<(u32, u16, u16, [u8; 8])> {
+        match self.get_variant() {
+            Variant::Microsoft => {
+                let bytes = self.as_bytes();
+
+                Some((
+                    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                    u16::from_be_bytes([bytes[4], bytes[5]]),
+                    u16::from_be_bytes([bytes[6], bytes[7]]),
+                    [
+                        bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                        bytes[15],
+                    ],
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// If the UUID is the NCS variant this will return its timestamp,
+    /// address family, and host address.
+    ///
+    /// The timestamp is the 48-bit count of 4 microsecond intervals since
+    /// 1980-01-01, the address family is 7 bits, and the host address is
+    /// 56 bits. Returns `None` for any other variant. See
+    /// [`Builder::from_ncs_fields`] for more details.
+    ///
+    /// Note that the nil UUID is also the NCS variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("333a2276-0000-0000-0d00-00809c000000")?;
+    ///
+    /// assert_eq!(
+    ///     Some((0x333a_2276_0000, 0x0d, [0x00, 0x00, 0x80, 0x9c, 0x00, 0x00, 0x00])),
+    ///     uuid.get_ncs_fields(),
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn get_ncs_fields(&self) -> Option<(u64, u8, [u8; 7])> {
+        match self.get_variant() {
+            Variant::NCS => {
+                let bytes = self.as_bytes();
+
+                Some((
+                    u64::from_be_bytes([
+                        0, 0, bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5],
+                    ]),
+                    bytes[8],
+                    [
+                        bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+                    ],
+                ))
+            }
+            _ => None,
+        }
+    }
+
     /// Returns the version number of the UUID.
     ///
     /// This represents the algorithm used to generate the value.
@@ -576,12 +1116,60 @@ impl Uuid {
             7 => Some(Version::SortRand),
             #[cfg(uuid_unstable)]
             8 => Some(Version::Custom),
-            #[cfg(uuid_unstable)]
-            0xf => Some(Version::Max),
+            0xf if self.is_max() => Some(Version::Max),
             _ => None,
         }
     }
 
+    /// Checks that the UUID is one that RFC 9562 describes.
+    ///
+    /// This is useful in environments that must only accept canonical
+    /// UUIDs. The following are rejected:
+    ///
+    /// * UUIDs with a variant other than the one described in RFC 9562,
+    ///   such as the legacy NCS and Microsoft variants.
+    /// * Version 2 (DCE Security) UUIDs, which RFC 9562 leaves to DCE,
+    ///   unless the `v2` feature is enabled.
+    /// * UUIDs with a version that hasn't been assigned.
+    ///
+    /// The nil and max UUIDs are always accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let rfc = Uuid::parse_str("02f09a3f-1624-3b1d-8409-44eff7708208")?;
+    /// let microsoft = Uuid::parse_str("02f09a3f-1624-3b1d-c409-44eff7708208")?;
+    ///
+    /// assert!(rfc.check_strict().is_ok());
+    /// assert!(microsoft.check_strict().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Variant in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.1)
+    /// * [Version in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.2)
+    pub const fn check_strict(&self) -> Result<(), Error> {
+        if self.is_nil() || self.as_u128() == u128::MAX {
+            return Ok(());
+        }
+
+        match self.get_variant() {
+            Variant::RFC4122 => (),
+            variant => return Err(Error(error::ErrorRepr::UnsupportedVariant { variant })),
+        }
+
+        match self.get_version_num() {
+            1 | 3..=8 => Ok(()),
+            #[cfg(feature = "v2")]
+            2 => Ok(()),
+            version => Err(Error(error::ErrorRepr::UnsupportedVersion { version })),
+        }
+    }
+
     /// Returns the four field values of the UUID.
     ///
     /// These values can be passed to the [`Uuid::from_fields`] method to get
@@ -846,7 +1434,21 @@ impl Uuid {
     }
 
     /// Tests if the UUID is max (all ones).
-    #[cfg(uuid_unstable)]
+    ///
+    /// The max UUID sorts after every other UUID, so it's useful as an
+    /// upper bound in range scans, alongside the nil UUID as a lower bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert!((Uuid::nil()..=Uuid::max()).contains(&uuid));
+    /// assert!(Uuid::max().is_max());
+    /// assert!(!uuid.is_max());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
     pub const fn is_max(&self) -> bool {
         self.as_u128() == u128::MAX
     }
@@ -876,37 +1478,135 @@ impl Uuid {
     ///     "urn:uuid:00000000-0000-0000-0000-000000000000"
     /// );
     /// ```
+    #[cfg(not(uuid_core_only))]
     pub const fn encode_buffer() -> [u8; fmt::Urn::LENGTH] {
         [0; fmt::Urn::LENGTH]
     }
 
-    /// If the UUID is the correct version (v1, v6, or v7) this will return
-    /// the timestamp and counter portion parsed from a V1 UUID.
+    /// Writes the UUID into the 16 bytes starting at `offset` in `buf`.
     ///
-    /// Returns `None` if the supplied UUID is not V1.
+    /// The bytes don't need to be aligned, so this method can be used to
+    /// write UUIDs at arbitrary offsets in packed network headers or
+    /// on-disk formats. Bytes in `buf` outside of the UUID are untouched.
     ///
-    /// The V1 timestamp format defined in RFC4122 specifies a 60-bit
-    /// integer representing the number of 100-nanosecond intervals
-    /// since 00:00:00.00, 15 Oct 1582.
+    /// # Errors
     ///
-    /// [`Timestamp`] offers several options for converting the raw RFC4122
-    /// value into more commonly-used formats, such as a unix timestamp.
+    /// This function will return an error if `buf` doesn't contain at least
+    /// 16 bytes starting from `offset`.
     ///
-    /// # Roundtripping
+    /// # Examples
     ///
-    /// This method is unlikely to roundtrip a timestamp in a UUID due to the way
-    /// UUIDs encode timestamps. The timestamp returned from this method will be truncated to
-    /// 100ns precision for version 1 and 6 UUIDs, and to millisecond precision for version 7 UUIDs.
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
     ///
-    /// [`Timestamp`]: v1/struct.Timestamp.html
-    pub const fn get_timestamp(&self) -> Option<Timestamp> {
-        match self.get_version() {
-            Some(Version::Mac) => {
-                let (ticks, counter) = timestamp::decode_rfc4122_timestamp(self);
+    /// let mut header = [0; 18];
+    /// uuid.write_unaligned_at(&mut header, 1)?;
+    ///
+    /// assert_eq!(
+    ///     header,
+    ///     [
+    ///         0x00, 0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2,
+    ///         0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0x00,
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_unaligned_at(&self, buf: &mut [u8], offset: usize) -> Result<(), Error> {
+        let len = buf.len().saturating_sub(offset);
 
-                Some(Timestamp::from_rfc4122(ticks, counter))
+        match buf.get_mut(offset..).and_then(|b| b.get_mut(..16)) {
+            Some(b) => {
+                b.copy_from_slice(&self.0);
+                Ok(())
             }
-            #[cfg(uuid_unstable)]
+            None => Err(Error(error::ErrorRepr::ByteLength { len })),
+        }
+    }
+
+    /// Writes the UUID to a pointer to 16 bytes that may not be aligned.
+    ///
+    /// Prefer [`Uuid::write_unaligned_at`] unless you're already working
+    /// with raw pointers.
+    ///
+    /// # Safety
+    ///
+    /// `dst` must be valid for writes of 16 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// let mut header = [0u8; 19];
+    ///
+    /// // SAFETY: `header` has 16 bytes after offset 3
+    /// unsafe { uuid.write_unaligned(header.as_mut_ptr().add(3)) };
+    ///
+    /// assert_eq!(&header[..3], &[0; 3]);
+    /// assert_eq!(&header[3..], uuid.as_bytes());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub unsafe fn write_unaligned(&self, dst: *mut u8) {
+        std::ptr::write_unaligned(dst as *mut Bytes, self.0)
+    }
+
+    /// If the UUID is the correct version (v1, v6, or v7) this will return
+    /// the timestamp and counter portion parsed from it.
+    ///
+    /// Returns `None` if the UUID isn't one of those versions. Version 6 and
+    /// version 7 UUIDs are only recognized when unstable features are enabled.
+    ///
+    /// Version 1 and version 6 UUIDs store a 60-bit integer representing the
+    /// number of 100-nanosecond intervals since 00:00:00.00, 15 Oct 1582, along
+    /// with a 14-bit clock sequence, which is returned as the counter. Version 7
+    /// UUIDs store the number of milliseconds since the Unix epoch, and don't
+    /// have a counter that can be decoded.
+    ///
+    /// [`Timestamp`] offers several options for converting the decoded
+    /// value into more commonly-used formats, such as a unix timestamp.
+    ///
+    /// # Roundtripping
+    ///
+    /// This method is unlikely to roundtrip a timestamp in a UUID due to the way
+    /// UUIDs encode timestamps. The timestamp returned from this method will be truncated to
+    /// 100ns precision for version 1 and 6 UUIDs, and to millisecond precision for version 7 UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// Getting the time a UUID received from another system was generated,
+    /// and how old it is:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let uuid = Uuid::parse_str("f3b49a52-52a1-11e7-802a-010203040506")?;
+    ///
+    /// let ts = uuid.get_timestamp().expect("a version 1 UUID");
+    /// assert_eq!((1_497_624_119, 123_400), ts.to_unix());
+    ///
+    /// let now = Timestamp::from_unix(NoContext, 1_497_624_179, 123_400);
+    /// assert_eq!(Ok(std::time::Duration::from_secs(60)), now.duration_since(&ts));
+    ///
+    /// // Random UUIDs don't have a timestamp
+    /// assert!(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?
+    ///     .get_timestamp()
+    ///     .is_none());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn get_timestamp(&self) -> Option<Timestamp> {
+        match self.get_version() {
+            Some(Version::Mac) => {
+                let (ticks, counter) = timestamp::decode_rfc4122_timestamp(self);
+
+                Some(Timestamp::from_rfc4122(ticks, counter))
+            }
+            #[cfg(uuid_unstable)]
             Some(Version::SortMac) => {
                 let (ticks, counter) = timestamp::decode_sorted_rfc4122_timestamp(self);
 
@@ -922,13 +1622,233 @@ impl Uuid {
                 Some(Timestamp {
                     seconds,
                     nanos,
-                    #[cfg(any(feature = "v1", feature = "v6"))]
                     counter: 0,
+                    usable_counter_bits: 0,
                 })
             }
             _ => None,
         }
     }
+
+    /// If the UUID is the correct version (v1 or v6) this will return the
+    /// node ID it was generated with.
+    ///
+    /// Returns `None` if the UUID isn't one of those versions. Version 6
+    /// UUIDs are only recognized when unstable features are enabled.
+    ///
+    /// The node ID is usually the MAC address of the machine that generated
+    /// the UUID, or a random value with its multicast bit set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("f3b49a52-52a1-11e7-802a-010203040506")?;
+    ///
+    /// assert_eq!(Some([1, 2, 3, 4, 5, 6]), uuid.get_node_id());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn get_node_id(&self) -> Option<[u8; 6]> {
+        match self.get_version() {
+            Some(Version::Mac) => Some(self.node_id_bytes()),
+            #[cfg(uuid_unstable)]
+            Some(Version::SortMac) => Some(self.node_id_bytes()),
+            _ => None,
+        }
+    }
+
+    /// If the UUID is the correct version (v1 or v6) this will return the
+    /// 14-bit clock sequence it was generated with.
+    ///
+    /// Returns `None` if the UUID isn't one of those versions. Version 6
+    /// UUIDs are only recognized when unstable features are enabled.
+    ///
+    /// The clock sequence is the same as the counter of the timestamp
+    /// returned by [`Uuid::get_timestamp`]. Along with the node ID, it can
+    /// identify the generator that produced a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("f3b49a52-52a1-11e7-802a-010203040506")?;
+    ///
+    /// assert_eq!(Some(42), uuid.get_clock_sequence());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn get_clock_sequence(&self) -> Option<u16> {
+        match self.get_version() {
+            Some(Version::Mac) => Some(self.clock_sequence_bits()),
+            #[cfg(uuid_unstable)]
+            Some(Version::SortMac) => Some(self.clock_sequence_bits()),
+            _ => None,
+        }
+    }
+
+    /// Convert a version 1 UUID into a version 6 one.
+    ///
+    /// The timestamp is reordered so its most significant bits come first,
+    /// and the clock sequence and node ID are copied over as-is, so the
+    /// result sorts by the time the original UUID was generated. Version 6
+    /// UUIDs are returned unchanged. Returns `None` for any other version.
+    ///
+    /// This conversion can be reversed with [`Uuid::to_v1`]. To migrate
+    /// a whole set of stored UUIDs, see the `migrate` module.
+    ///
+    /// Note that usage of this method requires the `uuid_unstable` cfg to
+    /// be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let v1 = Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?;
+    /// let v6 = v1.to_v6().unwrap();
+    ///
+    /// assert_eq!(Some(Version::SortMac), v6.get_version());
+    /// assert_eq!("1e74ba22-0616-6934-8000-010203040506", v6.hyphenated().to_string());
+    /// assert_eq!(v1.get_timestamp(), v6.get_timestamp());
+    ///
+    /// assert_eq!(Some(v1), v6.to_v1());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[cfg(uuid_unstable)]
+    pub const fn to_v6(&self) -> Option<Uuid> {
+        match self.get_version() {
+            Some(Version::Mac) => {
+                let (ticks, _) = timestamp::decode_rfc4122_timestamp(self);
+
+                Some(
+                    self.with_timestamp_of(timestamp::encode_sorted_rfc4122_timestamp(
+                        ticks,
+                        self.clock_sequence_bits(),
+                        &self.node_id_bytes(),
+                    )),
+                )
+            }
+            Some(Version::SortMac) => Some(*self),
+            _ => None,
+        }
+    }
+
+    /// Convert a version 6 UUID into a version 1 one.
+    ///
+    /// This is the reverse of [`Uuid::to_v6`]. The timestamp is reordered
+    /// back into the version 1 layout, and the clock sequence and node ID
+    /// are copied over as-is. Version 1 UUIDs are returned unchanged.
+    /// Returns `None` for any other version.
+    ///
+    /// Note that usage of this method requires the `uuid_unstable` cfg to
+    /// be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let v6 = Uuid::parse_str("1e74ba22-0616-6934-8000-010203040506")?;
+    /// let v1 = v6.to_v1().unwrap();
+    ///
+    /// assert_eq!(Some(Version::Mac), v1.get_version());
+    /// assert_eq!("20616934-4ba2-11e7-8000-010203040506", v1.hyphenated().to_string());
+    ///
+    /// // Random UUIDs can't be converted
+    /// assert!(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?
+    ///     .to_v1()
+    ///     .is_none());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[cfg(uuid_unstable)]
+    pub const fn to_v1(&self) -> Option<Uuid> {
+        match self.get_version() {
+            Some(Version::SortMac) => {
+                let (ticks, _) = timestamp::decode_sorted_rfc4122_timestamp(self);
+
+                Some(self.with_timestamp_of(timestamp::encode_rfc4122_timestamp(
+                    ticks,
+                    self.clock_sequence_bits(),
+                    &self.node_id_bytes(),
+                )))
+            }
+            Some(Version::Mac) => Some(*self),
+            _ => None,
+        }
+    }
+
+    // Take the timestamp and version from `encoded`, keeping the variant bits of this UUID
+    #[cfg(uuid_unstable)]
+    const fn with_timestamp_of(&self, encoded: Uuid) -> Uuid {
+        let mut bytes = *encoded.as_bytes();
+        bytes[8] = self.as_bytes()[8];
+
+        Uuid::from_bytes(bytes)
+    }
+
+    // Version 1 and version 6 UUIDs share the same layout after the timestamp
+    const fn node_id_bytes(&self) -> [u8; 6] {
+        let bytes = self.as_bytes();
+
+        [
+            bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+        ]
+    }
+
+    const fn clock_sequence_bits(&self) -> u16 {
+        let bytes = self.as_bytes();
+
+        ((bytes[8] & 0x3F) as u16) << 8 | (bytes[9] as u16)
+    }
+
+    /// Get the time between the timestamps of two time-based UUIDs.
+    ///
+    /// Returns `None` if either UUID doesn't have a timestamp; see
+    /// [`Uuid::get_timestamp`] for the versions that do. The result is the
+    /// same whichever UUID was generated first, and is only as precise as
+    /// the timestamps themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::Uuid;
+    /// let a = Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?;
+    /// let b = Uuid::parse_str("2bc9f174-4ba2-11e7-8000-010203040506")?;
+    ///
+    /// assert_eq!(Some(Duration::from_millis(19_140)), a.time_between(&b));
+    /// assert_eq!(Some(Duration::from_millis(19_140)), b.time_between(&a));
+    ///
+    /// assert_eq!(None, a.time_between(&Uuid::nil()));
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    // `Duration::abs_diff` is newer than our MSRV
+    #[allow(unknown_lints, clippy::manual_abs_diff)]
+    pub fn time_between(&self, other: &Uuid) -> Option<Duration> {
+        let (a_secs, a_nanos) = self.get_timestamp()?.to_unix();
+        let (b_secs, b_nanos) = other.get_timestamp()?.to_unix();
+
+        let (a, b) = (
+            Duration::new(a_secs, a_nanos),
+            Duration::new(b_secs, b_nanos),
+        );
+
+        Some(if a > b { a - b } else { b - a })
+    }
+}
+
+// UUIDs are ordered by their bytes, which is the same as comparing them
+// as big-endian integers. Doing that compiles down to a pair of byte swaps
+// and wide comparisons instead of a bytewise loop or call to `memcmp`.
+impl PartialOrd for Uuid {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Uuid {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_u128().cmp(&other.as_u128())
+    }
 }
 
 impl Default for Uuid {
@@ -945,7 +1865,14 @@ impl AsRef<[u8]> for Uuid {
     }
 }
 
-#[cfg(feature = "serde")]
+#[cfg(uuid_core_only)]
+impl std::fmt::Debug for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Uuid").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(feature = "serde", not(uuid_core_only)))]
 pub mod serde {
     //! Adapters for alternative `serde` formats.
     //!
@@ -953,10 +1880,10 @@ pub mod serde {
     //! to change the way a [`Uuid`](../struct.Uuid.html) is serialized
     //! and deserialized.
 
-    pub use crate::external::serde_support::compact;
+    pub use crate::external::serde_support::{compact, strict};
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(uuid_core_only)))]
 mod tests {
     use super::*;
 
@@ -1163,7 +2090,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(uuid_unstable)]
     #[cfg_attr(
         all(
             target_arch = "wasm32",
@@ -1182,6 +2108,11 @@ mod tests {
         assert_eq!(max.get_version(), Some(Version::Max));
         assert_eq!(not_max.get_version(), Some(Version::Random));
 
+        // Only the max UUID itself has the max version
+        let mut almost_max = [0xff; 16];
+        almost_max[15] = 0xfe;
+        assert_eq!(None, Uuid::from_bytes(almost_max).get_version());
+
         assert_eq!(
             max,
             Builder::from_bytes([0xff; 16])
@@ -1190,6 +2121,139 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_get_timestamp() {
+        let ticks = 0x1e7_52a1_f3b4_9a52;
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        let v1 = Builder::from_rfc4122_timestamp(ticks, 42, &node_id).into_uuid();
+        let ts = v1.get_timestamp().unwrap();
+        assert_eq!((ticks, 42), (ts.to_gregorian_ticks(), ts.counter));
+
+        #[cfg(uuid_unstable)]
+        {
+            let v6 = Builder::from_sorted_rfc4122_timestamp(ticks, 42, &node_id).into_uuid();
+            let ts = v6.get_timestamp().unwrap();
+            assert_eq!((ticks, 42), (ts.to_gregorian_ticks(), ts.counter));
+
+            let v7 = Builder::from_unix_timestamp_millis(1_497_624_119_812, &[0; 10]).into_uuid();
+            assert_eq!(
+                (1_497_624_119, 812_000_000),
+                v7.get_timestamp().unwrap().to_unix()
+            );
+        }
+
+        assert!(new().get_timestamp().is_none());
+        assert!(Uuid::nil().get_timestamp().is_none());
+        assert!(Uuid::max().get_timestamp().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_get_node_id_and_clock_sequence() {
+        let ticks = 0x1e7_52a1_f3b4_9a52;
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        let v1 = Builder::from_rfc4122_timestamp(ticks, 0x3FFF, &node_id).into_uuid();
+        assert_eq!(Some(node_id), v1.get_node_id());
+        assert_eq!(Some(0x3FFF), v1.get_clock_sequence());
+
+        #[cfg(uuid_unstable)]
+        {
+            let v6 = Builder::from_sorted_rfc4122_timestamp(ticks, 42, &node_id).into_uuid();
+            assert_eq!(Some(node_id), v6.get_node_id());
+            assert_eq!(Some(42), v6.get_clock_sequence());
+        }
+
+        assert_eq!(None, new().get_node_id());
+        assert_eq!(None, new().get_clock_sequence());
+        assert_eq!(None, Uuid::nil().get_node_id());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_microsoft_and_ncs_fields() {
+        let d4 = [0xc0, 1, 2, 3, 4, 5, 6, 7];
+
+        let microsoft = Builder::from_microsoft_fields(0xa1a2a3a4, 0xb1b2, 0xc1c2, &d4).into_uuid();
+        assert_eq!(Variant::Microsoft, microsoft.get_variant());
+        assert_eq!(
+            Some((0xa1a2a3a4, 0xb1b2, 0xc1c2, d4)),
+            microsoft.get_microsoft_fields()
+        );
+        assert_eq!(None, microsoft.get_ncs_fields());
+
+        let host = [1, 2, 3, 4, 5, 6, 7];
+
+        let ncs = Builder::from_ncs_fields(0xffff_ffff_ffff, 0x7f, &host)
+            .try_build()
+            .unwrap();
+        assert_eq!(Variant::NCS, ncs.get_variant());
+        assert_eq!(Some((0xffff_ffff_ffff, 0x7f, host)), ncs.get_ncs_fields());
+        assert_eq!(None, ncs.get_microsoft_fields());
+
+        assert!(Builder::from_ncs_fields(1 << 48, 0, &host)
+            .try_build()
+            .is_err());
+        assert!(Builder::from_ncs_fields(0, 0x80, &host)
+            .try_build()
+            .is_err());
+
+        assert_eq!(None, new().get_microsoft_fields());
+        assert_eq!(None, new().get_ncs_fields());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(uuid_unstable)]
+    fn test_to_v6_to_v1() {
+        let ticks = 0x1e7_52a1_f3b4_9a52;
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        let v1 = Builder::from_rfc4122_timestamp(ticks, 0x3FFF, &node_id).into_uuid();
+        let v6 = Builder::from_sorted_rfc4122_timestamp(ticks, 0x3FFF, &node_id).into_uuid();
+
+        assert_eq!(Some(v6), v1.to_v6());
+        assert_eq!(Some(v1), v6.to_v1());
+
+        assert_eq!(Some(v1), v1.to_v1());
+        assert_eq!(Some(v6), v6.to_v6());
+
+        assert_eq!(None, new().to_v6());
+        assert_eq!(None, new().to_v1());
+        assert_eq!(None, Uuid::nil().to_v6());
+        assert_eq!(None, Uuid::max().to_v1());
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1218,6 +2282,66 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_predefined_hashspaces() {
+        assert_eq!(
+            Uuid::HASHSPACE_SHA2_224.hyphenated().to_string(),
+            "59031ca3-fbdb-47fb-9f6c-0f30e2e83145"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA2_256.hyphenated().to_string(),
+            "3fb32780-953c-4464-9cfd-e85dbbe9843d"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA2_384.hyphenated().to_string(),
+            "e6800581-f333-484b-8778-601ff2b58da8"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA2_512.hyphenated().to_string(),
+            "0fde22f2-e7ba-4fd1-9753-9c2ea88fa3f9"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA2_512_224.hyphenated().to_string(),
+            "003c2038-c4fe-4b95-a672-0c26c1b79542"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA2_512_256.hyphenated().to_string(),
+            "9475ad00-3769-4c07-9642-5e7383732306"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA3_224.hyphenated().to_string(),
+            "9768761f-ac5a-419e-a180-7ca239e8025a"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA3_256.hyphenated().to_string(),
+            "2034d66b-4047-4553-8f80-70e593176877"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA3_384.hyphenated().to_string(),
+            "872fb339-2636-4bdd-bda6-b6dc2a82b1b3"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHA3_512.hyphenated().to_string(),
+            "a4920a5d-a8a6-426c-8d14-a6cafbe64c7b"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHAKE_128.hyphenated().to_string(),
+            "7ea218f6-629a-425f-9f88-7439d63296bb"
+        );
+        assert_eq!(
+            Uuid::HASHSPACE_SHAKE_256.hyphenated().to_string(),
+            "2e7fc6a4-2919-4edc-b0ba-7d7062ce4f0a"
+        );
+    }
+
     #[cfg(feature = "v3")]
     #[test]
     #[cfg_attr(
@@ -1235,6 +2359,52 @@ mod tests {
         assert_eq!(uuid.get_version_num(), 3);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_version_display_from_str() {
+        let versions = [
+            (Version::Nil, "Nil", None),
+            (Version::Mac, "Mac", Some("v1")),
+            (Version::Dce, "Dce", Some("v2")),
+            (Version::Md5, "Md5", Some("v3")),
+            (Version::Random, "Random", Some("v4")),
+            (Version::Sha1, "Sha1", Some("v5")),
+            #[cfg(uuid_unstable)]
+            (Version::SortMac, "SortMac", Some("v6")),
+            #[cfg(uuid_unstable)]
+            (Version::SortRand, "SortRand", Some("v7")),
+            #[cfg(uuid_unstable)]
+            (Version::Custom, "Custom", Some("v8")),
+            (Version::Max, "Max", None),
+        ];
+
+        for &(version, name, number) in versions.iter() {
+            assert_eq!(name, version.to_string());
+            assert_eq!(Ok(version), name.parse());
+            assert_eq!(Ok(version), name.to_ascii_uppercase().parse());
+
+            if let Some(number) = number {
+                assert_eq!(Ok(version), number.parse());
+                assert_eq!(Ok(version), number[1..].parse());
+                assert_eq!(Ok(version), number.to_ascii_uppercase().parse());
+            }
+        }
+
+        for invalid in ["", "v", "v0", "v9", "15", "random!", "sortrandom"].iter() {
+            assert_eq!(
+                ErrorKind::UnsupportedVersion,
+                invalid.parse::<Version>().unwrap_err().kind()
+            );
+        }
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1248,7 +2418,7 @@ mod tests {
         let uuid1 = new();
         let uuid2 = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
         let uuid3 = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
-        let uuid4 = Uuid::parse_str("936DA01F9ABD4d9dC0C702AF85C822A8").unwrap();
+        let uuid4 = Uuid::parse_str("936DA01F-9ABD-4d9d-C0C7-02AF85C822A8").unwrap();
         let uuid5 = Uuid::parse_str("F9168C5E-CEB2-4faa-D6BF-329BF39FA1E4").unwrap();
         let uuid6 = Uuid::parse_str("f81d4fae-7dec-11d0-7765-00a0c91e6bf6").unwrap();
 
@@ -1260,6 +2430,216 @@ mod tests {
         assert_eq!(uuid6.get_variant(), Variant::NCS);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_try_build() {
+        let ticks = 0x1e7_4ba2_2061_6934;
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        let uuid = Builder::from_rfc4122_timestamp(ticks, 0x3fff, &node_id)
+            .try_build()
+            .unwrap();
+        assert_eq!(
+            uuid,
+            Builder::from_rfc4122_timestamp(ticks, 0x3fff, &node_id).into_uuid()
+        );
+
+        assert_eq!(
+            Builder::from_rfc4122_timestamp(1 << 60, 0, &node_id).try_build(),
+            Err(Error(error::ErrorRepr::Range {
+                field: "timestamp",
+                bits: 60
+            }))
+        );
+        assert_eq!(
+            Builder::from_rfc4122_timestamp(ticks, 0x4000, &node_id).try_build(),
+            Err(Error(error::ErrorRepr::Range {
+                field: "counter",
+                bits: 14
+            }))
+        );
+
+        let mut builder = Builder::from_rfc4122_timestamp(ticks, 0, &[0; 6]);
+        builder
+            .set_node_id(&node_id[..5])
+            .set_variant(Variant::RFC4122);
+        assert_eq!(
+            builder.try_build(),
+            Err(Error(error::ErrorRepr::NodeLength { len: 5 }))
+        );
+
+        // The first error is the one that's kept
+        assert_eq!(
+            Builder::from_rfc4122_timestamp(ticks, 0x4000, &node_id)
+                .with_node_id(&[])
+                .try_build()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfRange
+        );
+
+        #[cfg(uuid_unstable)]
+        {
+            assert!(
+                Builder::from_unix_timestamp_millis(u64::MAX >> 16, &[0; 10])
+                    .try_build()
+                    .is_ok()
+            );
+            assert!(Builder::from_unix_timestamp_millis(1 << 48, &[0; 10])
+                .try_build()
+                .is_err());
+            assert!(
+                Builder::from_sorted_rfc4122_timestamp(ticks, 0x4000, &node_id)
+                    .try_build()
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_rfc4122_fields() {
+        let ticks = 0x1e7_4ba2_2061_6934;
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        assert_eq!(
+            Builder::from_rfc4122_fields(
+                Version::Mac,
+                0x2061_6934,
+                0x4ba2,
+                0x1e7,
+                0x3fff,
+                0x0102_0304_0506
+            )
+            .try_build(),
+            Ok(Builder::from_rfc4122_timestamp(ticks, 0x3fff, &node_id).into_uuid())
+        );
+
+        assert_eq!(
+            Builder::from_rfc4122_fields(Version::Mac, 0, 0, 0x1000, 0, 0).try_build(),
+            Err(Error(error::ErrorRepr::Range {
+                field: "time_hi",
+                bits: 12
+            }))
+        );
+        assert_eq!(
+            Builder::from_rfc4122_fields(Version::Mac, 0, 0, 0, 0x4000, 0).try_build(),
+            Err(Error(error::ErrorRepr::Range {
+                field: "clock_seq",
+                bits: 14
+            }))
+        );
+        assert_eq!(
+            Builder::from_rfc4122_fields(Version::Mac, 0, 0, 0, 0, 1 << 48).try_build(),
+            Err(Error(error::ErrorRepr::Range {
+                field: "node",
+                bits: 48
+            }))
+        );
+
+        let mut builder = Builder::from_rfc4122_timestamp(ticks, 0, &node_id);
+        builder.set_clock_seq(0x3fff);
+        assert_eq!(
+            builder.try_build(),
+            Builder::from_rfc4122_timestamp(ticks, 0x3fff, &node_id).try_build()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_check_strict() {
+        for uuid in [
+            "550e8400-e29b-11d4-a716-446655440000",
+            "550e8400-e29b-41d4-a716-446655440000",
+            "550e8400-e29b-81d4-b716-446655440000",
+            "00000000-0000-0000-0000-000000000000",
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+        ] {
+            assert!(Uuid::parse_str_strict(uuid).is_ok(), "{}", uuid);
+        }
+
+        assert_eq!(
+            Uuid::parse_str_strict("936DA01F-9ABD-4d9d-C0C7-02AF85C822A8"),
+            Err(Error(error::ErrorRepr::UnsupportedVariant {
+                variant: Variant::Microsoft
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_str_strict("f81d4fae-7dec-11d0-7765-00a0c91e6bf6"),
+            Err(Error(error::ErrorRepr::UnsupportedVariant {
+                variant: Variant::NCS
+            }))
+        );
+        #[cfg(not(feature = "v2"))]
+        assert_eq!(
+            Uuid::parse_str_strict("550e8400-e29b-21d4-a716-446655440000"),
+            Err(Error(error::ErrorRepr::UnsupportedVersion { version: 2 }))
+        );
+        #[cfg(feature = "v2")]
+        assert!(Uuid::parse_str_strict("550e8400-e29b-21d4-a716-446655440000").is_ok());
+        assert_eq!(
+            Uuid::parse_str_strict("550e8400-e29b-91d4-a716-446655440000"),
+            Err(Error(error::ErrorRepr::UnsupportedVersion { version: 9 }))
+        );
+        assert_eq!(
+            Uuid::parse_str_strict("550e8400-e29b-01d4-a716-446655440000"),
+            Err(Error(error::ErrorRepr::UnsupportedVersion { version: 0 }))
+        );
+
+        assert!(Builder::from_bytes([0xff; 16]).into_strict_uuid().is_ok());
+        assert!(Builder::from_bytes([0xfe; 16]).into_strict_uuid().is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_time_between() {
+        let a = Builder::from_rfc4122_timestamp(0x1e7_4ba2_2061_6934, 0, &[1; 6]).into_uuid();
+        let b = Builder::from_rfc4122_timestamp(0x1e7_4ba2_2061_6935, 7, &[2; 6]).into_uuid();
+
+        assert_eq!(Some(Duration::from_nanos(100)), a.time_between(&b));
+        assert_eq!(Some(Duration::from_nanos(100)), b.time_between(&a));
+        assert_eq!(Some(Duration::from_secs(0)), a.time_between(&a));
+
+        assert_eq!(None, a.time_between(&new()));
+        assert_eq!(None, new().time_between(&a));
+
+        #[cfg(uuid_unstable)]
+        {
+            let c = Builder::from_unix_timestamp_millis(1_497_624_119_000, &[0; 10]).into_uuid();
+            let d = Builder::from_unix_timestamp_millis(1_497_624_120_500, &[0; 10]).into_uuid();
+
+            assert_eq!(Some(Duration::from_millis(1_500)), c.time_between(&d));
+        }
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1790,6 +3170,121 @@ mod tests {
         assert_eq!(u1, u2);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_refs() {
+        let u1 = new();
+
+        let mut buf = [0; 17];
+        buf[1..].copy_from_slice(u1.as_bytes());
+
+        let u2 = Uuid::from_slice_ref(&buf[1..]).unwrap();
+        assert_eq!(&u1, u2);
+        assert!(std::ptr::eq(u2.as_bytes().as_ptr(), buf[1..].as_ptr()));
+
+        assert_eq!(
+            Uuid::from_slice_ref(&buf),
+            Err(Error(error::ErrorRepr::ByteLength { len: 17 }))
+        );
+
+        let mut bytes = *u1.as_bytes();
+        *Uuid::from_bytes_mut(&mut bytes) = new2();
+        assert_eq!(&bytes, new2().as_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_unaligned_roundtrip() {
+        let u1 = new();
+
+        let mut buf = [0xff; 19];
+        u1.write_unaligned_at(&mut buf, 3).unwrap();
+
+        assert_eq!(&buf[3..], u1.as_bytes());
+        assert_eq!(&buf[..3], &[0xff; 3]);
+
+        let u2 = Uuid::read_unaligned_at(&buf, 3).unwrap();
+        assert_eq!(u1, u2);
+
+        // SAFETY: `buf` has 16 bytes after offset 3
+        let u3 = unsafe { Uuid::read_unaligned(buf.as_ptr().add(3)) };
+        assert_eq!(u1, u3);
+
+        let mut buf = [0; 17];
+        // SAFETY: `buf` has 16 bytes after offset 1
+        unsafe { u1.write_unaligned(buf.as_mut_ptr().add(1)) };
+        assert_eq!(&buf[1..], u1.as_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_unaligned_too_small() {
+        let mut buf = [0; 18];
+
+        assert_eq!(
+            Uuid::read_unaligned_at(&buf, 3),
+            Err(Error(error::ErrorRepr::ByteLength { len: 15 }))
+        );
+        assert_eq!(
+            Uuid::read_unaligned_at(&buf, 20),
+            Err(Error(error::ErrorRepr::ByteLength { len: 0 }))
+        );
+        assert_eq!(
+            new().write_unaligned_at(&mut buf, 3),
+            Err(Error(error::ErrorRepr::ByteLength { len: 15 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_ord_matches_bytes() {
+        let uuids = [
+            Uuid::nil(),
+            Uuid::from_u128(1),
+            Uuid::from_u128(0xff),
+            Uuid::from_u128(1 << 64),
+            Uuid::from_u128(0x80 << 120),
+            Uuid::from_u128(u128::MAX),
+            new(),
+            new2(),
+        ];
+
+        for a in &uuids {
+            for b in &uuids {
+                assert_eq!(a.cmp(b), a.as_bytes().cmp(b.as_bytes()));
+                assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+            }
+        }
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -55,6 +55,7 @@ define_uuid_macro! {
 ///
 /// ```
 /// # use uuid::uuid;
+/// # #[cfg(not(feature = "tiny"))]
 /// let uuid = uuid!("urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4");
 /// ```
 ///
@@ -97,3 +98,49 @@ define_uuid_macro! {
 ///
 /// [uuid::Uuid]: https://docs.rs/uuid/*/uuid/struct.Uuid.html
 }
+
+/// Define named namespace [`Uuid`][uuid::Uuid] constants.
+///
+/// Each namespace is parsed at compile time using [`uuid!`], so a
+/// typo in a namespace is a compilation error instead of a runtime panic.
+/// Keeping a project's namespaces together in one place also makes it easy
+/// to see which ones are in use.
+///
+/// ## Examples
+///
+/// ```
+/// # use uuid::{namespaces, Uuid};
+/// namespaces! {
+///     /// The namespace for order IDs.
+///     pub ORDERS = "b1d8ffa2-6a1f-4bd4-9a32-53b0a1f3c8d1";
+///     /// The namespace for customer IDs.
+///     pub(crate) CUSTOMERS = "7f4c02e5-0b5c-4f6e-8b3d-2d1c9a0e6f44";
+/// }
+///
+/// assert_eq!(
+///     "b1d8ffa2-6a1f-4bd4-9a32-53b0a1f3c8d1",
+///     ORDERS.hyphenated().to_string(),
+/// );
+/// # #[cfg(feature = "v5")]
+/// let order = Uuid::new_v5(&ORDERS, b"order-1234");
+/// ```
+///
+/// Invalid namespaces are rejected:
+///
+/// ```compile_fail
+/// # use uuid::namespaces;
+/// namespaces! {
+///     pub ORDERS = "b1d8ffa2-6a1f-4bd4-9a32-53b0a1f3c8dz";
+/// }
+/// ```
+///
+/// [uuid::Uuid]: https://docs.rs/uuid/*/uuid/struct.Uuid.html
+#[macro_export]
+macro_rules! namespaces {
+    ($($(#[$attr:meta])* $vis:vis $name:ident = $uuid:literal;)*) => {
+        $(
+            $(#[$attr])*
+            $vis const $name: $crate::Uuid = $crate::uuid!($uuid);
+        )*
+    };
+}
@@ -1,14 +1,40 @@
 #[cfg(feature = "v3")]
 pub(crate) fn hash(ns: &[u8], src: &[u8]) -> [u8; 16] {
-    use md_5::{Digest, Md5};
+    let mut hasher = Hasher::new(ns);
 
-    let mut hasher = Md5::new();
-
-    hasher.update(ns);
     hasher.update(src);
 
-    let mut bytes = [0; 16];
-    bytes.copy_from_slice(&hasher.finalize()[..16]);
+    hasher.finish()
+}
+
+#[cfg(feature = "v3")]
+#[derive(Clone)]
+pub(crate) struct Hasher(md_5::Md5);
+
+#[cfg(feature = "v3")]
+impl Hasher {
+    pub(crate) fn new(ns: &[u8]) -> Self {
+        use md_5::{Digest, Md5};
+
+        let mut hasher = Md5::new();
+
+        hasher.update(ns);
+
+        Hasher(hasher)
+    }
+
+    pub(crate) fn update(&mut self, src: &[u8]) {
+        use md_5::Digest;
+
+        self.0.update(src);
+    }
+
+    pub(crate) fn finish(&self) -> [u8; 16] {
+        use md_5::Digest;
+
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&self.0.clone().finalize()[..16]);
 
-    bytes
+        bytes
+    }
 }
@@ -0,0 +1,261 @@
+//! Migrating stored UUIDs between versions.
+//!
+//! This module is useful for converting an existing column of version 1
+//! primary keys into version 6 ones, which sort by the time they were
+//! generated. The timestamp, clock sequence, and node ID of each UUID
+//! are kept, so the conversion can be reversed, and each migrated UUID
+//! still identifies the host and time it came from.
+//!
+//! Note that you need to enable the `std` Cargo feature and the
+//! `uuid_unstable` cfg in order to use this module.
+
+use crate::{collision::CollisionChecker, std::vec::Vec, Uuid, Version};
+
+pub use crate::collision::Collision;
+
+/// The result of migrating a set of UUIDs from one version to another.
+///
+/// UUIDs are migrated in the order they're given, and the migrated UUID
+/// at each index corresponds to the original UUID at the same index.
+/// UUIDs that aren't the version being migrated from are passed through
+/// unchanged and reported as [`skipped`](Migration::skipped).
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, migrate::Migration};
+/// let uuids = [
+///     Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?,
+///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+/// ];
+///
+/// let migration = Migration::v1_to_v6(uuids);
+///
+/// assert!(migration.is_clean());
+/// assert_eq!(
+///     "1e74ba22-0616-6934-8000-010203040506",
+///     migration.uuids()[0].hyphenated().to_string(),
+/// );
+/// assert_eq!([1], migration.skipped());
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Migration {
+    uuids: Vec<Uuid>,
+    skipped: Vec<usize>,
+    out_of_order: Vec<usize>,
+    collisions: Vec<Collision>,
+}
+
+impl Migration {
+    /// Migrate version 1 UUIDs to version 6.
+    ///
+    /// Each version 1 UUID has its timestamp reordered so that the most
+    /// significant bits come first. The clock sequence and node ID are
+    /// copied over as-is.
+    pub fn v1_to_v6<I: IntoIterator<Item = Uuid>>(uuids: I) -> Self {
+        let uuids = uuids.into_iter();
+
+        let mut migrated = Vec::with_capacity(uuids.size_hint().0);
+        let mut skipped = Vec::new();
+        let mut original = CollisionChecker::with_capacity(migrated.capacity());
+
+        for (index, uuid) in uuids.enumerate() {
+            original.insert(uuid);
+
+            match v1_to_v6(&uuid) {
+                Some(v6) => migrated.push(v6),
+                None => {
+                    skipped.push(index);
+                    migrated.push(uuid);
+                }
+            }
+        }
+
+        Migration::check(migrated, skipped, original)
+    }
+
+    fn check(uuids: Vec<Uuid>, skipped: Vec<usize>, original: CollisionChecker) -> Self {
+        let out_of_order = uuids
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[1] < pair[0])
+            .map(|(index, _)| index + 1)
+            .collect();
+
+        // Only report collisions the migration introduced, not ones that
+        // were already there in the original UUIDs
+        let existing = original.collisions();
+
+        let mut checker = CollisionChecker::with_capacity(uuids.len());
+        checker.extend(uuids.iter().copied());
+
+        let collisions = checker
+            .collisions()
+            .into_iter()
+            .filter(|collision| {
+                !existing.iter().any(|existing| {
+                    existing.first == collision.first && existing.duplicate == collision.duplicate
+                })
+            })
+            .collect();
+
+        Migration {
+            uuids,
+            skipped,
+            out_of_order,
+            collisions,
+        }
+    }
+
+    /// Get the migrated UUIDs.
+    pub fn uuids(&self) -> &[Uuid] {
+        &self.uuids
+    }
+
+    /// Take the migrated UUIDs.
+    pub fn into_uuids(self) -> Vec<Uuid> {
+        self.uuids
+    }
+
+    /// Get the indexes of UUIDs that weren't migrated because they weren't
+    /// the right version to begin with.
+    pub fn skipped(&self) -> &[usize] {
+        &self.skipped
+    }
+
+    /// Get the indexes of migrated UUIDs that sort before the one
+    /// immediately before them.
+    ///
+    /// If the original UUIDs were given in the order they were generated
+    /// then this will only be empty if the migrated UUIDs can be sorted
+    /// without changing that order.
+    pub fn out_of_order(&self) -> &[usize] {
+        &self.out_of_order
+    }
+
+    /// Get any migrated UUIDs that are duplicates of each other when the
+    /// UUIDs they were migrated from weren't.
+    ///
+    /// This can happen when UUIDs that were skipped are already the
+    /// version being migrated to.
+    pub fn collisions(&self) -> &[Collision] {
+        &self.collisions
+    }
+
+    /// Whether the migrated UUIDs kept their order and didn't introduce
+    /// any collisions.
+    pub fn is_clean(&self) -> bool {
+        self.out_of_order.is_empty() && self.collisions.is_empty()
+    }
+}
+
+// Reorder the timestamp of a version 1 UUID, keeping its clock sequence and node ID
+fn v1_to_v6(uuid: &Uuid) -> Option<Uuid> {
+    match uuid.get_version() {
+        Some(Version::Mac) => uuid.to_v6(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn v1(ticks: u64, counter: u16, node: u8) -> Uuid {
+        timestamp::encode_rfc4122_timestamp(ticks, counter, &[node; 6])
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_v1_to_v6() {
+        let uuids = [
+            v1(0x1e7_4ba2_2061_6934, 0x3fff, 1),
+            v1(0x1e7_4ba2_2061_6935, 0, 2),
+            v1(0x1e7_4ba2_2061_6935, 1, 1),
+        ];
+
+        let migration = Migration::v1_to_v6(uuids.iter().copied());
+
+        assert!(migration.is_clean());
+        assert!(migration.skipped().is_empty());
+
+        for (original, migrated) in uuids.iter().zip(migration.uuids()) {
+            assert_eq!(Some(Version::SortMac), migrated.get_version());
+            assert_eq!(original.as_bytes()[8..], migrated.as_bytes()[8..]);
+            assert_eq!(
+                timestamp::decode_rfc4122_timestamp(original),
+                timestamp::decode_sorted_rfc4122_timestamp(migrated)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_v1_to_v6_out_of_order() {
+        // The second UUID was generated after the third
+        let uuids = [
+            v1(0x1e7_4ba2_2061_6934, 0, 1),
+            v1(0x1e8_4ba2_2061_6933, 0, 1),
+            v1(0x1e7_4ba2_2061_6935, 0, 1),
+        ];
+
+        let migration = Migration::v1_to_v6(uuids.iter().copied());
+
+        assert_eq!([2], migration.out_of_order());
+        assert!(migration.collisions().is_empty());
+        assert!(!migration.is_clean());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_v1_to_v6_collisions() {
+        let original = v1(0x1e7_4ba2_2061_6934, 42, 1);
+        let existing = v1_to_v6(&original).unwrap();
+
+        let uuids = [original, existing, original];
+
+        let migration = Migration::v1_to_v6(uuids.iter().copied());
+
+        assert_eq!([1], migration.skipped());
+        // The original UUID was already duplicated, so only its collision
+        // with the existing version 6 UUID is new
+        assert_eq!(
+            [Collision {
+                uuid: existing,
+                first: 0,
+                duplicate: 1,
+            }],
+            migration.collisions()
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Trees of namespaces for name-based UUIDs.
+//!
+//! Note that you need to enable the `v5` Cargo feature in order to use this
+//! module.
+
+use crate::Uuid;
+
+/// A namespace for version 5 UUIDs that can have child namespaces.
+///
+/// A child namespace is the version 5 UUID of its name in its parent, so
+/// a tree of namespaces, like a company, then a service, then a kind of
+/// entity, can be derived from a single root instead of keeping a UUID
+/// constant for each level. The same path of names always derives the same
+/// namespace.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Namespace, Uuid};
+/// let company = Namespace::DNS.derive("example.com");
+/// let users = company.derive("accounts").derive("users");
+///
+/// let uuid = users.new_v5("alice");
+///
+/// assert_eq!(
+///     Uuid::new_v5(
+///         &Uuid::new_v5(&Uuid::new_v5(company.as_uuid(), b"accounts"), b"users"),
+///         b"alice"
+///     ),
+///     uuid
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Namespace(Uuid);
+
+impl Namespace {
+    /// The namespace for fully-qualified domain names.
+    pub const DNS: Self = Namespace(Uuid::NAMESPACE_DNS);
+
+    /// The namespace for ISO object identifiers (OIDs).
+    pub const OID: Self = Namespace(Uuid::NAMESPACE_OID);
+
+    /// The namespace for URLs.
+    pub const URL: Self = Namespace(Uuid::NAMESPACE_URL);
+
+    /// The namespace for X.500 distinguished names (DNs).
+    pub const X500: Self = Namespace(Uuid::NAMESPACE_X500);
+
+    /// Use a UUID as a namespace.
+    ///
+    /// This is useful for the root of a tree of namespaces. Any UUID can be
+    /// a namespace, but a random one is best so it won't clash with others.
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Namespace(uuid)
+    }
+
+    /// Derive a child namespace from its name.
+    ///
+    /// The child namespace is the version 5 UUID of `name` in this
+    /// namespace.
+    pub fn derive(&self, name: impl AsRef<[u8]>) -> Self {
+        Namespace(self.new_v5(name))
+    }
+
+    /// Create a version 5 UUID for a name in this namespace.
+    ///
+    /// This is the same as calling [`Uuid::new_v5`] with this namespace.
+    pub fn new_v5(&self, name: impl AsRef<[u8]>) -> Uuid {
+        Uuid::new_v5(&self.0, name.as_ref())
+    }
+
+    /// Get the UUID of this namespace.
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consume the namespace and get its UUID.
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for Namespace {
+    fn from(uuid: Uuid) -> Self {
+        Namespace(uuid)
+    }
+}
+
+impl From<Namespace> for Uuid {
+    fn from(namespace: Namespace) -> Self {
+        namespace.0
+    }
+}
+
+impl AsRef<Uuid> for Namespace {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_derive() {
+        let root = Namespace::from_uuid(Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8));
+
+        let service = root.derive("billing");
+        assert_eq!(
+            Uuid::new_v5(root.as_uuid(), b"billing"),
+            service.into_uuid()
+        );
+
+        // The same path always derives the same namespace
+        assert_eq!(service, root.derive(b"billing"));
+        assert_eq!(
+            service.derive("invoices").new_v5("42"),
+            root.derive("billing").derive("invoices").new_v5(b"42")
+        );
+
+        // Different paths derive different namespaces
+        assert_ne!(service.derive("invoices"), root.derive("invoices"));
+        assert_ne!(service.derive("invoices"), service.derive("receipts"));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_predefined() {
+        assert_eq!(Uuid::NAMESPACE_DNS, Namespace::DNS.into_uuid());
+        assert_eq!(
+            Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://example.com"),
+            Namespace::URL.new_v5("https://example.com")
+        );
+        assert_eq!(Namespace::OID, Namespace::from(Uuid::from(Namespace::OID)));
+    }
+}
@@ -0,0 +1,738 @@
+//! Node IDs for version 1 and version 6 UUIDs.
+//!
+//! Versions 1 and 6 UUIDs include a node ID that's traditionally the MAC
+//! address of the machine generating them. In environments like the browser
+//! there's no MAC address available, so RFC4122 allows a random node ID to be
+//! used instead. This module helps generate a random node ID once and then
+//! persist it, so UUIDs from the same environment remain correlated across
+//! sessions. [`NodeId::process_random`] keeps one for the lifetime of the
+//! process instead.
+//!
+//! Note that you need to enable the `v1` or `v6` Cargo feature in order to
+//! use this module. Generating random node IDs also needs the `rng` feature.
+//!
+//! # References
+//!
+//! * [Node IDs that do not identify the host in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.5)
+
+use crate::{
+    error::{Error, ErrorRepr},
+    std::convert::TryFrom,
+};
+
+#[cfg(feature = "rng")]
+use crate::rng;
+
+/// The node ID of a version 1 or version 6 UUID.
+///
+/// A node ID is 6 bytes, like a MAC address. Node IDs that aren't derived
+/// from a real MAC address should have their multicast bit set so they
+/// can't conflict with one that is.
+///
+/// Node IDs are formatted as colon-separated hex bytes, like
+/// `01:23:45:67:89:ab`.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Builder, NodeId};
+/// let node_id: NodeId = "01:23:45:67:89:ab".parse()?;
+///
+/// assert!(node_id.is_multicast());
+///
+/// let uuid = Builder::from_rfc4122_timestamp(0x1e7_52a1_f3b4_9580, 0, node_id.as_bytes()).into_uuid();
+///
+/// assert_eq!(
+///     "f3b49580-52a1-11e7-8000-0123456789ab",
+///     uuid.hyphenated().to_string(),
+/// );
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NodeId([u8; 6]);
+
+impl NodeId {
+    /// Create a node ID from its bytes.
+    pub const fn from_bytes(bytes: [u8; 6]) -> Self {
+        NodeId(bytes)
+    }
+
+    /// Create a node ID from a slice of 6 bytes.
+    pub fn from_slice(b: &[u8]) -> Result<Self, Error> {
+        <[u8; 6]>::try_from(b)
+            .map(NodeId)
+            .map_err(|_| Error(ErrorRepr::NodeLength { len: b.len() }))
+    }
+
+    /// Generate a random node ID.
+    ///
+    /// The multicast bit of the node ID is set, so it can't conflict with
+    /// a node ID derived from a real MAC address.
+    ///
+    /// Note that usage of this method requires the `rng` feature of this
+    /// crate to be enabled.
+    #[cfg(feature = "rng")]
+    pub fn random() -> Self {
+        NodeId(random_node_id())
+    }
+
+    /// Get a random node ID that's shared by the whole process.
+    ///
+    /// The first call generates a node ID like [`NodeId::random`], and every
+    /// later call returns the same one until the process exits. This is an
+    /// easy way to generate version 1 and version 6 UUIDs that don't reveal
+    /// the MAC address of the machine, while keeping a consistent node ID
+    /// like a real MAC address would. Use [`persistent_node_id`] to keep the
+    /// same node ID across processes instead.
+    ///
+    /// Note that usage of this method requires the `rng` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, NodeId};
+    /// let node_id = NodeId::process_random();
+    ///
+    /// assert!(node_id.is_multicast());
+    /// assert_eq!(node_id, NodeId::process_random());
+    ///
+    /// let uuid = Builder::from_rfc4122_timestamp(0x1e7_52a1_f3b4_9a52, 42, node_id.as_bytes()).into_uuid();
+    ///
+    /// assert_eq!(Some(*node_id.as_bytes()), uuid.get_node_id());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Node IDs that do not identify the host in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.5)
+    #[cfg(feature = "rng")]
+    pub fn process_random() -> Self {
+        use atomic::{Atomic, Ordering};
+
+        // A random node ID always has its multicast bit set, so it's never zero
+        static NODE_ID: Atomic<u64> = Atomic::new(0);
+
+        let mut node_id = NODE_ID.load(Ordering::Acquire);
+
+        if node_id == 0 {
+            let mut bytes = [0; 8];
+            bytes[2..].copy_from_slice(&random_node_id());
+
+            node_id = match NODE_ID.compare_exchange(
+                0,
+                u64::from_be_bytes(bytes),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => u64::from_be_bytes(bytes),
+                // Another thread got there first
+                Err(existing) => existing,
+            };
+        }
+
+        let bytes = node_id.to_be_bytes();
+
+        NodeId([bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+    }
+
+    /// Derive a node ID from a stable identifier for the machine.
+    ///
+    /// The same `machine_id` always produces the same node ID, so UUIDs
+    /// generated on the same machine remain correlated without needing to
+    /// persist anything. This is useful with identifiers like the contents
+    /// of `/etc/machine-id`. The node ID is a hash of `machine_id`, so it
+    /// doesn't reveal it.
+    ///
+    /// The multicast bit of the node ID is set, so it can't conflict with
+    /// a node ID derived from a real MAC address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::NodeId;
+    /// let node_id = NodeId::from_machine_id(b"b08dfa6083e7567a1921a715000001fb");
+    ///
+    /// assert!(node_id.is_multicast());
+    /// assert_eq!(node_id, NodeId::from_machine_id(b"b08dfa6083e7567a1921a715000001fb"));
+    /// ```
+    pub const fn from_machine_id(machine_id: &[u8]) -> Self {
+        let hash = hash_machine_id(machine_id).to_be_bytes();
+
+        NodeId([hash[0] | 0x01, hash[1], hash[2], hash[3], hash[4], hash[5]])
+    }
+
+    /// Read the node ID from the hardware address of the named network interface.
+    ///
+    /// This is the node ID described by RFC4122, so UUIDs generated with it
+    /// reveal the MAC address of the machine that generated them. Consider
+    /// [`NodeId::random`] or [`NodeId::from_machine_id`] if that's a concern.
+    ///
+    /// Hardware addresses can be read on Linux, macOS, and Windows. An error
+    /// is returned if the interface doesn't exist or has no hardware address.
+    ///
+    /// Note that usage of this method requires the `mac` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use uuid::NodeId;
+    /// let node_id = NodeId::from_interface("eth0")?;
+    ///
+    /// assert!(node_id.is_unicast());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[cfg(feature = "mac")]
+    pub fn from_interface(name: &str) -> Result<Self, Error> {
+        match mac_address::mac_address_by_name(name) {
+            Ok(Some(mac)) if mac.bytes() != [0; 6] => Ok(NodeId(mac.bytes())),
+            _ => Err(Error(ErrorRepr::NetworkInterface)),
+        }
+    }
+
+    /// Read the node ID from the hardware address of the first network
+    /// interface that has one.
+    ///
+    /// Loopback interfaces are skipped. `None` is returned if no interface
+    /// has a hardware address, like in some containers and sandboxes; see
+    /// [`NodeId::first_available_or_random`] for a fallback.
+    ///
+    /// Note that usage of this method requires the `mac` feature of this
+    /// crate to be enabled.
+    #[cfg(feature = "mac")]
+    pub fn first_available() -> Option<Self> {
+        match mac_address::get_mac_address() {
+            Ok(Some(mac)) if mac.bytes() != [0; 6] => Some(NodeId(mac.bytes())),
+            _ => None,
+        }
+    }
+
+    /// Read the node ID from the hardware address of the first network
+    /// interface that has one, or generate a random one if none do.
+    ///
+    /// Random node IDs have their multicast bit set, as RFC4122 suggests, so
+    /// they can't conflict with the node ID of another machine that does have
+    /// a hardware address. Use [`NodeId::is_multicast`] to tell whether the
+    /// fallback was taken. A new random node ID is generated on each call, so
+    /// callers should keep the one they get.
+    ///
+    /// Note that usage of this method requires the `mac` and `rng` features of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Context, NodeId, Timestamp};
+    /// let node_id = NodeId::first_available_or_random();
+    ///
+    /// let (ticks, counter) = Timestamp::now(Context::new_random()).to_rfc4122();
+    /// let uuid = Builder::from_rfc4122_timestamp(ticks, counter, node_id.as_bytes()).into_uuid();
+    ///
+    /// assert_eq!(node_id.as_bytes(), &uuid.as_bytes()[10..]);
+    /// ```
+    #[cfg(all(feature = "mac", feature = "rng"))]
+    pub fn first_available_or_random() -> Self {
+        Self::first_available().unwrap_or_else(Self::random)
+    }
+
+    /// Get the bytes of the node ID.
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Consume the node ID and get its bytes.
+    pub const fn into_bytes(self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Whether the multicast bit of the node ID is set.
+    ///
+    /// Random and machine-derived node IDs are multicast.
+    pub const fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Whether the multicast bit of the node ID is unset.
+    ///
+    /// Node IDs derived from a real MAC address are unicast.
+    pub const fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+}
+
+impl From<[u8; 6]> for NodeId {
+    fn from(bytes: [u8; 6]) -> Self {
+        NodeId(bytes)
+    }
+}
+
+impl<'a> From<&'a [u8; 6]> for NodeId {
+    fn from(bytes: &'a [u8; 6]) -> Self {
+        NodeId(*bytes)
+    }
+}
+
+impl<'a> From<&'a NodeId> for NodeId {
+    fn from(node_id: &'a NodeId) -> Self {
+        *node_id
+    }
+}
+
+impl From<NodeId> for [u8; 6] {
+    fn from(node_id: NodeId) -> Self {
+        node_id.0
+    }
+}
+
+impl AsRef<[u8]> for NodeId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// Hash a stable identifier for the machine
+//
+// FNV-1a, followed by a finalizer to spread the bits
+pub(crate) const fn hash_machine_id(machine_id: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+
+    let mut i = 0;
+    while i < machine_id.len() {
+        hash ^= machine_id[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+
+    hash
+}
+
+#[cfg(not(uuid_core_only))]
+impl crate::std::fmt::Display for NodeId {
+    fn fmt(&self, f: &mut crate::std::fmt::Formatter<'_>) -> crate::std::fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            a, b, c, d, e, g
+        )
+    }
+}
+
+#[cfg(not(uuid_core_only))]
+impl crate::std::str::FromStr for NodeId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 17 {
+            return Err(Error(ErrorRepr::NodeIdLength { len: s.len() }));
+        }
+
+        let mut node_id = [0; 6];
+
+        for (index, c) in s.char_indices() {
+            let valid = if index % 3 == 2 {
+                c == ':'
+            } else {
+                match c.to_digit(16) {
+                    Some(digit) => {
+                        node_id[index / 3] = (node_id[index / 3] << 4) | digit as u8;
+                        true
+                    }
+                    None => false,
+                }
+            };
+
+            if !valid {
+                return Err(Error(ErrorRepr::NodeIdChar {
+                    character: c,
+                    index,
+                }));
+            }
+        }
+
+        Ok(NodeId(node_id))
+    }
+}
+
+#[cfg(not(uuid_core_only))]
+impl TryFrom<&'_ str> for NodeId {
+    type Error = Error;
+
+    fn try_from(s: &'_ str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// A place to persist a node ID between sessions.
+///
+/// Note that usage of this trait requires the `rng` feature of this crate
+/// to be enabled.
+#[cfg(feature = "rng")]
+pub trait NodeIdStorage {
+    /// Load a previously stored node ID, if there is one.
+    fn load(&self) -> Option<[u8; 6]>;
+
+    /// Store a node ID so it can be loaded in future sessions.
+    fn store(&self, node_id: &[u8; 6]);
+}
+
+#[cfg(feature = "rng")]
+impl<T: NodeIdStorage + ?Sized> NodeIdStorage for &T {
+    fn load(&self) -> Option<[u8; 6]> {
+        (**self).load()
+    }
+
+    fn store(&self, node_id: &[u8; 6]) {
+        (**self).store(node_id)
+    }
+}
+
+/// Generate a random node ID.
+///
+/// The multicast bit of the node ID is set, so it can't conflict with
+/// a node ID derived from a real MAC address.
+#[cfg(feature = "rng")]
+pub fn random_node_id() -> [u8; 6] {
+    let mut node_id = rng::node_id();
+    node_id[0] |= 0x01;
+
+    node_id
+}
+
+/// Load a node ID from `storage`, or generate and store a random one.
+///
+/// The first call for a given storage generates a node ID using
+/// [`random_node_id`]. Later calls return the same node ID.
+///
+/// # Examples
+///
+/// ```
+/// # use std::cell::Cell;
+/// # use uuid::node::{persistent_node_id, NodeIdStorage};
+/// #[derive(Default)]
+/// struct Storage(Cell<Option<[u8; 6]>>);
+///
+/// impl NodeIdStorage for Storage {
+///     fn load(&self) -> Option<[u8; 6]> {
+///         self.0.get()
+///     }
+///
+///     fn store(&self, node_id: &[u8; 6]) {
+///         self.0.set(Some(*node_id));
+///     }
+/// }
+///
+/// let storage = Storage::default();
+///
+/// assert_eq!(persistent_node_id(&storage), persistent_node_id(&storage));
+/// ```
+#[cfg(feature = "rng")]
+pub fn persistent_node_id(storage: impl NodeIdStorage) -> [u8; 6] {
+    if let Some(node_id) = storage.load() {
+        return node_id;
+    }
+
+    let node_id = random_node_id();
+    storage.store(&node_id);
+
+    node_id
+}
+
+/// Persists a node ID in the browser's `localStorage`.
+///
+/// The node ID is stored as a hex string under the key
+/// [`LocalStorage::KEY`]. If `localStorage` isn't available then the
+/// node ID won't be persisted.
+///
+/// Note that you need to enable the `js` Cargo feature in order to use
+/// this type.
+#[cfg(all(
+    feature = "std",
+    feature = "js",
+    feature = "rng",
+    all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    )
+))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalStorage;
+
+#[cfg(all(
+    feature = "std",
+    feature = "js",
+    feature = "rng",
+    all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    )
+))]
+mod local_storage {
+    use super::{LocalStorage, NodeIdStorage};
+    use crate::std::string::String;
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = localStorage, js_name = getItem, catch)]
+        fn get_item(key: &str) -> Result<Option<String>, JsValue>;
+
+        #[wasm_bindgen(js_namespace = localStorage, js_name = setItem, catch)]
+        fn set_item(key: &str, value: &str) -> Result<(), JsValue>;
+    }
+
+    impl LocalStorage {
+        /// The key the node ID is stored under.
+        pub const KEY: &'static str = "uuid-node-id";
+    }
+
+    impl NodeIdStorage for LocalStorage {
+        fn load(&self) -> Option<[u8; 6]> {
+            let value = get_item(Self::KEY).ok()??;
+            let value = value.as_bytes();
+
+            if value.len() != 12 {
+                return None;
+            }
+
+            let mut node_id = [0; 6];
+            for (i, b) in node_id.iter_mut().enumerate() {
+                let s = crate::std::str::from_utf8(&value[i * 2..i * 2 + 2]).ok()?;
+                *b = u8::from_str_radix(s, 16).ok()?;
+            }
+
+            Some(node_id)
+        }
+
+        fn store(&self, node_id: &[u8; 6]) {
+            use crate::std::fmt::Write;
+
+            let mut value = String::with_capacity(12);
+            for b in node_id {
+                let _ = write!(value, "{:02x}", b);
+            }
+
+            // If storage is unavailable or full then the node ID
+            // just won't outlive this session
+            let _ = set_item(Self::KEY, &value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::ToString;
+
+    #[cfg(feature = "rng")]
+    use crate::std::cell::Cell;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[cfg(feature = "rng")]
+    #[derive(Default)]
+    struct TestStorage(Cell<Option<[u8; 6]>>);
+
+    #[cfg(feature = "rng")]
+    impl NodeIdStorage for TestStorage {
+        fn load(&self) -> Option<[u8; 6]> {
+            self.0.get()
+        }
+
+        fn store(&self, node_id: &[u8; 6]) {
+            self.0.set(Some(*node_id));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rng")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_random_node_id_is_multicast() {
+        assert_eq!(random_node_id()[0] & 0x01, 0x01);
+    }
+
+    #[test]
+    #[cfg(feature = "rng")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_process_random() {
+        let node_id = NodeId::process_random();
+
+        assert!(node_id.is_multicast());
+        assert_eq!(node_id, NodeId::process_random());
+    }
+
+    #[test]
+    #[cfg(feature = "rng")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_persistent_node_id() {
+        let storage = TestStorage::default();
+
+        let node_id = persistent_node_id(&storage);
+
+        assert_eq!(Some(node_id), storage.load());
+        assert_eq!(node_id, persistent_node_id(&storage));
+    }
+
+    #[test]
+    #[cfg(feature = "rng")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_persistent_node_id_existing() {
+        let storage = TestStorage(Cell::new(Some([1, 2, 3, 4, 5, 6])));
+
+        assert_eq!([1, 2, 3, 4, 5, 6], persistent_node_id(&storage));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_node_id_multicast() {
+        assert!(NodeId::from_bytes([0x01, 0, 0, 0, 0, 0]).is_multicast());
+        assert!(NodeId::from_bytes([0xfe, 0, 0, 0, 0, 0]).is_unicast());
+        assert!(NodeId::from_machine_id(&[]).is_multicast());
+    }
+
+    #[test]
+    #[cfg(feature = "mac")]
+    fn test_node_id_from_interface() {
+        assert!(NodeId::from_interface("uuid-test-missing0").is_err());
+
+        if let Some(node_id) = NodeId::first_available() {
+            assert_ne!([0; 6], node_id.into_bytes());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "mac", feature = "rng"))]
+    fn test_node_id_first_available_or_random() {
+        let node_id = NodeId::first_available_or_random();
+
+        match NodeId::first_available() {
+            Some(first) => assert_eq!(first, node_id),
+            None => assert!(node_id.is_multicast()),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_node_id_from_machine_id() {
+        let a = NodeId::from_machine_id(b"b08dfa6083e7567a1921a715000001fb");
+        let b = NodeId::from_machine_id(b"b08dfa6083e7567a1921a715000001fc");
+
+        assert_eq!(
+            a,
+            NodeId::from_machine_id(b"b08dfa6083e7567a1921a715000001fb")
+        );
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_node_id_from_slice() {
+        assert_eq!(
+            NodeId::from_bytes([1, 2, 3, 4, 5, 6]),
+            NodeId::from_slice(&[1, 2, 3, 4, 5, 6]).unwrap()
+        );
+        assert_eq!(
+            Err(Error(ErrorRepr::NodeLength { len: 5 })),
+            NodeId::from_slice(&[1, 2, 3, 4, 5])
+        );
+    }
+
+    #[test]
+    #[cfg(not(uuid_core_only))]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_node_id_string() {
+        let node_id = NodeId::from_bytes([0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+
+        assert_eq!("01:23:45:67:89:ab", node_id.to_string());
+        assert_eq!(Ok(node_id), "01:23:45:67:89:ab".parse());
+        assert_eq!(Ok(node_id), "01:23:45:67:89:AB".parse());
+
+        assert_eq!(
+            Err(Error(ErrorRepr::NodeIdLength { len: 12 })),
+            "0123456789ab".parse::<NodeId>()
+        );
+        assert_eq!(
+            Err(Error(ErrorRepr::NodeIdChar {
+                character: '-',
+                index: 2
+            })),
+            "01-23-45-67-89-ab".parse::<NodeId>()
+        );
+        assert_eq!(
+            Err(Error(ErrorRepr::NodeIdChar {
+                character: 'g',
+                index: 16
+            })),
+            "01:23:45:67:89:ag".parse::<NodeId>()
+        );
+    }
+}
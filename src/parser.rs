@@ -16,7 +16,7 @@
 use crate::{
     error::*,
     std::{convert::TryFrom, str},
-    Uuid,
+    Uuid, Version,
 };
 
 impl str::FromStr for Uuid {
@@ -35,6 +35,40 @@ impl TryFrom<&'_ str> for Uuid {
     }
 }
 
+impl str::FromStr for Version {
+    type Err = Error;
+
+    fn from_str(version_str: &str) -> Result<Self, Self::Err> {
+        // Names are matched case-insensitively
+        let mut name = [0; 8];
+        let name = match name.get_mut(..version_str.len()) {
+            Some(name) => {
+                name.copy_from_slice(version_str.as_bytes());
+                name.make_ascii_lowercase();
+                &*name
+            }
+            None => return Err(Error(ErrorRepr::VersionName)),
+        };
+
+        match name {
+            b"nil" => Ok(Version::Nil),
+            b"mac" | b"1" | b"v1" => Ok(Version::Mac),
+            b"dce" | b"2" | b"v2" => Ok(Version::Dce),
+            b"md5" | b"3" | b"v3" => Ok(Version::Md5),
+            b"random" | b"4" | b"v4" => Ok(Version::Random),
+            b"sha1" | b"5" | b"v5" => Ok(Version::Sha1),
+            #[cfg(uuid_unstable)]
+            b"sortmac" | b"6" | b"v6" => Ok(Version::SortMac),
+            #[cfg(uuid_unstable)]
+            b"sortrand" | b"7" | b"v7" => Ok(Version::SortRand),
+            #[cfg(uuid_unstable)]
+            b"custom" | b"8" | b"v8" => Ok(Version::Custom),
+            b"max" => Ok(Version::Max),
+            _ => Err(Error(ErrorRepr::VersionName)),
+        }
+    }
+}
+
 impl Uuid {
     /// Parses a `Uuid` from a string of hexadecimal digits with optional
     /// hyphens.
@@ -67,6 +101,30 @@ impl Uuid {
             .map_err(InvalidUuid::into_err)
     }
 
+    /// Parses a `Uuid` from a string of hexadecimal digits with optional
+    /// hyphens, rejecting UUIDs that RFC 9562 doesn't describe.
+    ///
+    /// This is [`parse_str`] followed by [`check_strict`], so legacy
+    /// variants, unassigned versions, and version 2 UUIDs when the `v2`
+    /// feature isn't enabled are all errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert!(Uuid::parse_str_strict("550e8400-e29b-41d4-a716-446655440000").is_ok());
+    /// assert!(Uuid::parse_str_strict("550e8400-e29b-91d4-a716-446655440000").is_err());
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`check_strict`]: #method.check_strict
+    pub fn parse_str_strict(input: &str) -> Result<Uuid, Error> {
+        let uuid = Uuid::parse_str(input)?;
+        uuid.check_strict()?;
+
+        Ok(uuid)
+    }
+
     /// Parses a `Uuid` from a string of hexadecimal digits with optional
     /// hyphens.
     ///
@@ -127,24 +185,195 @@ impl Uuid {
             Ok(bytes) => Ok(Uuid::from_bytes(bytes)),
             // If parsing fails then we don't know exactly what went wrong
             // In this case, we just return a generic error
-            Err(_) => Err(Error(ErrorKind::Other)),
+            Err(_) => Err(Error(ErrorRepr::Other)),
         }
     }
+
+    /// Parses a `Uuid` from a NUL-terminated C string of hexadecimal digits
+    /// with optional hyphens.
+    ///
+    /// This method is the same as [`try_parse_ascii`], but accepts a C string
+    /// directly so UUIDs passed across FFI boundaries don't need to be
+    /// converted first. The NUL terminator isn't considered part of the input.
+    ///
+    /// # Examples
+    ///
+    /// Parse a hyphenated UUID:
+    ///
+    /// ```
+    /// # use std::ffi::CStr;
+    /// # use uuid::{Uuid, Version, Variant};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let input = CStr::from_bytes_with_nul(b"550e8400-e29b-41d4-a716-446655440000\0").unwrap();
+    /// let uuid = Uuid::try_parse_cstr(input)?;
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert_eq!(Variant::RFC4122, uuid.get_variant());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`try_parse_ascii`]: #method.try_parse_ascii
+    #[cfg(feature = "std")]
+    pub fn try_parse_cstr(input: &std::ffi::CStr) -> Result<Uuid, Error> {
+        Self::try_parse_ascii(input.to_bytes())
+    }
+
+    /// Parses a `Uuid` from a simple or hyphenated string without
+    /// validating it.
+    ///
+    /// This method is for inputs that are already known to be valid, such
+    /// as values written by this library and read back from storage you
+    /// control. In debug builds the input is still validated, and this
+    /// method will panic if it's invalid.
+    ///
+    /// Prefer [`parse_trusted`] unless you've measured the length check it
+    /// performs to be a bottleneck.
+    ///
+    /// # Safety
+    ///
+    /// `input` must be a valid UUID in either the [`Simple`] or
+    /// [`Hyphenated`] format, with hex digits in either case. With the
+    /// `tiny` feature, it must be in the [`Hyphenated`] format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// // SAFETY: The input is a valid hyphenated UUID
+    /// let uuid = unsafe { Uuid::parse_unchecked("550e8400-e29b-41d4-a716-446655440000") };
+    ///
+    /// assert_eq!(uuid.as_u128(), 0x550e8400e29b41d4a716446655440000);
+    /// ```
+    ///
+    /// [`parse_trusted`]: #method.parse_trusted
+    /// [`Simple`]: fmt/struct.Simple.html
+    /// [`Hyphenated`]: fmt/struct.Hyphenated.html
+    pub unsafe fn parse_unchecked(input: &str) -> Uuid {
+        debug_assert!(
+            matches!(input.len(), 32 | 36) && Uuid::try_parse(input).is_ok(),
+            "attempted to parse an invalid UUID without checking it: {:?}",
+            input
+        );
+
+        let positions = if input.len() == 36 {
+            &HYPHENATED_POSITIONS
+        } else {
+            &SIMPLE_POSITIONS
+        };
+
+        let s = input.as_bytes();
+        let mut bytes = [0; 16];
+
+        for (b, i) in bytes.iter_mut().zip(positions.iter()) {
+            *b = (unchecked_nibble(*s.get_unchecked(*i)) << 4)
+                | unchecked_nibble(*s.get_unchecked(*i + 1));
+        }
+
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Parses a `Uuid` from a simple or hyphenated string, only checking
+    /// that its length is correct.
+    ///
+    /// This is a safe alternative to [`parse_unchecked`] for inputs that
+    /// are already known to be valid. In debug builds the input is fully
+    /// validated, and this method will panic if it's invalid. In release
+    /// builds an input with invalid characters produces an unspecified UUID.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `input` isn't 32 or 36 characters long.
+    /// With the `tiny` feature, only the hyphenated format is accepted, so
+    /// it will panic if `input` isn't 36 characters long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_trusted("550e8400-e29b-41d4-a716-446655440000");
+    ///
+    /// assert_eq!(uuid.as_u128(), 0x550e8400e29b41d4a716446655440000);
+    /// ```
+    ///
+    /// [`parse_unchecked`]: #method.parse_unchecked
+    pub fn parse_trusted(input: &str) -> Uuid {
+        #[cfg(not(feature = "tiny"))]
+        let valid_len = matches!(input.len(), 32 | 36);
+        #[cfg(feature = "tiny")]
+        let valid_len = input.len() == 36;
+
+        assert!(
+            valid_len,
+            "attempted to parse a UUID with an invalid length: {}",
+            input.len()
+        );
+
+        debug_assert!(
+            Uuid::try_parse(input).is_ok(),
+            "attempted to parse an invalid UUID without checking it: {:?}",
+            input
+        );
+
+        Uuid::from_bytes(decode_trusted(input.as_bytes()))
+    }
+}
+
+// The position in the input of the two hex digits for each output byte
+const SIMPLE_POSITIONS: [usize; 16] = [0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30];
+const HYPHENATED_POSITIONS: [usize; 16] =
+    [0, 2, 4, 6, 9, 11, 14, 16, 19, 21, 24, 26, 28, 30, 32, 34];
+
+// Decodes `[0-9a-fA-F]` without a lookup table or any branches
+// Other characters produce unspecified values
+#[inline]
+const fn unchecked_nibble(c: u8) -> u8 {
+    (c & 0x0f).wrapping_add(9 * (c >> 6))
+}
+
+// Decode a simple or hyphenated UUID without validating its digits. Unlike
+// `parse_unchecked`, indexing is checked, so a short input panics instead of
+// reading out of bounds
+fn decode_trusted(s: &[u8]) -> [u8; 16] {
+    let positions = if s.len() == 36 {
+        &HYPHENATED_POSITIONS
+    } else {
+        &SIMPLE_POSITIONS
+    };
+
+    let mut bytes = [0; 16];
+
+    for (b, i) in bytes.iter_mut().zip(positions.iter()) {
+        *b = (unchecked_nibble(s[*i]) << 4) | unchecked_nibble(s[*i + 1]);
+    }
+
+    bytes
 }
 
 const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
+    // Most UUIDs are plain hyphenated strings, so try those first
+    #[cfg(not(feature = "tiny"))]
+    if let Ok(b) = parse_hyphenated_fast(input) {
+        return Ok(b);
+    }
+
     let result = match (input.len(), input) {
         // Inputs of 32 bytes must be a non-hyphenated UUID
+        #[cfg(not(feature = "tiny"))]
         (32, s) => parse_simple(s),
         // Hyphenated UUIDs may be wrapped in various ways:
         // - `{UUID}` for braced UUIDs
         // - `urn:uuid:UUID` for URNs
         // - `UUID` for a regular hyphenated UUID
+        #[cfg(not(feature = "tiny"))]
         (36, s)
         | (38, [b'{', s @ .., b'}'])
         | (45, [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..]) => {
             parse_hyphenated(s)
         }
+        // With the `tiny` feature, only regular hyphenated UUIDs are parsed
+        #[cfg(feature = "tiny")]
+        (36, s) => parse_hyphenated(s),
         // Any other shaped input is immediately invalid
         _ => Err(()),
     };
@@ -155,6 +384,7 @@ const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
     }
 }
 
+#[cfg(not(feature = "tiny"))]
 #[inline]
 const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
     // This length check here removes all other bounds
@@ -187,6 +417,7 @@ const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
     Ok(buf)
 }
 
+#[cfg(not(feature = "tiny"))]
 #[inline]
 const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
     // This length check here removes all other bounds
@@ -236,6 +467,78 @@ const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
     Ok(buf)
 }
 
+#[cfg(not(feature = "tiny"))]
+#[inline]
+const fn parse_hyphenated_fast(s: &[u8]) -> Result<[u8; 16], ()> {
+    if s.len() != 36 {
+        return Err(());
+    }
+
+    // Instead of checking each character as we go, we accumulate any
+    // invalid bits here and check them once at the end. Misplaced hyphens
+    // leave their differing bits, and invalid hex digits contribute the
+    // upper nibble of their `0xff` sentinel
+    let mut invalid = (s[8] ^ b'-') | (s[13] ^ b'-') | (s[18] ^ b'-') | (s[23] ^ b'-');
+
+    let mut buf: [u8; 16] = [0; 16];
+    let mut i = 0;
+
+    while i < 16 {
+        let h1 = HEX_TABLE[s[HYPHENATED_POSITIONS[i]] as usize];
+        let h2 = HEX_TABLE[s[HYPHENATED_POSITIONS[i] + 1] as usize];
+
+        invalid |= (h1 | h2) >> 4;
+
+        buf[i] = SHL4_TABLE[h1 as usize] | h2;
+        i += 1;
+    }
+
+    if invalid != 0 {
+        return Err(());
+    }
+
+    Ok(buf)
+}
+
+// With the `tiny` feature, only the hyphenated format is parsed, one
+// character at a time without any lookup tables. This is slower, but smaller.
+#[cfg(feature = "tiny")]
+const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
+    if s.len() != 36 {
+        return Err(());
+    }
+
+    let mut buf: [u8; 16] = [0; 16];
+    let mut i = 0;
+    let mut digit = 0;
+
+    while digit < 32 {
+        if matches!(i, 8 | 13 | 18 | 23) {
+            if s[i] != b'-' {
+                return Err(());
+            }
+
+            i += 1;
+            continue;
+        }
+
+        let h = match s[i] {
+            b'0'..=b'9' => s[i] - b'0',
+            b'a'..=b'f' => s[i] - b'a' + 10,
+            b'A'..=b'F' => s[i] - b'A' + 10,
+            _ => return Err(()),
+        };
+
+        buf[digit / 2] |= if digit % 2 == 0 { h << 4 } else { h };
+
+        i += 1;
+        digit += 1;
+    }
+
+    Ok(buf)
+}
+
+#[cfg(not(feature = "tiny"))]
 const HEX_TABLE: &[u8; 256] = &{
     let mut buf = [0; 256];
     let mut i: u8 = 0;
@@ -256,6 +559,7 @@ const HEX_TABLE: &[u8; 256] = &{
     }
 };
 
+#[cfg(not(feature = "tiny"))]
 const SHL4_TABLE: &[u8; 256] = &{
     let mut buf = [0; 256];
     let mut i: u8 = 0;
@@ -274,9 +578,13 @@ const SHL4_TABLE: &[u8; 256] = &{
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{std::string::ToString, tests::new};
+    use crate::{
+        std::string::ToString,
+        tests::{new, new2},
+    };
 
     #[test]
+    #[cfg(not(feature = "tiny"))]
     fn test_parse_uuid_v4_valid() {
         let from_hyphenated = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
         let from_simple = Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c8").unwrap();
@@ -312,12 +620,12 @@ mod tests {
         // Invalid
         assert_eq!(
             Uuid::parse_str(""),
-            Err(Error(ErrorKind::SimpleLength { len: 0 }))
+            Err(Error(ErrorRepr::SimpleLength { len: 0 }))
         );
 
         assert_eq!(
             Uuid::parse_str("!"),
-            Err(Error(ErrorKind::Char {
+            Err(Error(ErrorRepr::Char {
                 character: '!',
                 index: 1,
             }))
@@ -325,7 +633,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error(ErrorRepr::GroupLength {
                 group: 4,
                 len: 13,
                 index: 25,
@@ -334,7 +642,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-BBF-329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error(ErrorRepr::GroupLength {
                 group: 3,
                 len: 3,
                 index: 20,
@@ -343,7 +651,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-BGBF-329BF39FA1E4"),
-            Err(Error(ErrorKind::Char {
+            Err(Error(ErrorRepr::Char {
                 character: 'G',
                 index: 21,
             }))
@@ -351,27 +659,27 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2F4faaFB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 2 }))
+            Err(Error(ErrorRepr::GroupCount { count: 2 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faaFB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorRepr::GroupCount { count: 3 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 4 }))
+            Err(Error(ErrorRepr::GroupCount { count: 4 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorRepr::GroupCount { count: 3 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faaXB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::Char {
+            Err(Error(ErrorRepr::Char {
                 character: 'X',
                 index: 19,
             }))
@@ -379,7 +687,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{F9168C5E-CEB2-4faa9B6BFF329BF39FA1E41"),
-            Err(Error(ErrorKind::Char {
+            Err(Error(ErrorRepr::Char {
                 character: '{',
                 index: 1,
             }))
@@ -387,12 +695,12 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{F9168C5E-CEB2-4faa9B6BFF329BF39FA1E41}"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorRepr::GroupCount { count: 3 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB-24fa-eB6BFF32-BF39FA1E4"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error(ErrorRepr::GroupLength {
                 group: 1,
                 len: 3,
                 index: 10,
@@ -403,7 +711,7 @@ mod tests {
         // //
         assert_eq!(
             Uuid::parse_str("01020304-1112-2122-3132-41424344"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error(ErrorRepr::GroupLength {
                 group: 4,
                 len: 8,
                 index: 25,
@@ -412,17 +720,17 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::SimpleLength { len: 31 }))
+            Err(Error(ErrorRepr::SimpleLength { len: 31 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c88"),
-            Err(Error(ErrorKind::SimpleLength { len: 33 }))
+            Err(Error(ErrorRepr::SimpleLength { len: 33 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0cg8"),
-            Err(Error(ErrorKind::Char {
+            Err(Error(ErrorRepr::Char {
                 character: 'g',
                 index: 32,
             }))
@@ -430,7 +738,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426%9247bb680e5fe0c8"),
-            Err(Error(ErrorKind::Char {
+            Err(Error(ErrorRepr::Char {
                 character: '%',
                 index: 16,
             }))
@@ -438,22 +746,22 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("231231212212423424324323477343246663"),
-            Err(Error(ErrorKind::SimpleLength { len: 36 }))
+            Err(Error(ErrorRepr::SimpleLength { len: 36 }))
         );
 
         assert_eq!(
             Uuid::parse_str("{00000000000000000000000000000000}"),
-            Err(Error(ErrorKind::GroupCount { count: 1 }))
+            Err(Error(ErrorRepr::GroupCount { count: 1 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::SimpleLength { len: 31 }))
+            Err(Error(ErrorRepr::SimpleLength { len: 31 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e550X410b1426f9247bb680e5fe0cd"),
-            Err(Error(ErrorKind::Char {
+            Err(Error(ErrorRepr::Char {
                 character: 'X',
                 index: 7,
             }))
@@ -461,12 +769,12 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e550-4105b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::GroupCount { count: 2 }))
+            Err(Error(ErrorRepr::GroupCount { count: 2 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error(ErrorRepr::GroupLength {
                 group: 3,
                 len: 5,
                 index: 20,
@@ -491,6 +799,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "tiny"))]
     fn test_roundtrip_simple() {
         let uuid_orig = new();
         let orig_str = uuid_orig.simple().to_string();
@@ -499,6 +808,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "tiny"))]
     fn test_roundtrip_urn() {
         let uuid_orig = new();
         let orig_str = uuid_orig.urn().to_string();
@@ -507,6 +817,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "tiny"))]
     fn test_roundtrip_braced() {
         let uuid_orig = new();
         let orig_str = uuid_orig.braced().to_string();
@@ -514,8 +825,172 @@ mod tests {
         assert_eq!(uuid_orig, uuid_out);
     }
 
+    #[test]
+    #[cfg(feature = "tiny")]
+    fn test_parse_tiny() {
+        let uuid = new();
+
+        assert_eq!(
+            Uuid::parse_str(&uuid.hyphenated().to_string()).unwrap(),
+            uuid
+        );
+
+        // Only the hyphenated format is parsed
+        assert!(Uuid::parse_str(&uuid.simple().to_string()).is_err());
+        assert!(Uuid::parse_str(&uuid.braced().to_string()).is_err());
+        assert!(Uuid::parse_str(&uuid.urn().to_string()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_parse_cstr() {
+        let input =
+            std::ffi::CStr::from_bytes_with_nul(b"67e55044-10b1-426f-9247-bb680e5fe0c8\0").unwrap();
+
+        assert_eq!(
+            Uuid::try_parse_cstr(input).unwrap(),
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+        );
+
+        let mut buf = [0; 37];
+        let uuid = new();
+        assert_eq!(
+            Uuid::try_parse_cstr(uuid.hyphenated().encode_cstr(&mut buf)).unwrap(),
+            uuid
+        );
+
+        let input = std::ffi::CStr::from_bytes_with_nul(b"67e55044\0").unwrap();
+        assert!(Uuid::try_parse_cstr(input).is_err());
+    }
+
+    #[test]
+    fn test_parse_unchecked() {
+        for uuid in [new(), new2(), Uuid::nil(), Uuid::from_u128(u128::MAX)] {
+            let hyphenated = uuid.hyphenated().to_string();
+            #[cfg(not(feature = "tiny"))]
+            let simple = uuid.simple().to_string();
+            let upper = uuid
+                .hyphenated()
+                .encode_upper(&mut Uuid::encode_buffer())
+                .to_string();
+
+            // SAFETY: The inputs are all valid
+            unsafe {
+                assert_eq!(Uuid::parse_unchecked(&hyphenated), uuid);
+                #[cfg(not(feature = "tiny"))]
+                assert_eq!(Uuid::parse_unchecked(&simple), uuid);
+                assert_eq!(Uuid::parse_unchecked(&upper), uuid);
+            }
+
+            assert_eq!(Uuid::parse_trusted(&hyphenated), uuid);
+            #[cfg(not(feature = "tiny"))]
+            assert_eq!(Uuid::parse_trusted(&simple), uuid);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_trusted_invalid_length() {
+        Uuid::parse_trusted("550e8400-e29b-41d4-a716");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_parse_trusted_invalid_debug() {
+        Uuid::parse_trusted("550e8400-e29b-41d4-a716-44665544000g");
+    }
+
+    #[test]
+    #[cfg(not(feature = "tiny"))]
+    fn test_parse_hyphenated_fast() {
+        let valid = [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+            "00000000-0000-0000-0000-000000000000",
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+        ];
+
+        for s in valid {
+            assert_eq!(
+                parse_hyphenated_fast(s.as_bytes()),
+                parse_hyphenated(s.as_bytes())
+            );
+            assert!(parse_hyphenated_fast(s.as_bytes()).is_ok());
+        }
+
+        let invalid = [
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8a",
+            "67e5504410b1426f9247bb680e5fe0c8____",
+            "67e55044+10b1-426f-9247-bb680e5fe0c8",
+            "67e55044-10b1-426f-9247_bb680e5fe0c8",
+            "67e5504-410b1-426f-9247-bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0cg",
+            "g7e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e55044-10b1-426f-92\u{ff}-bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0\0\0",
+        ];
+
+        for s in invalid {
+            assert!(parse_hyphenated_fast(s.as_bytes()).is_err(), "{}", s);
+            assert_eq!(
+                parse_hyphenated_fast(s.as_bytes()),
+                parse_hyphenated(s.as_bytes())
+            );
+        }
+    }
+
     #[test]
     fn test_try_parse_ascii_non_utf8() {
         assert!(Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5\0e0c8").is_err());
     }
+
+    #[test]
+    fn test_error_kind() {
+        let kind = |s: &str| Uuid::parse_str(s).unwrap_err().kind();
+
+        assert_eq!(kind(""), ErrorKind::InvalidLength);
+        assert_eq!(kind("!"), ErrorKind::InvalidCharacter { index: 1 });
+        assert_eq!(
+            kind("F9168C5E-CEB2-4faa-BBF-329BF39FA1E4"),
+            ErrorKind::InvalidGroupLength { group: 3 }
+        );
+        assert_eq!(
+            kind("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4-"),
+            ErrorKind::InvalidGroupCount
+        );
+        assert_eq!(
+            Uuid::try_parse_ascii(b"\xff").unwrap_err().kind(),
+            ErrorKind::Other
+        );
+        assert_eq!(
+            Uuid::from_slice(&[0; 3]).unwrap_err().kind(),
+            ErrorKind::InvalidLength
+        );
+        assert_eq!(
+            Uuid::parse_str_strict("F9168C5E-CEB2-4faa-D6BF-329BF39FA1E4")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnsupportedVariant
+        );
+        assert_eq!(
+            Uuid::parse_str_strict("F9168C5E-CEB2-9faa-B6BF-329BF39FA1E4")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnsupportedVersion
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_into_io_error() {
+        let err = std::io::Error::from(Uuid::parse_str("!").unwrap_err());
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            Uuid::parse_str("!").unwrap_err().to_string()
+        );
+    }
 }
@@ -1,6 +1,18 @@
-#[cfg(any(feature = "v4", feature = "v7"))]
 pub(crate) fn bytes() -> [u8; 16] {
-    #[cfg(not(feature = "fast-rng"))]
+    #[cfg(all(feature = "uefi", target_os = "uefi"))]
+    {
+        let mut bytes = [0u8; 16];
+
+        uefi::fill(&mut bytes);
+
+        bytes
+    }
+
+    #[cfg(all(
+        not(feature = "fast-rng"),
+        not(feature = "buffered-rng"),
+        not(all(feature = "uefi", target_os = "uefi"))
+    ))]
     {
         let mut bytes = [0u8; 16];
 
@@ -12,15 +24,66 @@ pub(crate) fn bytes() -> [u8; 16] {
         bytes
     }
 
-    #[cfg(feature = "fast-rng")]
+    #[cfg(all(
+        feature = "buffered-rng",
+        not(feature = "fast-rng"),
+        not(all(feature = "uefi", target_os = "uefi"))
+    ))]
+    {
+        let mut bytes = [0u8; 16];
+
+        buffered::fill(&mut bytes);
+
+        bytes
+    }
+
+    #[cfg(all(feature = "fast-rng", not(all(feature = "uefi", target_os = "uefi"))))]
     {
         rand::random()
     }
 }
 
+// Fill a large buffer with random bytes in as few calls as possible
+#[cfg(feature = "v4")]
+pub(crate) fn fill(bytes: &mut [u8]) {
+    #[cfg(all(feature = "uefi", target_os = "uefi"))]
+    {
+        uefi::fill(bytes);
+    }
+
+    // The buffered pool is skipped, since the buffer is usually bigger than it
+    #[cfg(all(
+        not(feature = "fast-rng"),
+        not(all(feature = "uefi", target_os = "uefi"))
+    ))]
+    {
+        getrandom::getrandom(bytes).unwrap_or_else(|err| {
+            // NB: getrandom::Error has no source; this is adequate display
+            panic!("could not retrieve random bytes for uuid: {}", err)
+        });
+    }
+
+    #[cfg(all(feature = "fast-rng", not(all(feature = "uefi", target_os = "uefi"))))]
+    {
+        rand::RngCore::fill_bytes(&mut rand::thread_rng(), bytes);
+    }
+}
+
 #[cfg(any(feature = "v1", feature = "v6"))]
 pub(crate) fn u16() -> u16 {
-    #[cfg(not(feature = "fast-rng"))]
+    #[cfg(all(feature = "uefi", target_os = "uefi"))]
+    {
+        let mut bytes = [0u8; 2];
+
+        uefi::fill(&mut bytes);
+
+        ((bytes[0] as u16) << 8) | (bytes[1] as u16)
+    }
+
+    #[cfg(all(
+        not(feature = "fast-rng"),
+        not(all(feature = "uefi", target_os = "uefi"))
+    ))]
     {
         let mut bytes = [0u8; 2];
 
@@ -32,8 +95,142 @@ pub(crate) fn u16() -> u16 {
         ((bytes[0] as u16) << 8) | (bytes[1] as u16)
     }
 
-    #[cfg(feature = "fast-rng")]
+    #[cfg(all(feature = "fast-rng", not(all(feature = "uefi", target_os = "uefi"))))]
+    {
+        rand::random()
+    }
+}
+
+#[cfg(any(feature = "v1", feature = "v6"))]
+pub(crate) fn node_id() -> [u8; 6] {
+    #[cfg(all(feature = "uefi", target_os = "uefi"))]
+    {
+        let mut bytes = [0u8; 6];
+
+        uefi::fill(&mut bytes);
+
+        bytes
+    }
+
+    #[cfg(all(
+        not(feature = "fast-rng"),
+        not(all(feature = "uefi", target_os = "uefi"))
+    ))]
+    {
+        let mut bytes = [0u8; 6];
+
+        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
+            // NB: getrandom::Error has no source; this is adequate display
+            panic!("could not retrieve random bytes for uuid: {}", err)
+        });
+
+        bytes
+    }
+
+    #[cfg(all(feature = "fast-rng", not(all(feature = "uefi", target_os = "uefi"))))]
     {
         rand::random()
     }
 }
+
+#[cfg(all(feature = "uefi", target_os = "uefi"))]
+mod uefi {
+    use uefi_rs::{boot, proto::rng::Rng};
+
+    /// Fill a buffer using the firmware's `EFI_RNG_PROTOCOL`.
+    ///
+    /// The protocol is opened for each call rather than cached, since
+    /// boot services may not outlive the caller.
+    pub(super) fn fill(bytes: &mut [u8]) {
+        let mut rng = boot::get_handle_for_protocol::<Rng>()
+            .and_then(boot::open_protocol_exclusive::<Rng>)
+            .unwrap_or_else(|err| panic!("could not open the UEFI RNG protocol for uuid: {}", err));
+
+        rng.get_rng(None, bytes)
+            .unwrap_or_else(|err| panic!("could not retrieve random bytes for uuid: {}", err));
+    }
+}
+
+#[cfg(all(
+    feature = "buffered-rng",
+    not(feature = "fast-rng"),
+    not(all(feature = "uefi", target_os = "uefi"))
+))]
+mod buffered {
+    use std::cell::RefCell;
+
+    const CAPACITY: usize = 4096;
+
+    struct Pool {
+        buf: [u8; CAPACITY],
+        pos: usize,
+    }
+
+    thread_local! {
+        // `const` initializers need Rust 1.59, newer than our MSRV
+        #[allow(unknown_lints, clippy::missing_const_for_thread_local)]
+        static POOL: RefCell<Pool> = RefCell::new(Pool {
+            buf: [0; CAPACITY],
+            pos: CAPACITY,
+        });
+    }
+
+    /// Fill a buffer from the current thread's pool of random bytes.
+    ///
+    /// The pool is refilled from `getrandom` when it doesn't have enough
+    /// bytes left. Bytes are zeroed in the pool once they've been handed out.
+    pub(super) fn fill(bytes: &mut [u8]) {
+        debug_assert!(bytes.len() <= CAPACITY);
+
+        let buffered = POOL.try_with(|pool| {
+            let mut pool = pool.borrow_mut();
+
+            if CAPACITY - pool.pos < bytes.len() {
+                getrandom(&mut pool.buf);
+                pool.pos = 0;
+            }
+
+            let range = pool.pos..pool.pos + bytes.len();
+            bytes.copy_from_slice(&pool.buf[range.clone()]);
+            pool.buf[range].fill(0);
+            pool.pos += bytes.len();
+        });
+
+        // The pool isn't available while thread-locals are being destroyed
+        if buffered.is_err() {
+            getrandom(bytes);
+        }
+    }
+
+    fn getrandom(bytes: &mut [u8]) {
+        getrandom::getrandom(bytes).unwrap_or_else(|err| {
+            // NB: getrandom::Error has no source; this is adequate display
+            panic!("could not retrieve random bytes for uuid: {}", err)
+        });
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_fill_across_refill() {
+            let mut seen = std::vec::Vec::new();
+
+            // Enough draws to need more than one refill
+            for _ in 0..(CAPACITY / 16) * 2 + 1 {
+                let mut bytes = [0; 16];
+                fill(&mut bytes);
+
+                assert!(!seen.contains(&bytes));
+                seen.push(bytes);
+            }
+
+            POOL.with(|pool| {
+                let pool = pool.borrow();
+
+                assert!(pool.buf[..pool.pos].iter().all(|b| *b == 0));
+            });
+        }
+    }
+}
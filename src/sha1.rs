@@ -1,14 +1,77 @@
 #[cfg(feature = "v5")]
 pub(crate) fn hash(ns: &[u8], src: &[u8]) -> [u8; 16] {
+    let mut hasher = Hasher::new(ns);
+
+    hasher.update(src);
+
+    hasher.finish()
+}
+
+#[cfg(feature = "v5")]
+#[derive(Clone)]
+pub(crate) struct Hasher(sha1_smol::Sha1);
+
+#[cfg(feature = "v5")]
+impl Hasher {
+    pub(crate) fn new(ns: &[u8]) -> Self {
+        let mut hasher = sha1_smol::Sha1::new();
+
+        hasher.update(ns);
+
+        Hasher(hasher)
+    }
+
+    pub(crate) fn update(&mut self, src: &[u8]) {
+        self.0.update(src);
+    }
+
+    pub(crate) fn finish(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&self.0.digest().bytes()[..16]);
+
+        bytes
+    }
+}
+
+// HMAC-SHA1 from RFC 2104, keyed by `key`, over the namespace and name
+#[cfg(all(uuid_unstable, feature = "v8"))]
+pub(crate) fn hmac(key: &[u8], ns: &[u8], src: &[u8]) -> [u8; 16] {
     use sha1_smol::Sha1;
 
-    let mut hasher = Sha1::new();
+    const BLOCK_LEN: usize = 64;
 
-    hasher.update(ns);
-    hasher.update(src);
+    // Keys longer than a block are hashed first
+    let mut block = [0; BLOCK_LEN];
+    if key.len() > BLOCK_LEN {
+        let mut hasher = Sha1::new();
+        hasher.update(key);
+
+        block[..20].copy_from_slice(&hasher.digest().bytes());
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut pad = [0; BLOCK_LEN];
+
+    for (pad, key) in pad.iter_mut().zip(block.iter()) {
+        *pad = key ^ 0x36;
+    }
+
+    let mut inner = Sha1::new();
+    inner.update(&pad);
+    inner.update(ns);
+    inner.update(src);
+
+    for (pad, key) in pad.iter_mut().zip(block.iter()) {
+        *pad = key ^ 0x5c;
+    }
+
+    let mut outer = Sha1::new();
+    outer.update(&pad);
+    outer.update(&inner.digest().bytes());
 
     let mut bytes = [0; 16];
-    bytes.copy_from_slice(&hasher.digest().bytes()[..16]);
+    bytes.copy_from_slice(&outer.digest().bytes()[..16]);
 
     bytes
 }
@@ -0,0 +1,246 @@
+// SIMD hex encoding for the `Simple` and `Hyphenated` formats.
+//
+// Each implementation of `format_simple` returns `None` if the
+// instructions it needs aren't available, in which case callers
+// fall back to the scalar implementation in `fmt`.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) use self::x86::format_simple;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub(crate) use self::neon::format_simple;
+
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+#[inline]
+pub(crate) fn format_simple(_: &[u8; 16], _: bool) -> Option<[u8; 32]> {
+    None
+}
+
+#[inline]
+pub(crate) fn format_hyphenated(src: &[u8; 16], upper: bool) -> Option<[u8; 36]> {
+    let simple = format_simple(src, upper)?;
+
+    let mut dst = [b'-'; 36];
+    dst[0..8].copy_from_slice(&simple[0..8]);
+    dst[9..13].copy_from_slice(&simple[8..12]);
+    dst[14..18].copy_from_slice(&simple[12..16]);
+    dst[19..23].copy_from_slice(&simple[16..20]);
+    dst[24..36].copy_from_slice(&simple[20..32]);
+
+    Some(dst)
+}
+
+#[inline]
+fn lut(upper: bool) -> &'static [u8; 16] {
+    if upper {
+        &crate::fmt::UPPER
+    } else {
+        &crate::fmt::LOWER
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use crate::std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use crate::std::arch::x86_64::*;
+
+    #[inline]
+    pub(crate) fn format_simple(src: &[u8; 16], upper: bool) -> Option<[u8; 32]> {
+        let lut = super::lut(upper);
+
+        if has_avx2() {
+            // SAFETY: AVX2 is available
+            Some(unsafe { format_simple_avx2(src, lut) })
+        } else if has_ssse3() {
+            // SAFETY: SSSE3 is available
+            Some(unsafe { format_simple_ssse3(src, lut) })
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn has_avx2() -> bool {
+        std::is_x86_feature_detected!("avx2")
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn has_avx2() -> bool {
+        cfg!(target_feature = "avx2")
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn has_ssse3() -> bool {
+        std::is_x86_feature_detected!("ssse3")
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn has_ssse3() -> bool {
+        cfg!(target_feature = "ssse3")
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn format_simple_avx2(src: &[u8; 16], lut: &[u8; 16]) -> [u8; 32] {
+        let lut = _mm256_broadcastsi128_si256(_mm_loadu_si128(lut.as_ptr() as *const __m128i));
+
+        // Widen each byte into a 16-bit lane, then put its high nibble in the
+        // low byte of the lane and its low nibble in the high byte, so the
+        // digits come out in order after the lookup
+        let x = _mm256_cvtepu8_epi16(_mm_loadu_si128(src.as_ptr() as *const __m128i));
+        let hi = _mm256_srli_epi16(x, 4);
+        let lo = _mm256_slli_epi16(_mm256_and_si256(x, _mm256_set1_epi16(0x0f)), 8);
+
+        let hex = _mm256_shuffle_epi8(lut, _mm256_or_si256(hi, lo));
+
+        let mut dst = [0; 32];
+        _mm256_storeu_si256(dst.as_mut_ptr() as *mut __m256i, hex);
+        dst
+    }
+
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn format_simple_ssse3(src: &[u8; 16], lut: &[u8; 16]) -> [u8; 32] {
+        let lut = _mm_loadu_si128(lut.as_ptr() as *const __m128i);
+        let mask = _mm_set1_epi8(0x0f);
+
+        let x = _mm_loadu_si128(src.as_ptr() as *const __m128i);
+        let hi = _mm_shuffle_epi8(lut, _mm_and_si128(_mm_srli_epi16(x, 4), mask));
+        let lo = _mm_shuffle_epi8(lut, _mm_and_si128(x, mask));
+
+        let mut dst = [0; 32];
+        _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, _mm_unpacklo_epi8(hi, lo));
+        _mm_storeu_si128(
+            dst.as_mut_ptr().add(16) as *mut __m128i,
+            _mm_unpackhi_epi8(hi, lo),
+        );
+        dst
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use crate::std::arch::aarch64::*;
+
+    #[inline]
+    pub(crate) fn format_simple(src: &[u8; 16], upper: bool) -> Option<[u8; 32]> {
+        // SAFETY: NEON is enabled for this target
+        Some(unsafe { format_simple_neon(src, super::lut(upper)) })
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn format_simple_neon(src: &[u8; 16], lut: &[u8; 16]) -> [u8; 32] {
+        let lut = vld1q_u8(lut.as_ptr());
+
+        let x = vld1q_u8(src.as_ptr());
+        let hi = vqtbl1q_u8(lut, vshrq_n_u8(x, 4));
+        let lo = vqtbl1q_u8(lut, vandq_u8(x, vdupq_n_u8(0x0f)));
+
+        let mut dst = [0; 32];
+        vst1q_u8(dst.as_mut_ptr(), vzip1q_u8(hi, lo));
+        vst1q_u8(dst.as_mut_ptr().add(16), vzip2q_u8(hi, lo));
+        dst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn inputs() -> impl Iterator<Item = [u8; 16]> {
+        (0..=255u8).map(|b| {
+            let mut bytes = [0; 16];
+            for (i, x) in bytes.iter_mut().enumerate() {
+                *x = b.wrapping_add((i as u8).wrapping_mul(17));
+            }
+            bytes
+        })
+    }
+
+    fn scalar_simple(src: &[u8; 16], upper: bool) -> [u8; 32] {
+        let mut dst = [0; 32];
+        for (i, x) in src.iter().enumerate() {
+            dst[i * 2] = lut(upper)[(x >> 4) as usize];
+            dst[i * 2 + 1] = lut(upper)[(x & 0x0f) as usize];
+        }
+        dst
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_format_simple() {
+        for src in inputs() {
+            for upper in [false, true] {
+                if let Some(simd) = format_simple(&src, upper) {
+                    assert_eq!(simd, scalar_simple(&src, upper));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_format_hyphenated() {
+        let src = [
+            0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6,
+            0xd7, 0xd8,
+        ];
+
+        if let Some(simd) = format_hyphenated(&src, false) {
+            assert_eq!(&simd, b"a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8");
+        }
+        if let Some(simd) = format_hyphenated(&src, true) {
+            assert_eq!(&simd, b"A1A2A3A4-B1B2-C1C2-D1D2-D3D4D5D6D7D8");
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    fn test_x86_implementations() {
+        for src in inputs() {
+            for upper in [false, true] {
+                let expected = scalar_simple(&src, upper);
+
+                if std::is_x86_feature_detected!("avx2") {
+                    // SAFETY: AVX2 is available
+                    let actual = unsafe { x86::format_simple_avx2(&src, lut(upper)) };
+                    assert_eq!(actual, expected);
+                }
+                if std::is_x86_feature_detected!("ssse3") {
+                    // SAFETY: SSSE3 is available
+                    let actual = unsafe { x86::format_simple_ssse3(&src, lut(upper)) };
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+    }
+}
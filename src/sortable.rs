@@ -0,0 +1,210 @@
+//! A sortable ID that hides which UUID version backs it.
+//!
+//! Note that you need to enable the `v6` or `v7` Cargo feature in order to
+//! use this module. If both are enabled then version 7 is used.
+
+use crate::{
+    error::{Error, ErrorRepr},
+    std::convert::TryFrom,
+    Timestamp, Uuid,
+};
+
+/// A unique ID that sorts by the time it was created.
+///
+/// A `SortableId` is a UUID, but it only exposes the parts that matter
+/// for a sortable ID: when it was created, how it orders against other
+/// IDs, and its string form. The crate picks which UUID version backs it,
+/// so applications don't need to:
+///
+/// - If the `v7` feature is enabled then new IDs are version 7 UUIDs.
+/// - Otherwise new IDs are version 6 UUIDs with a random node ID.
+///
+/// Both versions are accepted when parsing, so IDs created before
+/// switching from one to the other can still be read. IDs backed by
+/// different versions don't sort by time against each other though,
+/// so switching between them will leave existing IDs out of order.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::SortableId;
+/// let a: SortableId = "017f22e2-79b0-7cc3-98c4-dc0c0c07398f".parse()?;
+/// let b: SortableId = "017f22e2-7d98-7a52-b4f1-bc0f7e54a4c9".parse()?;
+///
+/// assert!(a < b);
+/// assert_eq!((1645557742, 0), a.created_at().to_unix());
+///
+/// // Version 6 UUIDs are also sortable
+/// assert!("1ec9414c-232a-6b00-b3c8-9f6bdeced846".parse::<SortableId>().is_ok());
+///
+/// // Other versions aren't
+/// assert!("67e55044-10b1-426f-9247-bb680e5fe0c8".parse::<SortableId>().is_err());
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SortableId(Uuid);
+
+impl SortableId {
+    /// Create a new ID using the current system time.
+    ///
+    /// Note that usage of this method requires the `std` and `rng`
+    /// features of this crate to be enabled.
+    #[cfg(all(feature = "std", feature = "rng"))]
+    pub fn now() -> Self {
+        #[cfg(feature = "v7")]
+        {
+            SortableId(Uuid::now_v7())
+        }
+        #[cfg(not(feature = "v7"))]
+        {
+            SortableId(Uuid::now_v6(crate::NodeId::random()))
+        }
+    }
+
+    /// Use a UUID as a sortable ID.
+    ///
+    /// This method will return an error if the UUID isn't a version 6 or
+    /// version 7 UUID.
+    pub const fn from_uuid(uuid: Uuid) -> Result<Self, Error> {
+        match uuid.get_version_num() {
+            6 | 7 => Ok(SortableId(uuid)),
+            version => Err(Error(ErrorRepr::NotSortable { version })),
+        }
+    }
+
+    /// Get the time the ID was created.
+    ///
+    /// The time is truncated to 100ns precision for IDs backed by version 6
+    /// UUIDs, and to millisecond precision for IDs backed by version 7
+    /// UUIDs.
+    pub const fn created_at(&self) -> Timestamp {
+        match self.0.get_timestamp() {
+            Some(ts) => ts,
+            None => panic!("sortable IDs always have a timestamp"),
+        }
+    }
+
+    /// Get the underlying UUID.
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consume the ID and get the underlying UUID.
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<SortableId> for Uuid {
+    fn from(id: SortableId) -> Self {
+        id.0
+    }
+}
+
+impl TryFrom<Uuid> for SortableId {
+    type Error = Error;
+
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        SortableId::from_uuid(uuid)
+    }
+}
+
+impl crate::std::fmt::Debug for SortableId {
+    fn fmt(&self, f: &mut crate::std::fmt::Formatter<'_>) -> crate::std::fmt::Result {
+        crate::std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(uuid_core_only))]
+impl crate::std::fmt::Display for SortableId {
+    fn fmt(&self, f: &mut crate::std::fmt::Formatter<'_>) -> crate::std::fmt::Result {
+        crate::std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(uuid_core_only))]
+impl crate::std::str::FromStr for SortableId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SortableId::from_uuid(Uuid::parse_str(s)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_uuid() {
+        let v6 = Uuid::from_u128(0x1ec9414c_232a_6b00_b3c8_9f6bdeced846);
+        let v7 = Uuid::from_u128(0x017f22e2_79b0_7cc3_98c4_dc0c0c07398f);
+
+        assert_eq!(v6, SortableId::from_uuid(v6).unwrap().into_uuid());
+        assert_eq!(v7, SortableId::from_uuid(v7).unwrap().into_uuid());
+
+        assert_eq!(
+            Err(Error(ErrorRepr::NotSortable { version: 4 })),
+            SortableId::from_uuid(Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8))
+        );
+        assert_eq!(
+            Err(Error(ErrorRepr::NotSortable { version: 0 })),
+            SortableId::from_uuid(Uuid::nil())
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_created_at() {
+        let v7 =
+            SortableId::from_uuid(Uuid::from_u128(0x017f22e2_79b0_7cc3_98c4_dc0c0c07398f)).unwrap();
+
+        assert_eq!((1645557742, 0), v7.created_at().to_unix());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_now() {
+        let a = SortableId::now();
+        let b = SortableId::now();
+
+        assert!(a < b);
+        assert!(a.created_at().to_unix() <= b.created_at().to_unix());
+
+        #[cfg(feature = "v7")]
+        assert_eq!(7, a.as_uuid().get_version_num());
+        #[cfg(not(feature = "v7"))]
+        assert_eq!(6, a.as_uuid().get_version_num());
+    }
+}
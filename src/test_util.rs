@@ -0,0 +1,205 @@
+//! Utilities for testing UUID generation.
+//!
+//! This module is useful for soak tests that check a custom generator,
+//! context, or seeded source of randomness really does produce unique
+//! UUIDs, and for tests that need the same UUIDs on every run. Tests that
+//! make assertions about time-ordered UUIDs can use `freeze_clock` so they
+//! don't depend on the wall clock.
+//!
+//! Note that you need to enable the `test-util` Cargo feature in order to
+//! use this module, so builds that don't need it don't pay for checking
+//! whether the clock is frozen.
+
+use crate::{collision::mix, Builder, Uuid};
+
+pub use crate::collision::{Collision, CollisionChecker};
+
+#[cfg(uuid_unstable)]
+use crate::Timestamp;
+
+mod clock;
+
+pub(crate) use self::clock::frozen_now;
+#[cfg(all(uuid_unstable, feature = "v7"))]
+pub(crate) use self::clock::with_frozen_context_v7;
+pub use self::clock::{freeze_clock, FrozenClock};
+
+/// A reproducible source of UUIDs for tests.
+///
+/// A `SeededGenerator` produces the same sequence of UUIDs every time it's
+/// created with the same seed, so snapshot tests and simulations get stable
+/// IDs. The UUIDs have the right version and variant bits, but the rest of
+/// them is only pseudorandom, so a `SeededGenerator` must never be used to
+/// generate UUIDs outside of tests.
+///
+/// Version 7 UUIDs don't read the system clock. Instead, the first one
+/// uses the Unix epoch as its timestamp, or the time given to
+/// [`with_start`](SeededGenerator::with_start), and each one after it is a
+/// millisecond later. This means they always sort in the order they were
+/// generated.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::test_util::SeededGenerator;
+/// let mut a = SeededGenerator::new(42);
+/// let mut b = SeededGenerator::new(42);
+///
+/// assert_eq!(a.new_v4(), b.new_v4());
+/// assert_ne!(a.new_v4(), SeededGenerator::new(43).new_v4());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeededGenerator {
+    state: u64,
+    #[cfg(uuid_unstable)]
+    millis: u64,
+}
+
+impl SeededGenerator {
+    /// Create a generator from a seed.
+    pub const fn new(seed: u64) -> Self {
+        SeededGenerator {
+            state: seed,
+            #[cfg(uuid_unstable)]
+            millis: 0,
+        }
+    }
+
+    /// Use the given time as the timestamp of the next version 7 UUID.
+    ///
+    /// Only the whole milliseconds of `ts` are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{test_util::SeededGenerator, NoContext, Timestamp};
+    /// let mut generator = SeededGenerator::new(42)
+    ///     .with_start(Timestamp::from_unix(NoContext, 1_497_624_119, 0));
+    ///
+    /// let a = generator.new_v7();
+    /// let b = generator.new_v7();
+    ///
+    /// assert_eq!(Some(Timestamp::from_unix(NoContext, 1_497_624_119, 0)), a.get_timestamp());
+    /// assert!(a < b);
+    /// ```
+    #[cfg(uuid_unstable)]
+    pub const fn with_start(mut self, ts: Timestamp) -> Self {
+        self.millis = ts.to_unix_millis();
+        self
+    }
+
+    /// Generate the next version 4 UUID.
+    pub fn new_v4(&mut self) -> Uuid {
+        Builder::from_random_bytes(self.next_bytes()).into_uuid()
+    }
+
+    /// Generate the next version 7 UUID.
+    ///
+    /// Note that usage of this method requires the `uuid_unstable` cfg to
+    /// be enabled.
+    #[cfg(uuid_unstable)]
+    pub fn new_v7(&mut self) -> Uuid {
+        let bytes = self.next_bytes();
+
+        let mut random_bytes = [0; 10];
+        random_bytes.copy_from_slice(&bytes[..10]);
+
+        let uuid = Builder::from_unix_timestamp_millis(self.millis, &random_bytes).into_uuid();
+        self.millis += 1;
+
+        uuid
+    }
+
+    // Produce the next 16 bytes of the sequence using splitmix64
+    fn next_bytes(&mut self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+
+        for chunk in bytes.chunks_mut(8) {
+            self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+            chunk.copy_from_slice(&mix(self.state).to_be_bytes());
+        }
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::vec::Vec;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_seeded_generator_v4() {
+        use crate::{Variant, Version};
+
+        let a: Vec<Uuid> = {
+            let mut generator = SeededGenerator::new(42);
+            (0..1_000).map(|_| generator.new_v4()).collect()
+        };
+        let b: Vec<Uuid> = {
+            let mut generator = SeededGenerator::new(42);
+            (0..1_000).map(|_| generator.new_v4()).collect()
+        };
+
+        assert_eq!(a, b);
+
+        let mut checker = CollisionChecker::new();
+        checker.extend(a.iter().copied());
+        checker.insert(SeededGenerator::new(43).new_v4());
+
+        assert!(!checker.has_collisions());
+
+        for uuid in a {
+            assert_eq!(Some(Version::Random), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(uuid_unstable)]
+    fn test_seeded_generator_v7() {
+        use crate::{NoContext, Version};
+
+        let start = Timestamp::from_unix(NoContext, 1_497_624_119, 0);
+
+        let mut generator = SeededGenerator::new(42).with_start(start);
+        let uuids: Vec<Uuid> = (0..1_000).map(|_| generator.new_v7()).collect();
+
+        let mut again = SeededGenerator::new(42).with_start(start);
+        assert_eq!(uuids[0], again.new_v7());
+
+        assert_eq!(Some(start), uuids[0].get_timestamp());
+        assert_eq!(
+            Some(Timestamp::from_unix(NoContext, 1_497_624_119, 999_000_000)),
+            uuids[999].get_timestamp()
+        );
+
+        for pair in uuids.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert_eq!(Some(Version::SortRand), pair[1].get_version());
+        }
+    }
+}
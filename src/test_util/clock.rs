@@ -0,0 +1,279 @@
+//! Freezing the clock read by `Timestamp::now` in tests.
+
+use crate::{
+    std::{cell::RefCell, marker::PhantomData, time::Duration},
+    NoContext, Timestamp,
+};
+
+#[cfg(all(uuid_unstable, feature = "v7"))]
+use crate::ContextV7;
+
+thread_local! {
+    // `const` initializers need Rust 1.59, newer than our MSRV
+    #[allow(unknown_lints, clippy::missing_const_for_thread_local)]
+    static FROZEN: RefCell<Option<Frozen>> = RefCell::new(None);
+}
+
+// The state of a frozen clock on the current thread
+struct Frozen {
+    nanos: u128,
+    step: u128,
+    // Version 7 UUIDs never go backwards through a context, so a frozen
+    // clock gets its own instead of the shared one
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    context_v7: ContextV7,
+}
+
+/// Freeze the clock read by [`Timestamp::now`] and the `now_*` methods on
+/// [`Uuid`](crate::Uuid) at the given time on the current thread.
+///
+/// Time-ordered UUIDs generated on this thread get their timestamps from
+/// the frozen clock until the returned guard is dropped, so tests that
+/// check them are reproducible. The clock only moves when it's told to,
+/// through [`FrozenClock::advance`] or [`FrozenClock::auto_advance`].
+/// Other threads keep reading the system clock, so tests running in
+/// parallel don't see each other's clocks.
+///
+/// Freezing the clock again on the same thread replaces the previous time,
+/// and dropping any of the guards unfreezes it.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use uuid::{test_util, NoContext, Timestamp};
+/// let clock = test_util::freeze_clock(Timestamp::from_unix(NoContext, 1_497_624_119, 0));
+///
+/// assert_eq!((1_497_624_119, 0), Timestamp::now(NoContext).to_unix());
+///
+/// clock.advance(Duration::from_millis(1500));
+///
+/// assert_eq!((1_497_624_120, 500_000_000), Timestamp::now(NoContext).to_unix());
+/// ```
+pub fn freeze_clock(at: Timestamp) -> FrozenClock {
+    FROZEN.with(|frozen| {
+        *frozen.borrow_mut() = Some(Frozen {
+            nanos: unix_nanos(at),
+            step: 0,
+            #[cfg(all(uuid_unstable, feature = "v7"))]
+            context_v7: ContextV7::new(),
+        })
+    });
+
+    FrozenClock {
+        _thread: PhantomData,
+    }
+}
+
+/// A guard for a clock frozen by [`freeze_clock`].
+///
+/// Dropping the guard goes back to the system clock.
+#[derive(Debug)]
+#[must_use = "the clock is unfrozen when the guard is dropped"]
+pub struct FrozenClock {
+    // The clock belongs to the thread that froze it
+    _thread: PhantomData<*const ()>,
+}
+
+impl FrozenClock {
+    /// Move the clock to the given time.
+    ///
+    /// The time can be before the current one, to test what happens when
+    /// the clock goes backwards.
+    pub fn set(&self, at: Timestamp) {
+        with_frozen(|frozen| frozen.nanos = unix_nanos(at));
+    }
+
+    /// Move the clock forwards by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        with_frozen(|frozen| frozen.nanos += duration.as_nanos());
+    }
+
+    /// Move the clock forwards by the given duration each time it's read.
+    ///
+    /// The time read is the one before the clock is moved, so the first
+    /// read after calling this still returns the current time. Passing a
+    /// zero duration stops the clock moving again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::{test_util, NoContext, Timestamp};
+    /// let clock = test_util::freeze_clock(Timestamp::from_unix(NoContext, 1_497_624_119, 0));
+    /// clock.auto_advance(Duration::from_secs(1));
+    ///
+    /// assert_eq!((1_497_624_119, 0), Timestamp::now(NoContext).to_unix());
+    /// assert_eq!((1_497_624_120, 0), Timestamp::now(NoContext).to_unix());
+    /// ```
+    pub fn auto_advance(&self, step: Duration) {
+        with_frozen(|frozen| frozen.step = step.as_nanos());
+    }
+
+    /// Get the current time of the clock without moving it.
+    pub fn now(&self) -> Timestamp {
+        let nanos = with_frozen(|frozen| frozen.nanos);
+
+        Timestamp::from_unix(
+            NoContext,
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+}
+
+impl Drop for FrozenClock {
+    fn drop(&mut self) {
+        // The thread-local might already be gone if the guard outlives it
+        let _ = FROZEN.try_with(|frozen| *frozen.borrow_mut() = None);
+    }
+}
+
+fn with_frozen<R>(f: impl FnOnce(&mut Frozen) -> R) -> R {
+    FROZEN.with(|frozen| {
+        f(frozen
+            .borrow_mut()
+            .as_mut()
+            .expect("the clock was unfrozen by dropping another `FrozenClock`"))
+    })
+}
+
+fn unix_nanos(ts: Timestamp) -> u128 {
+    let (seconds, nanos) = ts.to_unix();
+
+    seconds as u128 * 1_000_000_000 + nanos as u128
+}
+
+// Read the frozen clock on the current thread, if there is one
+//
+// UUIDs can be generated while thread-locals are being destroyed, when there
+// can't be a frozen clock anymore
+pub(crate) fn frozen_now() -> Option<(u64, u32)> {
+    FROZEN
+        .try_with(|frozen| {
+            frozen.borrow_mut().as_mut().map(|frozen| {
+                let nanos = frozen.nanos;
+                frozen.nanos += frozen.step;
+
+                (
+                    (nanos / 1_000_000_000) as u64,
+                    (nanos % 1_000_000_000) as u32,
+                )
+            })
+        })
+        .ok()
+        .flatten()
+}
+
+// Use the version 7 context of the frozen clock on the current thread, if there is one
+#[cfg(all(uuid_unstable, feature = "v7"))]
+pub(crate) fn with_frozen_context_v7<R>(f: impl FnOnce(&ContextV7) -> R) -> Option<R> {
+    FROZEN
+        .try_with(|frozen| frozen.borrow().as_ref().map(|frozen| f(&frozen.context_v7)))
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(uuid_unstable)]
+    use crate::Uuid;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_freeze_clock() {
+        let start = Timestamp::from_unix(NoContext, 1_497_624_119, 1234);
+
+        let clock = freeze_clock(start);
+        assert_eq!(start, Timestamp::now(NoContext));
+        assert_eq!(start, Timestamp::now(NoContext));
+
+        clock.advance(Duration::from_nanos(999_998_766));
+        assert_eq!((1_497_624_120, 0), Timestamp::now(NoContext).to_unix());
+
+        clock.auto_advance(Duration::from_secs(1));
+        assert_eq!((1_497_624_120, 0), Timestamp::now(NoContext).to_unix());
+        assert_eq!((1_497_624_121, 0), clock.now().to_unix());
+        assert_eq!((1_497_624_121, 0), Timestamp::now(NoContext).to_unix());
+
+        clock.set(start);
+        assert_eq!(start, clock.now());
+
+        drop(clock);
+        assert!(Timestamp::now(NoContext).to_unix().0 > 1_600_000_000);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    fn test_freeze_clock_v7() {
+        // A time well before any UUIDs generated through the shared context
+        let start = Timestamp::from_unix(NoContext, 1_497_624_119, 0);
+
+        let clock = freeze_clock(start);
+
+        let first = Uuid::now_v7();
+        let second = Uuid::now_v7();
+
+        assert!(first < second);
+        assert_eq!(Some(start), first.get_timestamp());
+        assert_eq!(Some(start), second.get_timestamp());
+
+        clock.advance(Duration::from_millis(1));
+
+        let mut batch = [Uuid::nil(); 4];
+        Uuid::now_v7_batch(&mut batch);
+
+        assert!(second < batch[0]);
+        assert_eq!(
+            Some(Timestamp::from_unix(NoContext, 1_497_624_119, 1_000_000)),
+            batch[3].get_timestamp()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(uuid_unstable, feature = "v6", feature = "rng"))]
+    fn test_freeze_clock_v6() {
+        let _clock = freeze_clock(Timestamp::from_unix(NoContext, 1_497_624_119, 1200));
+
+        let first = Uuid::now_v6([1, 2, 3, 4, 5, 6]);
+        let second = Uuid::now_v6([1, 2, 3, 4, 5, 6]);
+
+        assert_ne!(first, second);
+        assert_eq!(
+            (1_497_624_119, 1200),
+            first.get_timestamp().unwrap().to_unix()
+        );
+    }
+}
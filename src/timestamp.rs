@@ -20,102 +20,245 @@
 //! * [Timestamp in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.1.4)
 //! * [Timestamp in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-6.1)
 
-use crate::Uuid;
+use crate::{
+    error::*,
+    std::{cmp, hash, time::Duration},
+    Uuid,
+};
 
 /// The number of 100 nanosecond ticks between the RFC4122 epoch
 /// (`1582-10-15 00:00:00`) and the Unix epoch (`1970-01-01 00:00:00`).
 pub const UUID_TICKS_BETWEEN_EPOCHS: u64 = 0x01B2_1DD2_1381_4000;
 
+// The largest number of ticks that fit in the 60 bits of an RFC4122 timestamp
+#[cfg(any(feature = "v1", feature = "v2", feature = "v6"))]
+const MAX_RFC4122_TICKS: u64 = 0x0FFF_FFFF_FFFF_FFFF;
+
 /// A timestamp that can be encoded into a UUID.
 ///
 /// This type abstracts the specific encoding, so versions 1, 6, and 7
 /// UUIDs can both be supported through the same type, even
 /// though they have a different representation of a timestamp.
 ///
+/// Timestamps are ordered by their seconds, then their nanoseconds, then
+/// their counter, so timestamps from UUIDs can be sorted by the time they
+/// represent.
+///
+/// When the `serde` feature is enabled, timestamps can be serialized as a
+/// struct with their seconds, nanoseconds, and counter.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Timestamp, NoContext};
+/// let earlier = Timestamp::from_unix(NoContext, 1_497_624_119, 999_999_999);
+/// let later = Timestamp::from_unix(NoContext, 1_497_624_120, 0);
+///
+/// assert!(earlier < later);
+/// ```
+///
 /// # References
 ///
 /// * [Timestamp in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.1.4)
 /// * [Timestamp in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-6.1)
 /// * [Clock Sequence in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.1.5)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy)]
 pub struct Timestamp {
     pub(crate) seconds: u64,
     pub(crate) nanos: u32,
-    #[cfg(any(feature = "v1", feature = "v6"))]
-    pub(crate) counter: u16,
+    pub(crate) counter: u128,
+    pub(crate) usable_counter_bits: u8,
+}
+
+// The usable counter bits only say how the counter is encoded, so they
+// aren't part of a timestamp's identity or order
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.seconds, self.nanos, self.counter).cmp(&(other.seconds, other.nanos, other.counter))
+    }
+}
+
+impl hash::Hash for Timestamp {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        (self.seconds, self.nanos, self.counter).hash(state);
+    }
 }
 
 impl Timestamp {
     /// Get a timestamp representing the current system time.
     ///
     /// This method defers to the standard library's `SystemTime` type.
+    /// When the `uefi` feature is enabled on a UEFI target without the standard
+    /// library, the time is read from UEFI Runtime Services instead. When the
+    /// `custom-clock` feature is enabled, the time is read from the clock
+    /// passed to [`clock::register_clock`] if there is one.
     ///
     /// # Panics
     ///
-    /// This method will panic if calculating the elapsed time since the Unix epoch fails.
-    #[cfg(feature = "std")]
-    pub fn now(context: impl ClockSequence<Output = u16>) -> Self {
-        #[cfg(not(any(feature = "v1", feature = "v6")))]
-        {
-            let _ = context;
-        }
+    /// This method will panic if calculating the elapsed time since the Unix epoch fails,
+    /// or if the `custom-clock` feature is enabled on a target without a system clock
+    /// and no clock has been registered.
+    #[cfg(any(
+        feature = "std",
+        all(feature = "uefi", target_os = "uefi"),
+        feature = "custom-clock"
+    ))]
+    pub fn now(context: impl ClockSequence<Output = impl Into<u128>>) -> Self {
+        Self::now_with(clock::SystemClock, context)
+    }
+
+    /// Get a timestamp representing the current time according to the given clock.
+    ///
+    /// This method can be used in place of [`Timestamp::now`] to supply the time
+    /// from somewhere other than the system, like a fixed clock in tests or a
+    /// real-time clock on an embedded target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ClockSource, Timestamp, NoContext};
+    /// struct FixedClock(u64, u32);
+    ///
+    /// impl ClockSource for FixedClock {
+    ///     fn now(&self) -> (u64, u32) {
+    ///         (self.0, self.1)
+    ///     }
+    /// }
+    ///
+    /// let ts = Timestamp::now_with(FixedClock(1_497_624_119, 1234), NoContext);
+    ///
+    /// assert_eq!((1_497_624_119, 1234), ts.to_unix());
+    /// ```
+    pub fn now_with(
+        clock: impl ClockSource,
+        context: impl ClockSequence<Output = impl Into<u128>>,
+    ) -> Self {
+        let (seconds, nanos) = clock.now();
+        let (seconds, nanos) = clock.time_scale().to_unix(seconds, nanos);
+
+        Self::from_unix(context, seconds, nanos)
+    }
 
-        let (seconds, nanos) = now();
+    /// Construct a `Timestamp` from an RFC4122 timestamp and counter, as used
+    /// in versions 1 and 6 UUIDs.
+    ///
+    /// # Overflow
+    ///
+    /// RFC4122 timestamps can represent times before the Unix epoch, but
+    /// `Timestamp` can't. Ticks before the Unix epoch will saturate to it.
+    /// Use [`Timestamp::try_from_rfc4122`] to catch them instead.
+    pub const fn from_rfc4122(ticks: u64, counter: u16) -> Self {
+        let (seconds, nanos) = Self::rfc4122_to_unix(ticks);
 
         Timestamp {
             seconds,
             nanos,
-            #[cfg(any(feature = "v1", feature = "v6"))]
-            counter: context.generate_sequence(seconds, nanos),
+            counter: counter as u128,
+            usable_counter_bits: 14,
         }
     }
 
-    /// Construct a `Timestamp` from an RFC4122 timestamp and counter, as used
-    /// in versions 1 and 6 UUIDs.
+    /// Try to construct a `Timestamp` from an RFC4122 timestamp and counter, as
+    /// used in versions 1 and 6 UUIDs.
     ///
-    /// # Overflow
+    /// # Errors
     ///
-    /// If conversion from RFC4122 ticks to the internal timestamp format would overflow
-    /// it will wrap.
-    pub const fn from_rfc4122(ticks: u64, counter: u16) -> Self {
-        #[cfg(not(any(feature = "v1", feature = "v6")))]
-        {
-            let _ = counter;
+    /// This method will return an error if `ticks` is before the Unix epoch,
+    /// or is wider than the 60 bits of an RFC4122 timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Timestamp;
+    /// let ts = Timestamp::try_from_rfc4122(0x1e7_4ba2_2061_6934, 0)?;
+    /// assert_eq!((1_496_854_535, 812_946_000), ts.to_unix());
+    ///
+    /// // 1582-10-15 00:00:00
+    /// assert!(Timestamp::try_from_rfc4122(0, 0).is_err());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn try_from_rfc4122(ticks: u64, counter: u16) -> Result<Self, Error> {
+        if ticks >> 60 != 0 {
+            Err(Error(ErrorRepr::Range {
+                field: "timestamp",
+                bits: 60,
+            }))
+        } else if ticks < UUID_TICKS_BETWEEN_EPOCHS {
+            Err(Error(ErrorRepr::TimestampBeforeEpoch))
+        } else {
+            Ok(Self::from_rfc4122(ticks, counter))
         }
+    }
 
-        let (seconds, nanos) = Self::rfc4122_to_unix(ticks);
+    /// Construct a `Timestamp` from a Unix timestamp, as used in version 7 UUIDs.
+    pub fn from_unix(
+        context: impl ClockSequence<Output = impl Into<u128>>,
+        seconds: u64,
+        nanos: u32,
+    ) -> Self {
+        let (counter, seconds, nanos) = context.generate_timestamp_sequence(seconds, nanos);
+        let counter = counter.into();
+        let usable_counter_bits = cmp::min(128, context.usable_bits()) as u8;
 
         Timestamp {
             seconds,
             nanos,
-            #[cfg(any(feature = "v1", feature = "v6"))]
             counter,
+            usable_counter_bits,
         }
     }
 
-    /// Construct a `Timestamp` from a Unix timestamp, as used in version 7 UUIDs.
+    /// Construct a `Timestamp` from the number of milliseconds since the Unix epoch.
     ///
-    /// # Overflow
+    /// # Examples
     ///
-    /// If conversion from RFC4122 ticks to the internal timestamp format would overflow
-    /// it will wrap.
-    pub fn from_unix(context: impl ClockSequence<Output = u16>, seconds: u64, nanos: u32) -> Self {
-        #[cfg(not(any(feature = "v1", feature = "v6")))]
-        {
-            let _ = context;
-
-            Timestamp { seconds, nanos }
-        }
-        #[cfg(any(feature = "v1", feature = "v6"))]
-        {
-            let counter = context.generate_sequence(seconds, nanos);
+    /// ```
+    /// # use uuid::{Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix_millis(NoContext, 1_497_624_119_812);
+    ///
+    /// assert_eq!((1_497_624_119, 812_000_000), ts.to_unix());
+    /// ```
+    pub fn from_unix_millis(
+        context: impl ClockSequence<Output = impl Into<u128>>,
+        millis: u64,
+    ) -> Self {
+        Self::from_unix(context, millis / 1_000, (millis % 1_000) as u32 * 1_000_000)
+    }
 
-            Timestamp {
-                seconds,
-                nanos,
-                counter,
-            }
-        }
+    /// Construct a `Timestamp` from the number of microseconds since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix_micros(NoContext, 1_497_624_119_812_946);
+    ///
+    /// assert_eq!((1_497_624_119, 812_946_000), ts.to_unix());
+    /// ```
+    pub fn from_unix_micros(
+        context: impl ClockSequence<Output = impl Into<u128>>,
+        micros: u64,
+    ) -> Self {
+        Self::from_unix(
+            context,
+            micros / 1_000_000,
+            (micros % 1_000_000) as u32 * 1_000,
+        )
     }
 
     /// Get the value of the timestamp as an RFC4122 timestamp and counter,
@@ -123,42 +266,474 @@ impl Timestamp {
     ///
     /// # Overflow
     ///
-    /// If conversion from RFC4122 ticks to the internal timestamp format would overflow
-    /// it will wrap.
-    #[cfg(any(feature = "v1", feature = "v6"))]
+    /// If conversion from the internal timestamp format to RFC4122 ticks would
+    /// overflow it will saturate to `u64::MAX`.
+    ///
+    /// RFC4122 timestamps are only 60 bits, so they run out in the year 5236,
+    /// and only the low 60 bits of the ticks are encoded in a UUID. Use
+    /// [`Timestamp::try_to_rfc4122`] to catch timestamps after that instead.
+    #[cfg(any(feature = "v1", feature = "v2", feature = "v6"))]
     pub const fn to_rfc4122(&self) -> (u64, u16) {
         (
             Self::unix_to_rfc4122_ticks(self.seconds, self.nanos),
-            self.counter,
+            self.counter as u16,
         )
     }
 
-    /// Get the value of the timestamp as a Unix timestamp, as used in version 7 UUIDs.
+    /// Try to get the value of the timestamp as an RFC4122 timestamp and
+    /// counter, as used in versions 1 and 6 UUIDs.
     ///
-    /// # Overflow
+    /// # Errors
+    ///
+    /// This method will return an error if the timestamp is too far in the
+    /// future to fit in the 60 bits of an RFC4122 timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_946_000);
+    /// assert_eq!(0x1e7_4ba2_2061_6934, ts.try_to_rfc4122()?.0);
+    ///
+    /// let ts = Timestamp::from_unix(NoContext, u64::MAX, 0);
+    /// assert!(ts.try_to_rfc4122().is_err());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[cfg(any(feature = "v1", feature = "v2", feature = "v6"))]
+    pub const fn try_to_rfc4122(&self) -> Result<(u64, u16), Error> {
+        let ticks = Self::unix_to_rfc4122_ticks(self.seconds, self.nanos);
+
+        if ticks > MAX_RFC4122_TICKS {
+            Err(Error(ErrorRepr::Range {
+                field: "timestamp",
+                bits: 60,
+            }))
+        } else {
+            Ok((ticks, self.counter as u16))
+        }
+    }
+
+    /// Get the number of 100 nanosecond ticks since the Gregorian epoch
+    /// (`1582-10-15 00:00:00`), as used in version 1 and 6 UUIDs.
+    ///
+    /// Unlike [`Timestamp::to_rfc4122`], this method doesn't include the counter
+    /// and is available without the `v1` or `v6` features, so the timestamps of
+    /// existing version 1 and 6 UUIDs can be inspected.
     ///
-    /// If conversion from RFC4122 ticks to the internal timestamp format would overflow
-    /// it will wrap.
+    /// If conversion from the internal timestamp format to ticks would overflow
+    /// it will saturate to `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?;
+    ///
+    /// assert_eq!(
+    ///     Some(0x1e7_4ba2_2061_6934),
+    ///     uuid.get_timestamp().map(|ts| ts.to_gregorian_ticks())
+    /// );
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn to_gregorian_ticks(&self) -> u64 {
+        Self::unix_to_rfc4122_ticks(self.seconds, self.nanos)
+    }
+
+    /// Get the value of the timestamp as a Unix timestamp, as used in version 7 UUIDs.
     pub const fn to_unix(&self) -> (u64, u32) {
         (self.seconds, self.nanos)
     }
 
-    #[cfg(any(feature = "v1", feature = "v6"))]
-    const fn unix_to_rfc4122_ticks(seconds: u64, nanos: u32) -> u64 {
-        let ticks = UUID_TICKS_BETWEEN_EPOCHS
-            .wrapping_add(seconds.wrapping_mul(10_000_000))
-            .wrapping_add(nanos as u64 / 100);
+    /// Get the number of whole milliseconds since the Unix epoch.
+    ///
+    /// This is the precision of the timestamp in a version 7 UUID. If the
+    /// number of milliseconds would overflow it will saturate to `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1_497_624_119, 812_946_000);
+    ///
+    /// assert_eq!(1_497_624_119_812, ts.to_unix_millis());
+    /// ```
+    pub const fn to_unix_millis(&self) -> u64 {
+        self.seconds
+            .saturating_mul(1_000)
+            .saturating_add(self.nanos as u64 / 1_000_000)
+    }
 
-        ticks
+    /// Get the number of whole microseconds since the Unix epoch.
+    ///
+    /// If the number of microseconds would overflow it will saturate to `u64::MAX`.
+    pub const fn to_unix_micros(&self) -> u64 {
+        self.seconds
+            .saturating_mul(1_000_000)
+            .saturating_add(self.nanos as u64 / 1_000)
     }
 
-    const fn rfc4122_to_unix(ticks: u64) -> (u64, u32) {
-        (
-            ticks.wrapping_sub(UUID_TICKS_BETWEEN_EPOCHS) / 10_000_000,
-            (ticks.wrapping_sub(UUID_TICKS_BETWEEN_EPOCHS) % 10_000_000) as u32 * 100,
+    // Get the counter along with the number of its low bits that are usable
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    pub(crate) const fn counter(&self) -> (u128, u8) {
+        (self.counter, self.usable_counter_bits)
+    }
+
+    /// Add a duration to the timestamp, returning `None` if the result would overflow.
+    ///
+    /// The counter, if there is one, is kept as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::{Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1_497_624_119, 500_000_000);
+    ///
+    /// let expires = ts.checked_add(Duration::from_millis(1_500)).unwrap();
+    ///
+    /// assert_eq!((1_497_624_121, 0), expires.to_unix());
+    /// ```
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.as_duration()?
+            .checked_add(duration)
+            .map(|duration| self.with_duration(duration))
+    }
+
+    /// Subtract a duration from the timestamp, returning `None` if the result
+    /// would be before the Unix epoch.
+    ///
+    /// The counter, if there is one, is kept as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::{Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1_497_624_119, 0);
+    ///
+    /// let backdated = ts.checked_sub(Duration::from_secs(60 * 60)).unwrap();
+    ///
+    /// assert_eq!((1_497_620_519, 0), backdated.to_unix());
+    /// assert!(ts.checked_sub(Duration::from_secs(1_497_624_120)).is_none());
+    /// ```
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.as_duration()?
+            .checked_sub(duration)
+            .map(|duration| self.with_duration(duration))
+    }
+
+    /// Get the amount of time elapsed from an earlier timestamp to this one.
+    ///
+    /// If `earlier` is actually later than this timestamp then the amount of
+    /// time between them is returned as an error instead. The counters of
+    /// the timestamps aren't considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::{Timestamp, NoContext};
+    /// let created = Timestamp::from_unix(NoContext, 1_497_624_119, 0);
+    /// let updated = Timestamp::from_unix(NoContext, 1_497_624_121, 500_000_000);
+    ///
+    /// assert_eq!(Ok(Duration::from_millis(2_500)), updated.duration_since(&created));
+    /// assert_eq!(Err(Duration::from_millis(2_500)), created.duration_since(&updated));
+    /// ```
+    pub fn duration_since(&self, earlier: &Timestamp) -> Result<Duration, Duration> {
+        let (now, earlier) = (self.as_nanos(), earlier.as_nanos());
+
+        if now >= earlier {
+            Ok(Self::nanos_to_duration(now - earlier))
+        } else {
+            Err(Self::nanos_to_duration(earlier - now))
+        }
+    }
+
+    /// Round the timestamp down to the given precision.
+    ///
+    /// This is useful for UUIDs that are shown to end users, where the
+    /// precise time they were created shouldn't be revealed. UUIDs built
+    /// from truncated timestamps still sort by the coarser time. The
+    /// counter is kept, so UUIDs from the same context remain unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{timestamp::Precision, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1_497_624_119, 812_946_000);
+    ///
+    /// assert_eq!((1_497_624_119, 812_000_000), ts.truncated(Precision::Milliseconds).to_unix());
+    /// assert_eq!((1_497_621_600, 0), ts.truncated(Precision::Hours).to_unix());
+    /// ```
+    pub fn truncated(&self, precision: Precision) -> Self {
+        let nanos = self.as_nanos();
+        let unit = precision.as_nanos();
+
+        self.with_nanos(nanos - nanos % unit)
+    }
+
+    /// Round the timestamp down to the given precision, then move it to a
+    /// random time within that precision.
+    ///
+    /// Like [`Timestamp::truncated`], this hides the precise time a UUID was
+    /// created, but UUIDs built from jittered timestamps don't all share the
+    /// same time, so the precision isn't obvious from the UUIDs themselves.
+    /// UUIDs created within the same interval of the precision are no longer
+    /// ordered by the time they were created, but still sort after UUIDs
+    /// from earlier intervals.
+    ///
+    /// Note that you need to enable the `rng` Cargo feature in order to use
+    /// this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{timestamp::Precision, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1_497_624_119, 812_946_000);
+    ///
+    /// let (seconds, _) = ts.jittered(Precision::Minutes).to_unix();
+    ///
+    /// assert!((1_497_624_060..1_497_624_120).contains(&seconds));
+    /// ```
+    #[cfg(feature = "rng")]
+    pub fn jittered(&self, precision: Precision) -> Self {
+        let nanos = self.as_nanos();
+        let unit = precision.as_nanos();
+
+        let jitter = u128::from_be_bytes(crate::rng::bytes()) % unit;
+
+        self.with_nanos(nanos - nanos % unit + jitter)
+    }
+
+    fn as_nanos(&self) -> u128 {
+        self.seconds as u128 * 1_000_000_000 + self.nanos as u128
+    }
+
+    fn with_nanos(&self, nanos: u128) -> Self {
+        let seconds = nanos / 1_000_000_000;
+
+        // Unnormalized or jittered timestamps near the end of time can go
+        // past the latest one that fits, so they saturate to it
+        let (seconds, nanos) = if seconds > u64::MAX as u128 {
+            (u64::MAX, 999_999_999)
+        } else {
+            (seconds as u64, (nanos % 1_000_000_000) as u32)
+        };
+
+        Timestamp {
+            seconds,
+            nanos,
+            counter: self.counter,
+            usable_counter_bits: self.usable_counter_bits,
+        }
+    }
+
+    /// Parse a timestamp from an RFC3339 string, like `2017-06-16T14:41:59.812Z`.
+    ///
+    /// The string may have any offset from UTC, and up to nanosecond precision.
+    /// Digits beyond nanoseconds are truncated. Leap seconds aren't supported.
+    ///
+    /// The parsed timestamp has no counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Timestamp;
+    /// let ts = Timestamp::parse_rfc3339("2017-06-16T14:41:59.812+10:00")?;
+    ///
+    /// assert_eq!((1_497_588_119, 812_000_000), ts.to_unix());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[cfg(not(uuid_core_only))]
+    pub fn parse_rfc3339(input: &str) -> Result<Self, Error> {
+        let input = input.as_bytes();
+        let mut index = 0;
+
+        let year = rfc3339_digits(input, &mut index, 4)?;
+        rfc3339_byte(input, &mut index, b"-")?;
+
+        let month_index = index;
+        let month = rfc3339_digits(input, &mut index, 2)?;
+        if !(1..=12).contains(&month) {
+            return Err(rfc3339_error(month_index));
+        }
+        rfc3339_byte(input, &mut index, b"-")?;
+
+        let day_index = index;
+        let day = rfc3339_digits(input, &mut index, 2)?;
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(rfc3339_error(day_index));
+        }
+        rfc3339_byte(input, &mut index, b"Tt ")?;
+
+        let hour_index = index;
+        let hour = rfc3339_digits(input, &mut index, 2)?;
+        if hour > 23 {
+            return Err(rfc3339_error(hour_index));
+        }
+        rfc3339_byte(input, &mut index, b":")?;
+
+        let minute_index = index;
+        let minute = rfc3339_digits(input, &mut index, 2)?;
+        if minute > 59 {
+            return Err(rfc3339_error(minute_index));
+        }
+        rfc3339_byte(input, &mut index, b":")?;
+
+        let second_index = index;
+        let second = rfc3339_digits(input, &mut index, 2)?;
+        if second > 59 {
+            return Err(rfc3339_error(second_index));
+        }
+
+        let mut nanos = 0;
+        if input.get(index) == Some(&b'.') {
+            index += 1;
+
+            let fraction_index = index;
+            while let Some(digit @ b'0'..=b'9') = input.get(index) {
+                if index - fraction_index < 9 {
+                    nanos = nanos * 10 + (digit - b'0') as u32;
+                }
+
+                index += 1;
+            }
+
+            let digits = index - fraction_index;
+            if digits == 0 {
+                return Err(rfc3339_error(index));
+            } else if digits < 9 {
+                nanos *= 10u32.pow(9 - digits as u32);
+            }
+        }
+
+        let offset = match rfc3339_byte(input, &mut index, b"Zz+-")? {
+            b'Z' | b'z' => 0,
+            sign => {
+                let offset_index = index;
+                let hours = rfc3339_digits(input, &mut index, 2)?;
+                rfc3339_byte(input, &mut index, b":")?;
+                let minutes = rfc3339_digits(input, &mut index, 2)?;
+
+                if hours > 23 || minutes > 59 {
+                    return Err(rfc3339_error(offset_index));
+                }
+
+                let offset = (hours * 3_600 + minutes * 60) as i64;
+                if sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+        };
+
+        if index != input.len() {
+            return Err(rfc3339_error(index));
+        }
+
+        let seconds = days_from_civil(year, month, day) * 86_400
+            + (hour * 3_600 + minute * 60 + second) as i64
+            - offset;
+
+        if seconds < 0 {
+            return Err(Error(ErrorRepr::TimestampBeforeEpoch));
+        }
+
+        Ok(Timestamp {
+            seconds: seconds as u64,
+            nanos,
+            counter: 0,
+            usable_counter_bits: 0,
+        })
+    }
+
+    /// Format the timestamp as an RFC3339 string in UTC, like `2017-06-16T14:41:59.812Z`.
+    ///
+    /// Fractional seconds are written with millisecond, microsecond, or nanosecond
+    /// precision, whichever is the shortest that represents them exactly. They're
+    /// left out entirely when they're zero. The counter isn't included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1_497_624_119, 812_000_000);
+    ///
+    /// assert_eq!("2017-06-16T14:41:59.812Z", ts.to_rfc3339());
+    /// ```
+    #[cfg(all(feature = "alloc", not(uuid_core_only)))]
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    pub fn to_rfc3339(&self) -> alloc::string::String {
+        let seconds = self
+            .seconds
+            .saturating_add((self.nanos / 1_000_000_000) as u64);
+        let nanos = self.nanos % 1_000_000_000;
+
+        let (year, month, day) = civil_from_days(seconds / 86_400);
+        let time = seconds % 86_400;
+
+        let fraction = if nanos == 0 {
+            alloc::string::String::new()
+        } else if nanos % 1_000_000 == 0 {
+            alloc::format!(".{:03}", nanos / 1_000_000)
+        } else if nanos % 1_000 == 0 {
+            alloc::format!(".{:06}", nanos / 1_000)
+        } else {
+            alloc::format!(".{:09}", nanos)
+        };
+
+        alloc::format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+            year,
+            month,
+            day,
+            time / 3_600,
+            time % 3_600 / 60,
+            time % 60,
+            fraction
         )
     }
 
+    // The nanoseconds of a timestamp aren't guaranteed to be less than a second,
+    // so they're added separately rather than through `Duration::new`, which panics
+    fn as_duration(&self) -> Option<Duration> {
+        Duration::from_secs(self.seconds).checked_add(Duration::from_nanos(self.nanos as u64))
+    }
+
+    fn with_duration(&self, duration: Duration) -> Self {
+        Timestamp {
+            seconds: duration.as_secs(),
+            nanos: duration.subsec_nanos(),
+            counter: self.counter,
+            usable_counter_bits: self.usable_counter_bits,
+        }
+    }
+
+    // Unnormalized timestamps can be further apart than `Duration::MAX`, so this saturates
+    fn nanos_to_duration(nanos: u128) -> Duration {
+        let seconds = nanos / 1_000_000_000;
+
+        if seconds > u64::MAX as u128 {
+            Duration::MAX
+        } else {
+            Duration::new(seconds as u64, (nanos % 1_000_000_000) as u32)
+        }
+    }
+
+    const fn unix_to_rfc4122_ticks(seconds: u64, nanos: u32) -> u64 {
+        UUID_TICKS_BETWEEN_EPOCHS
+            .saturating_add(seconds.saturating_mul(10_000_000))
+            .saturating_add(nanos as u64 / 100)
+    }
+
+    // Ticks before the Unix epoch saturate to it
+    const fn rfc4122_to_unix(ticks: u64) -> (u64, u32) {
+        let ticks = ticks.saturating_sub(UUID_TICKS_BETWEEN_EPOCHS);
+
+        (ticks / 10_000_000, (ticks % 10_000_000) as u32 * 100)
+    }
+
     #[deprecated(note = "use `to_unix` instead; this method will be removed in a future release")]
     /// Get the number of fractional nanoseconds in the Unix timestamp.
     ///
@@ -170,6 +745,84 @@ impl Timestamp {
     }
 }
 
+#[cfg(not(uuid_core_only))]
+fn rfc3339_error(index: usize) -> Error {
+    Error(ErrorRepr::Rfc3339 { index: index + 1 })
+}
+
+// Parse exactly `len` ASCII digits
+#[cfg(not(uuid_core_only))]
+fn rfc3339_digits(input: &[u8], index: &mut usize, len: usize) -> Result<u64, Error> {
+    let mut value = 0;
+
+    for _ in 0..len {
+        match input.get(*index) {
+            Some(digit @ b'0'..=b'9') => value = value * 10 + (digit - b'0') as u64,
+            _ => return Err(rfc3339_error(*index)),
+        }
+
+        *index += 1;
+    }
+
+    Ok(value)
+}
+
+// Parse one of the `expected` bytes
+#[cfg(not(uuid_core_only))]
+fn rfc3339_byte(input: &[u8], index: &mut usize, expected: &[u8]) -> Result<u8, Error> {
+    match input.get(*index) {
+        Some(byte) if expected.contains(byte) => {
+            *index += 1;
+            Ok(*byte)
+        }
+        _ => Err(rfc3339_error(*index)),
+    }
+}
+
+#[cfg(not(uuid_core_only))]
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The number of days between the Unix epoch and a date in the proleptic Gregorian calendar
+// See: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+#[cfg(any(
+    not(uuid_core_only),
+    all(feature = "uefi", target_os = "uefi", not(feature = "std"))
+))]
+fn days_from_civil(year: u64, month: u64, day: u64) -> i64 {
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+// The date in the proleptic Gregorian calendar a number of days after the Unix epoch
+// See: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+#[cfg(all(feature = "alloc", not(uuid_core_only)))]
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = (month_from_march + 2) % 12 + 1;
+
+    (era * 400 + year_of_era + (month <= 2) as u64, month, day)
+}
+
 pub(crate) const fn encode_rfc4122_timestamp(ticks: u64, counter: u16, node_id: &[u8; 6]) -> Uuid {
     let time_low = (ticks & 0xFFFF_FFFF) as u32;
     let time_mid = ((ticks >> 32) & 0xFFFF) as u16;
@@ -189,6 +842,30 @@ pub(crate) const fn encode_rfc4122_timestamp(ticks: u64, counter: u16, node_id:
     Uuid::from_fields(time_low, time_mid, time_high_and_version, &d4)
 }
 
+pub(crate) const fn encode_dce_timestamp(
+    domain: u8,
+    local_id: u32,
+    ticks: u64,
+    counter: u16,
+    node_id: &[u8; 6],
+) -> Uuid {
+    let time_mid = ((ticks >> 32) & 0xFFFF) as u16;
+    let time_high_and_version = (((ticks >> 48) & 0x0FFF) as u16) | (2 << 12);
+
+    let mut d4 = [0; 8];
+
+    d4[0] = ((counter & 0x3F) as u8) | 0x80;
+    d4[1] = domain;
+    d4[2] = node_id[0];
+    d4[3] = node_id[1];
+    d4[4] = node_id[2];
+    d4[5] = node_id[3];
+    d4[6] = node_id[4];
+    d4[7] = node_id[5];
+
+    Uuid::from_fields(local_id, time_mid, time_high_and_version, &d4)
+}
+
 pub(crate) const fn decode_rfc4122_timestamp(uuid: &Uuid) -> (u64, u16) {
     let bytes = uuid.as_bytes();
 
@@ -270,6 +947,32 @@ pub(crate) const fn encode_unix_timestamp_millis(millis: u64, random_bytes: &[u8
     Uuid::from_fields(millis_high, millis_low, random_and_version, &d4)
 }
 
+pub(crate) const fn encode_comb_timestamp_millis(millis: u64, random_bytes: &[u8; 10]) -> Uuid {
+    let millis_high = ((millis >> 32) & 0xFFFF) as u16;
+    let millis_low = (millis & 0xFFFF_FFFF) as u32;
+
+    let d1 = (random_bytes[0] as u32) << 24
+        | (random_bytes[1] as u32) << 16
+        | (random_bytes[2] as u32) << 8
+        | (random_bytes[3] as u32);
+    let d2 = (random_bytes[4] as u16) << 8 | (random_bytes[5] as u16);
+    let random_and_version =
+        (random_bytes[7] as u16 | ((random_bytes[6] as u16) << 8) & 0x0FFF) | (0x4 << 12);
+
+    let mut d4 = [0; 8];
+
+    d4[0] = (random_bytes[8] & 0x3F) | 0x80;
+    d4[1] = random_bytes[9];
+    d4[2] = (millis_high >> 8) as u8;
+    d4[3] = millis_high as u8;
+    d4[4] = (millis_low >> 24) as u8;
+    d4[5] = (millis_low >> 16) as u8;
+    d4[6] = (millis_low >> 8) as u8;
+    d4[7] = millis_low as u8;
+
+    Uuid::from_fields(d1, d2, random_and_version, &d4)
+}
+
 #[cfg(uuid_unstable)]
 pub(crate) const fn decode_unix_timestamp_millis(uuid: &Uuid) -> u64 {
     let bytes = uuid.as_bytes();
@@ -284,26 +987,84 @@ pub(crate) const fn decode_unix_timestamp_millis(uuid: &Uuid) -> u64 {
     millis
 }
 
-#[cfg(all(
+#[cfg(any(
     feature = "std",
-    feature = "js",
-    all(
-        target_arch = "wasm32",
-        target_vendor = "unknown",
-        target_os = "unknown"
-    )
+    all(feature = "uefi", target_os = "uefi"),
+    feature = "custom-clock"
 ))]
 fn now() -> (u64, u32) {
-    use wasm_bindgen::prelude::*;
+    #[cfg(feature = "test-util")]
+    {
+        if let Some(now) = crate::test_util::frozen_now() {
+            return now;
+        }
+    }
 
-    #[wasm_bindgen]
-    extern "C" {
-        // NOTE: This signature works around https://bugzilla.mozilla.org/show_bug.cgi?id=1787770
+    #[cfg(feature = "custom-clock")]
+    {
+        if let Some(clock) = clock::registered_clock() {
+            return clock();
+        }
+    }
+
+    system_now()
+}
+
+#[cfg(all(
+    feature = "custom-clock",
+    not(feature = "std"),
+    not(all(feature = "uefi", target_os = "uefi"))
+))]
+fn system_now() -> (u64, u32) {
+    panic!("no clock has been registered for uuid; call `uuid::timestamp::clock::register_clock` first")
+}
+
+#[cfg(all(
+    feature = "std",
+    feature = "js",
+    all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ),
+    not(feature = "ic")
+))]
+fn system_now() -> (u64, u32) {
+    use std::cell::Cell;
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        // NOTE: This signature works around https://bugzilla.mozilla.org/show_bug.cgi?id=1787770
         #[wasm_bindgen(js_namespace = Date, catch)]
         fn now() -> Result<f64, JsValue>;
+
+        #[wasm_bindgen(js_namespace = performance, js_name = now, catch)]
+        fn performance_now() -> Result<f64, JsValue>;
+    }
+
+    thread_local! {
+        // The Unix time in milliseconds when `performance.now()` started counting
+        static ORIGIN: Cell<Option<f64>> = Cell::new(None);
     }
 
-    let now = now().unwrap_throw();
+    // `Date.now()` only has millisecond precision, so if the high resolution
+    // `performance.now()` is available then it's used to fill in the rest
+    let now = match performance_now() {
+        Ok(elapsed) => {
+            let origin = ORIGIN.with(|origin| match origin.get() {
+                Some(origin) => origin,
+                None => {
+                    let start = now().unwrap_throw() - elapsed;
+                    origin.set(Some(start));
+                    start
+                }
+            });
+
+            origin + elapsed
+        }
+        Err(_) => now().unwrap_throw(),
+    };
 
     let secs = (now / 1_000.0) as u64;
     let nanos = ((now % 1_000.0) * 1_000_000.0) as u32;
@@ -320,9 +1081,10 @@ fn now() -> (u64, u32) {
             target_vendor = "unknown",
             target_os = "unknown"
         ))
-    )
+    ),
+    not(all(feature = "ic", target_arch = "wasm32"))
 ))]
-fn now() -> (u64, u32) {
+fn system_now() -> (u64, u32) {
     let dur = std::time::SystemTime::UNIX_EPOCH.elapsed().expect(
         "Getting elapsed time since UNIX_EPOCH. If this fails, we've somehow violated causality",
     );
@@ -330,12 +1092,77 @@ fn now() -> (u64, u32) {
     (dur.as_secs(), dur.subsec_nanos())
 }
 
+#[cfg(all(feature = "ic", target_arch = "wasm32"))]
+fn system_now() -> (u64, u32) {
+    // The time is fixed for the duration of a message, so UUIDs generated
+    // while handling the same message rely on their context to stay unique
+    let nanos = ic_cdk::api::time();
+
+    (nanos / 1_000_000_000, (nanos % 1_000_000_000) as u32)
+}
+
+#[cfg(all(feature = "uefi", target_os = "uefi", not(feature = "std")))]
+fn system_now() -> (u64, u32) {
+    let time = uefi_rs::runtime::get_time()
+        .unwrap_or_else(|err| panic!("could not retrieve the current time for uuid: {}", err));
+
+    let mut seconds = days_from_civil(time.year() as u64, time.month() as u64, time.day() as u64)
+        * 86_400
+        + time.hour() as i64 * 3_600
+        + time.minute() as i64 * 60
+        + time.second() as i64;
+
+    // UEFI clocks may be kept in local time, where `local = UTC - time_zone`
+    if let Some(time_zone) = time.time_zone() {
+        seconds += time_zone as i64 * 60;
+    }
+
+    (seconds as u64, time.nanosecond())
+}
+
+/// The precision to round a [`Timestamp`] to.
+///
+/// See [`Timestamp::truncated`] and [`Timestamp::jittered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Precision {
+    /// Whole milliseconds, which is the precision of a version 7 UUID.
+    Milliseconds,
+    /// Whole seconds.
+    Seconds,
+    /// Whole minutes.
+    Minutes,
+    /// Whole hours.
+    Hours,
+    /// Whole days, starting at midnight UTC.
+    Days,
+}
+
+impl Precision {
+    const fn as_nanos(&self) -> u128 {
+        match self {
+            Precision::Milliseconds => 1_000_000,
+            Precision::Seconds => 1_000_000_000,
+            Precision::Minutes => 60 * 1_000_000_000,
+            Precision::Hours => 60 * 60 * 1_000_000_000,
+            Precision::Days => 24 * 60 * 60 * 1_000_000_000,
+        }
+    }
+}
+
 /// A counter that can be used by version 1 and version 6 UUIDs to support
 /// the uniqueness of timestamps.
 ///
+/// Version 7 UUIDs can also use a counter to keep UUIDs generated with the
+/// same timestamp in order. The counter fills the bits after the version,
+/// starting with its most significant usable bit and skipping over the
+/// variant, so up to 74 bits of it can be used. Any bits it doesn't fill
+/// are random.
+///
 /// # References
 ///
 /// * [Clock Sequence in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.1.5)
+/// * [Monotonicity and Counters in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-6.2)
 pub trait ClockSequence {
     /// The type of sequence returned by this counter.
     type Output;
@@ -344,6 +1171,81 @@ pub trait ClockSequence {
     ///
     /// This method will be called each time a [`Timestamp`] is constructed.
     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output;
+
+    /// Get the next value in the sequence, along with the timestamp to use
+    /// with it.
+    ///
+    /// This method will be called each time a [`Timestamp`] is constructed
+    /// instead of [`ClockSequence::generate_sequence`]. It can be overridden
+    /// by contexts that need to adjust the time as well as the counter, like
+    /// holding the time steady while the counter increments, or moving the
+    /// time forward when the counter runs out. The default implementation
+    /// returns the time unchanged.
+    ///
+    /// # Examples
+    ///
+    /// A context that never lets the time go backwards:
+    ///
+    /// ```
+    /// # use std::cell::Cell;
+    /// # use uuid::{ClockSequence, Timestamp};
+    /// struct Monotonic {
+    ///     last: Cell<(u64, u32)>,
+    ///     counter: Cell<u16>,
+    /// }
+    ///
+    /// impl ClockSequence for Monotonic {
+    ///     type Output = u16;
+    ///
+    ///     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> u16 {
+    ///         self.generate_timestamp_sequence(seconds, subsec_nanos).0
+    ///     }
+    ///
+    ///     fn generate_timestamp_sequence(&self, seconds: u64, subsec_nanos: u32) -> (u16, u64, u32) {
+    ///         let (seconds, subsec_nanos) = self.last.get().max((seconds, subsec_nanos));
+    ///         self.last.set((seconds, subsec_nanos));
+    ///
+    ///         let counter = self.counter.get().wrapping_add(1);
+    ///         self.counter.set(counter);
+    ///
+    ///         (counter, seconds, subsec_nanos)
+    ///     }
+    /// }
+    ///
+    /// let context = Monotonic {
+    ///     last: Cell::new((0, 0)),
+    ///     counter: Cell::new(0),
+    /// };
+    ///
+    /// let a = Timestamp::from_unix(&context, 1_497_624_119, 0);
+    /// let b = Timestamp::from_unix(&context, 1_497_624_118, 0);
+    ///
+    /// assert_eq!(a.to_unix(), b.to_unix());
+    /// assert!(a < b);
+    /// ```
+    fn generate_timestamp_sequence(
+        &self,
+        seconds: u64,
+        subsec_nanos: u32,
+    ) -> (Self::Output, u64, u32) {
+        (
+            self.generate_sequence(seconds, subsec_nanos),
+            seconds,
+            subsec_nanos,
+        )
+    }
+
+    /// The number of usable bits in each value returned by
+    /// [`ClockSequence::generate_sequence`], counting from the least
+    /// significant bit.
+    ///
+    /// Bits above these are masked off when the value is encoded. This
+    /// method should always return the same value, and that value shouldn't
+    /// be more than 128. The default is 0, so a context's output is only
+    /// encoded into version 7 UUIDs when it opts in by overriding this method.
+    fn usable_bits(&self) -> usize {
+        0
+    }
 }
 
 impl<'a, T: ClockSequence + ?Sized> ClockSequence for &'a T {
@@ -351,15 +1253,483 @@ impl<'a, T: ClockSequence + ?Sized> ClockSequence for &'a T {
     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
         (**self).generate_sequence(seconds, subsec_nanos)
     }
+
+    fn generate_timestamp_sequence(
+        &self,
+        seconds: u64,
+        subsec_nanos: u32,
+    ) -> (Self::Output, u64, u32) {
+        (**self).generate_timestamp_sequence(seconds, subsec_nanos)
+    }
+
+    fn usable_bits(&self) -> usize {
+        (**self).usable_bits()
+    }
+}
+
+/// A source of the current time for timestamps.
+///
+/// Implement this trait to supply the time to [`Timestamp::now_with`] from
+/// somewhere other than the system clock.
+pub trait ClockSource {
+    /// Get the current time as the number of seconds and fractional
+    /// nanoseconds since the Unix epoch.
+    fn now(&self) -> (u64, u32);
+
+    /// Get the time scale that the clock keeps time in.
+    ///
+    /// Times read from the clock are converted from this time scale into
+    /// Unix time before they're used in a timestamp. The default
+    /// implementation returns [`TimeScale::Unix`].
+    fn time_scale(&self) -> TimeScale {
+        TimeScale::Unix
+    }
+}
+
+impl<T: ClockSource + ?Sized> ClockSource for &T {
+    fn now(&self) -> (u64, u32) {
+        (**self).now()
+    }
+
+    fn time_scale(&self) -> TimeScale {
+        (**self).time_scale()
+    }
+}
+
+/// The time scale that a [`ClockSource`] keeps time in.
+///
+/// Timestamps in UUIDs are always in Unix time, where every day has exactly
+/// 86400 seconds. Clocks that handle leap seconds differently need their
+/// time converted, so UUIDs decode back to the correct wall time.
+///
+/// Conversions use the leap seconds announced up to the end of 2016.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeScale {
+    /// Unix time, which ignores leap seconds.
+    ///
+    /// This is what the system clock uses on most platforms.
+    Unix,
+    /// International Atomic Time (TAI), counted from `1970-01-01 00:00:00` TAI.
+    ///
+    /// TAI counts every leap second, so it runs ahead of Unix time by 10
+    /// seconds plus the number of leap seconds so far. Times within a leap
+    /// second are converted to the end of the second before it.
+    Tai,
+    /// UTC with Smoothed Leap Seconds (UTC-SLS).
+    ///
+    /// Clocks that use UTC-SLS slow down over the 1000 seconds before a leap
+    /// second, so it's spread out over that time instead of being inserted
+    /// all at once. Times within a smear are converted to the real time they
+    /// were read at.
+    UtcSls,
+}
+
+impl TimeScale {
+    /// Convert a time in this time scale into Unix time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::timestamp::TimeScale;
+    /// // TAI was 37 seconds ahead of Unix time after 2017
+    /// assert_eq!((1_497_624_119, 1234), TimeScale::Tai.to_unix(1_497_624_156, 1234));
+    /// ```
+    pub fn to_unix(&self, seconds: u64, nanos: u32) -> (u64, u32) {
+        match self {
+            TimeScale::Unix => (seconds, nanos),
+            TimeScale::Tai => {
+                let mut offset = 10;
+
+                for &(leap, next_offset) in LEAP_SECONDS.iter() {
+                    if seconds < leap + offset {
+                        break;
+                    } else if seconds < leap + next_offset {
+                        // The leap second itself doesn't exist in Unix time
+                        return (leap - 1, 999_999_999);
+                    }
+
+                    offset = next_offset;
+                }
+
+                match seconds.checked_sub(offset) {
+                    Some(seconds) => (seconds, nanos),
+                    None => (0, 0),
+                }
+            }
+            TimeScale::UtcSls => {
+                let leap = LEAP_SECONDS
+                    .iter()
+                    .map(|&(leap, _)| leap)
+                    .find(|&leap| seconds >= leap - UTC_SLS_SMEAR && seconds < leap);
+
+                match leap {
+                    Some(leap) => {
+                        // The clock ran 1000 seconds while 1001 really passed
+                        let start = (leap - UTC_SLS_SMEAR) as u128 * 1_000_000_000;
+                        let smeared = seconds as u128 * 1_000_000_000 + nanos as u128 - start;
+                        let real =
+                            start + smeared * (UTC_SLS_SMEAR as u128 + 1) / UTC_SLS_SMEAR as u128;
+
+                        if real >= leap as u128 * 1_000_000_000 {
+                            (leap - 1, 999_999_999)
+                        } else {
+                            ((real / 1_000_000_000) as u64, (real % 1_000_000_000) as u32)
+                        }
+                    }
+                    None => (seconds, nanos),
+                }
+            }
+        }
+    }
+}
+
+// The number of seconds before a leap second that UTC-SLS smears it over
+const UTC_SLS_SMEAR: u64 = 1_000;
+
+// The Unix time at the end of each leap second, and the TAI-UTC offset after it
+const LEAP_SECONDS: [(u64, u64); 27] = [
+    (78_796_800, 11),    // 1972-07-01
+    (94_694_400, 12),    // 1973-01-01
+    (126_230_400, 13),   // 1974-01-01
+    (157_766_400, 14),   // 1975-01-01
+    (189_302_400, 15),   // 1976-01-01
+    (220_924_800, 16),   // 1977-01-01
+    (252_460_800, 17),   // 1978-01-01
+    (283_996_800, 18),   // 1979-01-01
+    (315_532_800, 19),   // 1980-01-01
+    (362_793_600, 20),   // 1981-07-01
+    (394_329_600, 21),   // 1982-07-01
+    (425_865_600, 22),   // 1983-07-01
+    (489_024_000, 23),   // 1985-07-01
+    (567_993_600, 24),   // 1988-01-01
+    (631_152_000, 25),   // 1990-01-01
+    (662_688_000, 26),   // 1991-01-01
+    (709_948_800, 27),   // 1992-07-01
+    (741_484_800, 28),   // 1993-07-01
+    (773_020_800, 29),   // 1994-07-01
+    (820_454_400, 30),   // 1996-01-01
+    (867_715_200, 31),   // 1997-07-01
+    (915_148_800, 32),   // 1999-01-01
+    (1_136_073_600, 33), // 2006-01-01
+    (1_230_768_000, 34), // 2009-01-01
+    (1_341_100_800, 35), // 2012-07-01
+    (1_435_708_800, 36), // 2015-07-01
+    (1_483_228_800, 37), // 2017-01-01
+];
+
+/// Default implementations for the [`ClockSource`] trait.
+pub mod clock {
+    #[cfg(any(
+        feature = "std",
+        all(feature = "uefi", target_os = "uefi"),
+        feature = "custom-clock"
+    ))]
+    use super::ClockSource;
+
+    #[cfg(feature = "custom-clock")]
+    use atomic::{Atomic, Ordering};
+
+    #[cfg(feature = "custom-clock")]
+    type ClockFn = fn() -> (u64, u32);
+
+    #[cfg(feature = "custom-clock")]
+    static CLOCK: Atomic<Option<ClockFn>> = Atomic::new(None);
+
+    /// Register the clock used by [`Timestamp::now`](super::Timestamp::now)
+    /// and the `now_*` methods on [`Uuid`](crate::Uuid).
+    ///
+    /// This is useful on embedded targets without the standard library, where
+    /// there's no system clock to read the time from. The `clock` function
+    /// must return the current time as a number of seconds and subsecond
+    /// nanoseconds since the Unix epoch, like from a real-time clock.
+    ///
+    /// A registered clock also takes priority over the system clock on
+    /// targets that have one. Registering another clock replaces the
+    /// previous one.
+    ///
+    /// Note that you need to enable the `custom-clock` Cargo feature
+    /// in order to use this function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{timestamp::clock, Timestamp, NoContext};
+    /// fn read_rtc() -> (u64, u32) {
+    ///     (1_497_624_119, 1234)
+    /// }
+    ///
+    /// clock::register_clock(read_rtc);
+    ///
+    /// let ts = Timestamp::now(NoContext);
+    ///
+    /// assert_eq!((1_497_624_119, 1234), ts.to_unix());
+    /// ```
+    #[cfg(feature = "custom-clock")]
+    pub fn register_clock(clock: fn() -> (u64, u32)) {
+        CLOCK.store(Some(clock), Ordering::Release);
+    }
+
+    #[cfg(feature = "custom-clock")]
+    pub(super) fn registered_clock() -> Option<ClockFn> {
+        CLOCK.load(Ordering::Acquire)
+    }
+
+    /// A clock that reads the current system time.
+    ///
+    /// This is the clock used by [`Timestamp::now`](super::Timestamp::now).
+    /// It defers to the standard library's `SystemTime` type, or to UEFI
+    /// Runtime Services when the `uefi` feature is enabled on a UEFI target
+    /// without the standard library. When the `custom-clock` feature is
+    /// enabled, it reads the clock passed to [`register_clock`] instead, if
+    /// there is one.
+    ///
+    /// # Panics
+    ///
+    /// Reading the time will panic if calculating the elapsed time since the
+    /// Unix epoch fails, or if the `custom-clock` feature is enabled on a
+    /// target without a system clock and no clock has been registered.
+    #[derive(Debug, Clone, Copy, Default)]
+    #[cfg(any(
+        feature = "std",
+        all(feature = "uefi", target_os = "uefi"),
+        feature = "custom-clock"
+    ))]
+    pub struct SystemClock;
+
+    #[cfg(any(
+        feature = "std",
+        all(feature = "uefi", target_os = "uefi"),
+        feature = "custom-clock"
+    ))]
+    impl ClockSource for SystemClock {
+        fn now(&self) -> (u64, u32) {
+            super::now()
+        }
+    }
+
+    /// A clock that reads the current system time in the background.
+    ///
+    /// Reading the system time on every call can dominate hot paths that
+    /// generate millions of UUIDs. This clock reads the time on a background
+    /// thread once every `interval` instead, and reading it only loads the
+    /// last value. The trade-off is that the time it returns can be up to
+    /// `interval` behind the system time, so more UUIDs share the same
+    /// timestamp. Those UUIDs are still unique, and use a counter to keep
+    /// them in order if their context has one.
+    ///
+    /// The background thread stops once every clone of the clock has been
+    /// dropped.
+    ///
+    /// Note that you need to enable the `std` Cargo feature in order to use
+    /// this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::{timestamp::clock::CoarseClock, Timestamp, NoContext};
+    /// let clock = CoarseClock::new(Duration::from_micros(100));
+    ///
+    /// let ts = Timestamp::now_with(&clock, NoContext);
+    /// ```
+    #[derive(Debug, Clone)]
+    #[cfg(feature = "std")]
+    pub struct CoarseClock {
+        // The last time read, in nanoseconds since the Unix epoch
+        nanos: std::sync::Arc<std::sync::atomic::AtomicU64>,
+    }
+
+    #[cfg(feature = "std")]
+    impl CoarseClock {
+        /// Start a clock that reads the system time every `interval`.
+        ///
+        /// # Panics
+        ///
+        /// This method will panic if the background thread can't be spawned.
+        pub fn new(interval: std::time::Duration) -> Self {
+            use std::sync::{atomic::Ordering, Arc};
+
+            let nanos = Arc::new(std::sync::atomic::AtomicU64::new(Self::read()));
+            let background = Arc::downgrade(&nanos);
+
+            std::thread::Builder::new()
+                .name("uuid-coarse-clock".into())
+                .spawn(move || {
+                    while let Some(nanos) = background.upgrade() {
+                        nanos.store(Self::read(), Ordering::Release);
+                        drop(nanos);
+
+                        std::thread::sleep(interval);
+                    }
+                })
+                .expect("failed to spawn the background thread for uuid's coarse clock");
+
+            CoarseClock { nanos }
+        }
+
+        // Nanoseconds since the Unix epoch overflow a `u64` in the year 2554
+        fn read() -> u64 {
+            let (seconds, nanos) = super::now();
+
+            seconds
+                .saturating_mul(1_000_000_000)
+                .saturating_add(nanos as u64)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl ClockSource for CoarseClock {
+        fn now(&self) -> (u64, u32) {
+            let nanos = self.nanos.load(std::sync::atomic::Ordering::Acquire);
+
+            (nanos / 1_000_000_000, (nanos % 1_000_000_000) as u32)
+        }
+    }
 }
 
 /// Default implementations for the [`ClockSequence`] trait.
 pub mod context {
     use super::ClockSequence;
 
-    #[cfg(any(feature = "v1", feature = "v6"))]
+    #[cfg(all(
+        any(feature = "v1", feature = "v6", all(uuid_unstable, feature = "v7")),
+        not(all(target_family = "wasm", not(target_feature = "atomics")))
+    ))]
     use atomic::{Atomic, Ordering};
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v6", all(uuid_unstable, feature = "v7")),
+        target_family = "wasm",
+        not(target_feature = "atomics")
+    ))]
+    use self::wasm::{Atomic, Ordering};
+
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    use crate::std::cmp;
+
+    // Single-threaded WebAssembly has no way to share a context between threads,
+    // so plain cells are used instead of atomics. This avoids pulling in atomic
+    // shims and keeps startup cheap in the browser.
+    #[cfg(all(
+        any(feature = "v1", feature = "v6", all(uuid_unstable, feature = "v7")),
+        target_family = "wasm",
+        not(target_feature = "atomics")
+    ))]
+    mod wasm {
+        use core::{cell::Cell, fmt};
+
+        pub(super) use core::sync::atomic::Ordering;
+
+        pub(super) struct Atomic<T>(Cell<T>);
+
+        // SAFETY: Without the `atomics` target feature there's only ever a single thread
+        unsafe impl<T> Sync for Atomic<T> {}
+
+        impl<T: Copy + PartialEq> Atomic<T> {
+            pub(super) const fn new(value: T) -> Self {
+                Atomic(Cell::new(value))
+            }
+
+            pub(super) fn load(&self, _: Ordering) -> T {
+                self.0.get()
+            }
+
+            #[cfg(any(feature = "v1", feature = "v6", all(uuid_unstable, feature = "v7")))]
+            pub(super) fn store(&self, value: T, _: Ordering) {
+                self.0.set(value);
+            }
+
+            pub(super) fn compare_exchange(
+                &self,
+                current: T,
+                new: T,
+                _: Ordering,
+                _: Ordering,
+            ) -> Result<T, T> {
+                let value = self.0.get();
+
+                if value == current {
+                    self.0.set(new);
+                    Ok(value)
+                } else {
+                    Err(value)
+                }
+            }
+        }
+
+        #[cfg(any(feature = "v1", feature = "v6"))]
+        impl Atomic<u16> {
+            pub(super) fn fetch_add(&self, value: u16, _: Ordering) -> u16 {
+                let prev = self.0.get();
+                self.0.set(prev.wrapping_add(value));
+
+                prev
+            }
+        }
+
+        impl<T: Copy + fmt::Debug> fmt::Debug for Atomic<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0.get(), f)
+            }
+        }
+    }
+
+    // A lock around state that's too wide to update with a single atomic
+    //
+    // The lock is only held while the state is read and updated, so threads
+    // waiting for it spin instead of parking.
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    struct SpinLock<T> {
+        locked: Atomic<bool>,
+        value: crate::std::cell::UnsafeCell<T>,
+    }
+
+    // SAFETY: The value is only accessed by the thread holding the lock
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl<T> SpinLock<T> {
+        const fn new(value: T) -> Self {
+            SpinLock {
+                locked: Atomic::new(false),
+                value: crate::std::cell::UnsafeCell::new(value),
+            }
+        }
+
+        fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+            struct Unlock<'a>(&'a Atomic<bool>);
+
+            impl Drop for Unlock<'_> {
+                fn drop(&mut self) {
+                    self.0.store(false, Ordering::Release);
+                }
+            }
+
+            while self
+                .locked
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                crate::std::hint::spin_loop();
+            }
+
+            // Release the lock even if `f` panics
+            let _unlock = Unlock(&self.locked);
+
+            // SAFETY: The lock is held until `_unlock` is dropped
+            f(unsafe { &mut *self.value.get() })
+        }
+    }
+
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl<T: crate::std::fmt::Debug> crate::std::fmt::Debug for SpinLock<T> {
+        fn fmt(&self, f: &mut crate::std::fmt::Formatter<'_>) -> crate::std::fmt::Result {
+            self.with(|value| crate::std::fmt::Debug::fmt(value, f))
+        }
+    }
+
     /// An empty counter that will always return the value `0`.
     ///
     /// This type should be used when constructing timestamps for version 7 UUIDs,
@@ -373,62 +1743,399 @@ pub mod context {
         fn generate_sequence(&self, _seconds: u64, _nanos: u32) -> Self::Output {
             0
         }
+
+        fn usable_bits(&self) -> usize {
+            0
+        }
     }
 
-    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
-    static CONTEXT: Context = Context {
-        count: Atomic::new(0),
-    };
+    /// A counter that encodes the fraction of a millisecond in a timestamp.
+    ///
+    /// This type implements the increased clock precision method from
+    /// RFC 9562. Version 7 UUIDs only have millisecond precision in their
+    /// timestamp, so this context fills the 12 bits of `rand_a` with the
+    /// sub-millisecond fraction instead of random data. UUIDs generated
+    /// from timestamps at least 245ns apart will sort in the order of
+    /// those timestamps without needing to share a counter.
+    ///
+    /// UUIDs generated from timestamps in the same ~244ns interval are only
+    /// ordered by their random bits. Use [`ContextV7`] when UUIDs need to be
+    /// strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, SubMillisecondContext};
+    /// let first = Uuid::new_v7(Timestamp::from_unix(SubMillisecondContext, 1_497_624_119, 1_250_000));
+    /// let second = Uuid::new_v7(Timestamp::from_unix(SubMillisecondContext, 1_497_624_119, 1_500_000));
+    ///
+    /// assert!(first < second);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-6.2)
+    #[derive(Debug, Clone, Copy, Default)]
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    pub struct SubMillisecondContext;
 
-    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
-    static CONTEXT_INITIALIZED: Atomic<bool> = Atomic::new(false);
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl ClockSequence for SubMillisecondContext {
+        type Output = u16;
 
-    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
-    pub(crate) fn shared_context() -> &'static Context {
-        // If the context is in its initial state then assign it to a random value
-        // It doesn't matter if multiple threads observe `false` here and initialize the context
-        if CONTEXT_INITIALIZED
-            .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
-            .is_ok()
-        {
-            CONTEXT.count.store(crate::rng::u16(), Ordering::Release);
+        fn generate_sequence(&self, _seconds: u64, nanos: u32) -> Self::Output {
+            // Scale the nanoseconds within the millisecond to 12 bits
+            ((nanos % 1_000_000) as u64 * 4096 / 1_000_000) as u16
         }
 
-        &CONTEXT
+        fn usable_bits(&self) -> usize {
+            12
+        }
     }
 
-    /// A thread-safe, wrapping counter that produces 14-bit numbers.
+    /// A thread-safe counter with up to 64 bits for version 7 UUIDs.
     ///
-    /// This type should be used when constructing version 1 and version 6 UUIDs.
+    /// Version 7 UUIDs have 74 bits after their millisecond timestamp that
+    /// can hold a counter. A [`ContextV7`] only uses the 12 bits of `rand_a`,
+    /// so it can produce 4096 strictly increasing UUIDs per millisecond on
+    /// average. This context uses a wider counter that can spill over into
+    /// `rand_b`, so the rest of the UUID is still random, but many more
+    /// UUIDs can be strictly ordered within the same millisecond.
+    ///
+    /// The counter is reseeded with a random value whenever the millisecond
+    /// changes. The highest bit of the seed is always clear, so a counter of
+    /// `n` bits can be incremented at least `2^(n-1)` times in a millisecond.
+    /// After that the counter stays at its maximum value, so UUIDs are only
+    /// ordered by their random bits until the next millisecond. Timestamps
+    /// earlier than the last one the context saw keep incrementing the
+    /// counter from where it was.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, WideCounterContext};
+    /// let context = WideCounterContext::new(42);
+    ///
+    /// let first = Uuid::new_v7(Timestamp::from_unix(&context, 1_497_624_119, 0));
+    /// let second = Uuid::new_v7(Timestamp::from_unix(&context, 1_497_624_119, 0));
+    ///
+    /// assert!(first < second);
+    /// ```
+    ///
+    /// # Choosing a counter width
+    ///
+    /// Every bit given to the counter is taken from the random bits of the
+    /// UUID. A wider counter can strictly order more UUIDs within the same
+    /// millisecond, but leaves fewer random bits to keep UUIDs from other
+    /// generators from colliding. As a rough guide, pick a counter that's
+    /// a few bits wider than the number of UUIDs a single context generates
+    /// per millisecond at its peak, and leave the rest random:
+    ///
+    /// | `counter_bits` | UUIDs per millisecond | Random bits |
+    /// |----------------|-----------------------|-------------|
+    /// | 12             | at least 2,048        | 62          |
+    /// | 24             | at least 8 million    | 50          |
+    /// | 42 (default)   | at least 2 trillion   | 32          |
+    /// | 64             | at least 2^63         | 10          |
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-6.2)
     #[derive(Debug)]
-    #[cfg(any(feature = "v1", feature = "v6"))]
-    pub struct Context {
-        count: Atomic<u16>,
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    pub struct WideCounterContext {
+        // The last millisecond timestamp and counter used, which together are
+        // too wide for a lock-free atomic on most targets
+        last: SpinLock<(u64, u64)>,
+        counter_bits: u8,
     }
 
-    #[cfg(any(feature = "v1", feature = "v6"))]
-    impl Context {
-        /// Construct a new context that's initialized with the given value.
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl Default for WideCounterContext {
+        fn default() -> Self {
+            Self::new(42)
+        }
+    }
+
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl WideCounterContext {
+        /// Construct a new context with a counter of the given number of bits.
         ///
-        /// The starting value should be a random number, so that UUIDs from
-        /// different systems with the same timestamps are less likely to collide.
-        /// When the `rng` feature is enabled, prefer the [`Context::new_random`] method.
-        pub const fn new(count: u16) -> Self {
-            Self {
-                count: Atomic::<u16>::new(count),
+        /// A 42-bit counter, which is what the [`Default`] implementation uses,
+        /// fills `rand_a` and the first 30 bits of `rand_b`, leaving 32 random bits.
+        ///
+        /// # Panics
+        ///
+        /// This method will panic if `counter_bits` is 0 or more than 64.
+        pub const fn new(counter_bits: u8) -> Self {
+            assert!(
+                counter_bits > 0 && counter_bits <= 64,
+                "the counter must be between 1 and 64 bits"
+            );
+
+            WideCounterContext {
+                last: SpinLock::new((0, 0)),
+                counter_bits,
             }
         }
 
-        /// Construct a new context that's initialized with a random value.
-        #[cfg(feature = "rng")]
-        pub fn new_random() -> Self {
-            Self {
-                count: Atomic::<u16>::new(crate::rng::u16()),
-            }
+        /// Get the number of bits in the counter.
+        ///
+        /// The other `74 - counter_bits` bits after the timestamp are random.
+        pub const fn counter_bits(&self) -> u8 {
+            self.counter_bits
+        }
+
+        fn max(&self) -> u64 {
+            u64::MAX >> (64 - self.counter_bits)
         }
     }
 
-    #[cfg(any(feature = "v1", feature = "v6"))]
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl ClockSequence for WideCounterContext {
+        type Output = u64;
+
+        fn generate_sequence(&self, seconds: u64, nanos: u32) -> Self::Output {
+            let millis = seconds
+                .saturating_mul(1_000)
+                .saturating_add(nanos as u64 / 1_000_000);
+
+            self.last.with(|last| {
+                let (last_millis, count) = last;
+
+                *count = if millis > *last_millis {
+                    // Leave the highest bit clear so there's room to count
+                    let bytes = crate::rng::bytes();
+                    let seed = u64::from_be_bytes([
+                        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6],
+                        bytes[7],
+                    ]);
+
+                    seed & (self.max() >> 1)
+                } else {
+                    cmp::min(count.saturating_add(1), self.max())
+                };
+                *last_millis = cmp::max(millis, *last_millis);
+
+                *count
+            })
+        }
+
+        fn usable_bits(&self) -> usize {
+            self.counter_bits as usize
+        }
+    }
+
+    #[cfg(all(
+        any(feature = "v1", feature = "v6"),
+        any(feature = "std", feature = "custom-clock"),
+        feature = "rng"
+    ))]
+    static CONTEXT: Context = Context::new(0);
+
+    #[cfg(all(
+        any(feature = "v1", feature = "v6"),
+        any(feature = "std", feature = "custom-clock"),
+        feature = "rng"
+    ))]
+    static CONTEXT_INITIALIZED: Atomic<bool> = Atomic::new(false);
+
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+    static STRIPED_CONTEXT: StripedContext = StripedContext::new(0);
+
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+    static STRIPED_CONTEXT_INITIALIZED: Atomic<bool> = Atomic::new(false);
+
+    #[cfg(all(
+        any(feature = "v1", feature = "v6"),
+        any(feature = "std", feature = "custom-clock"),
+        feature = "rng",
+        not(feature = "striped-context")
+    ))]
+    pub(crate) fn shared_context() -> &'static Context {
+        Context::shared()
+    }
+
+    #[cfg(all(
+        any(feature = "v1", feature = "v6"),
+        feature = "std",
+        feature = "rng",
+        feature = "striped-context"
+    ))]
+    pub(crate) fn shared_context() -> &'static StripedContext {
+        StripedContext::shared()
+    }
+
+    /// A thread-safe, wrapping counter that produces 14-bit numbers.
+    ///
+    /// This type should be used when constructing version 1 and version 6 UUIDs.
+    #[derive(Debug)]
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    pub struct Context {
+        count: Atomic<u16>,
+    }
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    impl Context {
+        /// Construct a new context that's initialized with the given value.
+        ///
+        /// The starting value should be a random number, so that UUIDs from
+        /// different systems with the same timestamps are less likely to collide.
+        /// When the `rng` feature is enabled, prefer the [`Context::new_random`] method.
+        pub const fn new(count: u16) -> Self {
+            Self {
+                count: Atomic::<u16>::new(count),
+            }
+        }
+
+        /// Construct a new context that's initialized with a random value.
+        #[cfg(feature = "rng")]
+        pub fn new_random() -> Self {
+            Self {
+                count: Atomic::<u16>::new(crate::rng::u16()),
+            }
+        }
+
+        /// Construct a new context that's initialized with a value derived
+        /// from this machine's ID.
+        ///
+        /// The machine ID is read from `/etc/machine-id`, falling back to
+        /// `/var/lib/dbus/machine-id`. Each host starts its clock sequence at
+        /// a different value that stays the same across restarts, so hosts
+        /// that generate UUIDs with the same timestamp are less likely to
+        /// collide than if they all started from the same value.
+        ///
+        /// On other platforms, like Windows, the ID can be read by the caller
+        /// and passed to [`Context::from_machine_id_bytes`] instead.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error if there's no machine ID to read.
+        #[cfg(feature = "std")]
+        pub fn from_machine_id() -> Result<Self, crate::Error> {
+            for path in ["/etc/machine-id", "/var/lib/dbus/machine-id"] {
+                if let Ok(id) = std::fs::read_to_string(path) {
+                    let id = id.trim();
+
+                    if !id.is_empty() {
+                        return Ok(Self::from_machine_id_bytes(id.as_bytes()));
+                    }
+                }
+            }
+
+            Err(crate::Error(crate::error::ErrorRepr::MachineId))
+        }
+
+        /// Construct a new context that's initialized with a value derived
+        /// from the given machine ID.
+        ///
+        /// The ID can be any value that's unique to a host, like the
+        /// `MachineGuid` in the Windows registry. It's hashed down to fit in
+        /// the clock sequence, so it isn't recoverable from generated UUIDs.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use uuid::{ClockSequence, Context};
+        /// let a = Context::from_machine_id_bytes(b"6b1c3e9a2f0d4f6c8a7e5b3d1c9f0a2e");
+        /// let b = Context::from_machine_id_bytes(b"6b1c3e9a2f0d4f6c8a7e5b3d1c9f0a2e");
+        ///
+        /// assert_eq!(a.generate_sequence(0, 0), b.generate_sequence(0, 0));
+        /// ```
+        pub const fn from_machine_id_bytes(id: &[u8]) -> Self {
+            Self::new((crate::node::hash_machine_id(id) % (u16::MAX >> 2) as u64) as u16)
+        }
+
+        /// Get a context that's shared by the whole process.
+        ///
+        /// The shared context is initialized with a random value the first
+        /// time it's used. Unless the `striped-context` feature is enabled,
+        /// this is the context used by [`Uuid::now_v1`] and [`Uuid::now_v6`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use uuid::{Context, Timestamp};
+        /// let ts = Timestamp::from_unix(Context::shared(), 1497624119, 1234);
+        /// ```
+        ///
+        /// [`Uuid::now_v1`]: ../../struct.Uuid.html#method.now_v1
+        /// [`Uuid::now_v6`]: ../../struct.Uuid.html#method.now_v6
+        #[cfg(all(any(feature = "std", feature = "custom-clock"), feature = "rng"))]
+        pub fn shared() -> &'static Context {
+            // If the context is in its initial state then assign it to a random value
+            // It doesn't matter if multiple threads observe `false` here and initialize the context
+            if CONTEXT_INITIALIZED
+                .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+            {
+                CONTEXT.count.store(crate::rng::u16(), Ordering::Release);
+            }
+
+            &CONTEXT
+        }
+
+        /// Construct a context that resumes from a previously saved state.
+        ///
+        /// RFC4122 recommends keeping the clock sequence in stable storage,
+        /// so that a process that restarts after its clock was set backwards
+        /// won't produce the same clock sequences for the same timestamps
+        /// as it did before.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use uuid::{ClockSequence, Context, ContextState};
+        /// let context = Context::new(42);
+        /// context.generate_sequence(0, 0);
+        ///
+        /// // Persist the state somewhere before the process exits
+        /// let saved = context.to_state().to_bytes();
+        ///
+        /// // Then pick up where it left off when the process restarts
+        /// let context = Context::from_state(ContextState::from_bytes(saved));
+        ///
+        /// assert_eq!(43, context.generate_sequence(0, 0));
+        /// ```
+        pub const fn from_state(state: ContextState) -> Self {
+            Self::new(state.count)
+        }
+
+        /// Get the current state of the context, so it can be saved and
+        /// resumed later with [`Context::from_state`].
+        pub fn to_state(&self) -> ContextState {
+            ContextState {
+                count: self.count.load(Ordering::Acquire),
+            }
+        }
+    }
+
+    /// The saved state of a [`Context`].
+    ///
+    /// When the `serde` feature is enabled, this type can be serialized.
+    /// Otherwise it can be converted to and from bytes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    pub struct ContextState {
+        pub(crate) count: u16,
+    }
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    impl ContextState {
+        /// Create a state from bytes previously returned by [`ContextState::to_bytes`].
+        pub const fn from_bytes(bytes: [u8; 2]) -> Self {
+            ContextState {
+                count: u16::from_be_bytes(bytes),
+            }
+        }
+
+        /// Get the state as bytes.
+        pub const fn to_bytes(&self) -> [u8; 2] {
+            self.count.to_be_bytes()
+        }
+    }
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
     impl ClockSequence for Context {
         type Output = u16;
 
@@ -440,35 +2147,1556 @@ pub mod context {
             // where the clock sequence doesn't change regardless of the timestamp
             self.count.fetch_add(1, Ordering::AcqRel) % (u16::MAX >> 2)
         }
+
+        fn usable_bits(&self) -> usize {
+            14
+        }
     }
-}
 
-#[cfg(all(test, any(feature = "v1", feature = "v6")))]
-mod tests {
-    use super::*;
+    // The 12 bits of `rand_a` in a version 7 UUID hold the counter
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    const V7_COUNTER_BITS: u64 = 12;
 
     #[cfg(all(
-        target_arch = "wasm32",
-        target_vendor = "unknown",
-        target_os = "unknown"
+        uuid_unstable,
+        feature = "v7",
+        any(
+            feature = "std",
+            all(feature = "uefi", target_os = "uefi"),
+            feature = "custom-clock"
+        )
     ))]
-    use wasm_bindgen_test::*;
+    static CONTEXT_V7: ContextV7 = ContextV7::new();
 
-    #[test]
-    #[cfg_attr(
-        all(
-            target_arch = "wasm32",
-            target_vendor = "unknown",
-            target_os = "unknown"
-        ),
-        wasm_bindgen_test
-    )]
-    fn rfc4122_unix_does_not_panic() {
-        // Ensure timestamp conversions never panic
-        Timestamp::unix_to_rfc4122_ticks(u64::MAX, 0);
-        Timestamp::unix_to_rfc4122_ticks(0, u32::MAX);
-        Timestamp::unix_to_rfc4122_ticks(u64::MAX, u32::MAX);
+    #[cfg(all(
+        uuid_unstable,
+        feature = "v7",
+        any(
+            feature = "std",
+            all(feature = "uefi", target_os = "uefi"),
+            feature = "custom-clock"
+        )
+    ))]
+    pub(crate) fn shared_context_v7() -> &'static ContextV7 {
+        &CONTEXT_V7
+    }
 
-        Timestamp::rfc4122_to_unix(u64::MAX);
+    /// A thread-safe counter for version 7 UUIDs generated in the same millisecond.
+    ///
+    /// This type implements the fixed-length dedicated counter method from
+    /// RFC 9562: the 12 bits of `rand_a` hold a counter that's seeded
+    /// randomly at the start of each millisecond and incremented for each
+    /// UUID generated within it. The most significant bit of the seed is
+    /// always clear, so there's room for at least 2048 UUIDs per
+    /// millisecond before the counter runs out.
+    ///
+    /// If the counter does run out, or the clock goes backwards, the
+    /// timestamp is advanced past the last one used instead, so each UUID
+    /// generated through the same context sorts after the one before it.
+    ///
+    /// [`Uuid::now_v7`] uses a shared instance of this context.
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-6.2)
+    ///
+    /// [`Uuid::now_v7`]: ../../struct.Uuid.html#method.now_v7
+    #[derive(Debug)]
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    pub struct ContextV7 {
+        // The last millisecond timestamp and counter used, packed so the
+        // counter carries into the timestamp when it overflows
+        last: Atomic<u64>,
+    }
+
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl Default for ContextV7 {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    impl ContextV7 {
+        /// Construct a new context.
+        pub const fn new() -> Self {
+            ContextV7 {
+                last: Atomic::<u64>::new(0),
+            }
+        }
+
+        // Get the millisecond timestamp and counter to use for the next UUID
+        //
+        // The seed is only used when the timestamp has moved past the last one
+        pub(crate) fn generate(&self, millis: u64, seed: u16) -> (u64, u16) {
+            let mask = (1 << V7_COUNTER_BITS) - 1;
+            let next = self.reserve(millis, seed, 1);
+
+            (next >> V7_COUNTER_BITS, (next & mask) as u16)
+        }
+
+        // Get the millisecond timestamps and counters to use for the next `count` UUIDs
+        //
+        // They're reserved all at once, so UUIDs generated on other threads
+        // can't interleave with them
+        pub(crate) fn generate_batch(
+            &self,
+            millis: u64,
+            seed: u16,
+            count: usize,
+        ) -> impl Iterator<Item = (u64, u16)> {
+            let mask = (1 << V7_COUNTER_BITS) - 1;
+            let first = if count == 0 {
+                0
+            } else {
+                self.reserve(millis, seed, count as u64)
+            };
+
+            (0..count as u64).map(move |i| {
+                let next = first.wrapping_add(i);
+
+                (next >> V7_COUNTER_BITS, (next & mask) as u16)
+            })
+        }
+
+        // Reserve `count` consecutive timestamps and counters, returning the first
+        fn reserve(&self, millis: u64, seed: u16, count: u64) -> u64 {
+            let mask = (1 << V7_COUNTER_BITS) - 1;
+
+            // Only 48 bits of the timestamp fit in the UUID
+            let millis = millis.min(0xFFFF_FFFF_FFFF);
+            let mut last = self.last.load(Ordering::Acquire);
+
+            loop {
+                let first = if millis > last >> V7_COUNTER_BITS {
+                    (millis << V7_COUNTER_BITS) | (seed as u64 & (mask >> 1))
+                } else {
+                    last.wrapping_add(1)
+                };
+                let next = first.wrapping_add(count - 1);
+
+                match self
+                    .last
+                    .compare_exchange(last, next, Ordering::AcqRel, Ordering::Acquire)
+                {
+                    Ok(_) => return first,
+                    Err(current) => last = current,
+                }
+            }
+        }
+    }
+
+    /// A thread-safe counter that only changes when the clock doesn't move forwards.
+    ///
+    /// This type follows the clock sequence semantics from RFC4122. It
+    /// remembers the last timestamp it saw, and keeps returning the same
+    /// 14-bit clock sequence for as long as timestamps keep increasing. The
+    /// clock sequence is only incremented when a timestamp is the same as or
+    /// earlier than the last one, like when the system clock is set
+    /// backwards. Since a [`Context`] increments on every call, this type
+    /// goes through its clock sequence much more slowly, so it's less likely
+    /// to come back around to a value it already used for a timestamp.
+    ///
+    /// Timestamps are compared at the 100ns precision of version 1 and
+    /// version 6 UUIDs. Only the low 49 bits of each timestamp are kept,
+    /// which wrap around about every 21 months, so the clock is treated as
+    /// moving forwards when it's up to about 10 months ahead of the last
+    /// timestamp the context saw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ClockSequence, RollbackContext};
+    /// let context = RollbackContext::new(42);
+    ///
+    /// assert_eq!(42, context.generate_sequence(1_497_624_119, 1_000));
+    /// assert_eq!(42, context.generate_sequence(1_497_624_119, 2_000));
+    ///
+    /// // The clock went backwards
+    /// assert_eq!(43, context.generate_sequence(1_497_624_118, 0));
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Clock Sequence in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.1.5)
+    #[derive(Debug)]
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    pub struct RollbackContext {
+        // Whether a timestamp has been seen, the low bits of the last one in
+        // 100ns ticks, and the clock sequence, packed so they fit in one atomic
+        last: Atomic<u64>,
+    }
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    const ROLLBACK_TICK_BITS: u32 = 49;
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    impl RollbackContext {
+        /// Construct a new context that's initialized with the given value.
+        ///
+        /// The starting value should be a random number, so that UUIDs from
+        /// different systems with the same timestamps are less likely to collide.
+        /// When the `rng` feature is enabled, prefer the [`RollbackContext::new_random`] method.
+        pub const fn new(count: u16) -> Self {
+            Self {
+                last: Atomic::<u64>::new((count % (u16::MAX >> 2)) as u64),
+            }
+        }
+
+        /// Construct a new context that's initialized with a random value.
+        #[cfg(feature = "rng")]
+        pub fn new_random() -> Self {
+            Self::new(crate::rng::u16())
+        }
+    }
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    impl ClockSequence for RollbackContext {
+        type Output = u16;
+
+        fn generate_sequence(&self, seconds: u64, nanos: u32) -> Self::Output {
+            let ticks = seconds
+                .saturating_mul(10_000_000)
+                .saturating_add(nanos as u64 / 100);
+
+            let mask = (1 << ROLLBACK_TICK_BITS) - 1;
+            let seen = 1 << (ROLLBACK_TICK_BITS + 14);
+
+            let ticks = ticks & mask;
+            let mut last = self.last.load(Ordering::Acquire);
+
+            loop {
+                let (last_ticks, count) = ((last >> 14) & mask, (last & 0x3FFF) as u16);
+
+                // The truncated ticks wrap, so the clock has moved forwards if
+                // they're less than half of their range ahead of the last ones
+                let elapsed = ticks.wrapping_sub(last_ticks) & mask;
+
+                let count = if last & seen == 0 || (elapsed != 0 && elapsed < mask / 2) {
+                    count
+                } else {
+                    // Wrap in the same way as `Context` so the sequence fits in 14 bits
+                    (count + 1) % (u16::MAX >> 2)
+                };
+
+                let next = seen | (ticks << 14) | count as u64;
+
+                match self
+                    .last
+                    .compare_exchange(last, next, Ordering::AcqRel, Ordering::Acquire)
+                {
+                    Ok(_) => return count,
+                    Err(current) => last = current,
+                }
+            }
+        }
+
+        fn usable_bits(&self) -> usize {
+            14
+        }
+    }
+
+    /// What a [`CheckedContext`] does when its clock sequence runs out for a timestamp.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    pub enum OverflowPolicy {
+        /// Keep going, reusing clock sequences that were already used for the timestamp.
+        ///
+        /// This is what [`Context`] does.
+        Wrap,
+        /// Wait for the clock to move on to the next 100ns tick.
+        ///
+        /// This only applies to timestamps read through [`CheckedContext::try_now`]
+        /// or [`CheckedContext::try_now_with`], since the clock can't be read
+        /// again for a timestamp that was given to the context. In that case,
+        /// it behaves like [`OverflowPolicy::Error`].
+        StallUntilNextTick,
+        /// Return an error.
+        Error,
+    }
+
+    /// A thread-safe, wrapping counter that produces 14-bit numbers and
+    /// detects when it runs out for a single timestamp.
+    ///
+    /// A [`Context`] can produce 16383 unique clock sequences before it wraps.
+    /// If more UUIDs than that are generated within the same 100ns tick then
+    /// some of them will collide. This context keeps track of the tick each
+    /// clock sequence was generated for, and follows an [`OverflowPolicy`] when
+    /// it would wrap within the same tick.
+    ///
+    /// When this type is used as a [`ClockSequence`], an overflow with any
+    /// policy other than [`OverflowPolicy::Wrap`] will panic. Use
+    /// [`CheckedContext::try_generate_sequence`] or [`CheckedContext::try_now`]
+    /// to handle overflow instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{CheckedContext, OverflowPolicy, Uuid};
+    /// let context = CheckedContext::new(0, OverflowPolicy::Error);
+    ///
+    /// for _ in 0..16383 {
+    ///     context.try_generate_sequence(1_497_624_119, 0)?;
+    /// }
+    ///
+    /// // The clock sequence has run out for this timestamp
+    /// assert!(context.try_generate_sequence(1_497_624_119, 0).is_err());
+    ///
+    /// // But the next one is fine
+    /// assert!(context.try_generate_sequence(1_497_624_119, 100).is_ok());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    #[derive(Debug)]
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    pub struct CheckedContext {
+        // Whether a timestamp has been seen, the low bits of the last one in 100ns
+        // ticks, the first clock sequence used for it, and the next one to use,
+        // packed so they fit in one atomic
+        state: Atomic<u64>,
+        policy: OverflowPolicy,
+    }
+
+    // The bits of the last timestamp a `CheckedContext` keeps, between the flag
+    // for whether it's seen one and its two 14-bit clock sequences
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    const CHECKED_TICK_MASK: u64 = (1 << 35) - 1;
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    impl CheckedContext {
+        /// Construct a new context that's initialized with the given value.
+        ///
+        /// The starting value should be a random number, so that UUIDs from
+        /// different systems with the same timestamps are less likely to collide.
+        /// When the `rng` feature is enabled, prefer the [`CheckedContext::new_random`] method.
+        pub const fn new(count: u16, policy: OverflowPolicy) -> Self {
+            Self {
+                state: Atomic::<u64>::new((count % (u16::MAX >> 2)) as u64),
+                policy,
+            }
+        }
+
+        /// Construct a new context that's initialized with a random value.
+        #[cfg(feature = "rng")]
+        pub fn new_random(policy: OverflowPolicy) -> Self {
+            Self::new(crate::rng::u16(), policy)
+        }
+
+        /// Get the policy the context follows when its clock sequence runs out.
+        pub const fn policy(&self) -> OverflowPolicy {
+            self.policy
+        }
+
+        /// Generate the next clock sequence for a timestamp.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error if every clock sequence has already
+        /// been used for the timestamp and the policy isn't [`OverflowPolicy::Wrap`].
+        pub fn try_generate_sequence(&self, seconds: u64, nanos: u32) -> Result<u16, crate::Error> {
+            // Timestamps are compared at the 100ns precision of version 1 and 6 UUIDs.
+            // Only their low bits are kept, which is enough to tell consecutive
+            // timestamps apart unless they're an exact multiple of about an hour apart
+            let ticks = seconds
+                .saturating_mul(10_000_000)
+                .saturating_add(nanos as u64 / 100)
+                & CHECKED_TICK_MASK;
+            let seen = 1 << 63;
+
+            let mut state = self.state.load(Ordering::Acquire);
+
+            loop {
+                let (last_ticks, first, count) = (
+                    (state >> 28) & CHECKED_TICK_MASK,
+                    ((state >> 14) & 0x3FFF) as u16,
+                    (state & 0x3FFF) as u16,
+                );
+
+                let first = if state & seen == 0 || ticks != last_ticks {
+                    count
+                } else if count != first {
+                    first
+                } else if self.policy == OverflowPolicy::Wrap {
+                    // Start counting through the clock sequences for this timestamp again
+                    count
+                } else {
+                    return Err(crate::Error(crate::error::ErrorRepr::ClockSequenceOverflow));
+                };
+
+                let next = seen
+                    | (ticks << 28)
+                    | (first as u64) << 14
+                    | ((count + 1) % (u16::MAX >> 2)) as u64;
+
+                match self
+                    .state
+                    .compare_exchange(state, next, Ordering::AcqRel, Ordering::Acquire)
+                {
+                    Ok(_) => return Ok(count),
+                    Err(current) => state = current,
+                }
+            }
+        }
+
+        /// Get a timestamp for the current system time, following the context's
+        /// [`OverflowPolicy`] if the clock sequence runs out.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error if every clock sequence has already
+        /// been used for the current time and the policy is [`OverflowPolicy::Error`].
+        #[cfg(any(
+            feature = "std",
+            all(feature = "uefi", target_os = "uefi"),
+            feature = "custom-clock"
+        ))]
+        pub fn try_now(&self) -> Result<crate::Timestamp, crate::Error> {
+            self.try_now_with(crate::timestamp::clock::SystemClock)
+        }
+
+        /// Get a timestamp for the current time from the given clock, following
+        /// the context's [`OverflowPolicy`] if the clock sequence runs out.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error if every clock sequence has already
+        /// been used for the current time and the policy is [`OverflowPolicy::Error`].
+        pub fn try_now_with(
+            &self,
+            clock: impl crate::ClockSource,
+        ) -> Result<crate::Timestamp, crate::Error> {
+            loop {
+                let (seconds, nanos) = clock.now();
+                let (seconds, nanos) = clock.time_scale().to_unix(seconds, nanos);
+
+                match self.try_generate_sequence(seconds, nanos) {
+                    Ok(counter) => {
+                        return Ok(crate::Timestamp {
+                            seconds,
+                            nanos,
+                            counter: counter as u128,
+                            usable_counter_bits: 14,
+                        })
+                    }
+                    Err(_) if self.policy == OverflowPolicy::StallUntilNextTick => {
+                        crate::std::hint::spin_loop()
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "v1", feature = "v6"))]
+    impl ClockSequence for CheckedContext {
+        type Output = u16;
+
+        fn generate_sequence(&self, seconds: u64, nanos: u32) -> Self::Output {
+            self.try_generate_sequence(seconds, nanos)
+                .unwrap_or_else(|err| panic!("{}", err))
+        }
+
+        fn usable_bits(&self) -> usize {
+            14
+        }
+    }
+
+    // 6 bits of the 14-bit clock sequence identify the stripe,
+    // and the remaining 8 bits come from its counter
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+    const STRIPES: usize = 64;
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+    const STRIPE_COUNT_BITS: u16 = 8;
+
+    /// A thread-safe counter that spreads contention across 64 stripes.
+    ///
+    /// Each thread is assigned a stripe the first time it generates a clock
+    /// sequence, and only ever increments that stripe's counter. The stripe
+    /// index makes up the upper 6 bits of each 14-bit clock sequence, so
+    /// threads on different stripes can never produce the same value, and
+    /// threads never contend for the same cache line unless there are more
+    /// than 64 of them.
+    ///
+    /// The trade-off is that each stripe can only produce 256 unique values
+    /// for the same timestamp, rather than the 16384 a [`Context`] can.
+    ///
+    /// This type can be used in place of [`Context`] for version 1 and
+    /// version 6 UUIDs on machines with many cores. Enabling the
+    /// `striped-context` feature makes [`Uuid::now_v1`] and [`Uuid::now_v6`]
+    /// use it too.
+    ///
+    /// [`Uuid::now_v1`]: ../../struct.Uuid.html#method.now_v1
+    /// [`Uuid::now_v6`]: ../../struct.Uuid.html#method.now_v6
+    #[derive(Debug)]
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+    pub struct StripedContext {
+        start: u16,
+        stripes: [Stripe; STRIPES],
+    }
+
+    // Padded to a cache line so stripes don't contend with each other
+    #[derive(Debug)]
+    #[repr(align(64))]
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+    struct Stripe {
+        count: Atomic<u16>,
+    }
+
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+    impl StripedContext {
+        /// Construct a new context with every stripe initialized with the given value.
+        ///
+        /// The starting value should be a random number, so that UUIDs from
+        /// different systems with the same timestamps are less likely to collide.
+        /// When the `rng` feature is enabled, prefer the [`StripedContext::new_random`] method.
+        pub const fn new(count: u16) -> Self {
+            // Atomics aren't `Copy`, so they need to be repeated through a constant
+            #[allow(clippy::declare_interior_mutable_const)]
+            const STRIPE: Stripe = Stripe {
+                count: Atomic::<u16>::new(0),
+            };
+
+            Self {
+                start: count,
+                stripes: [STRIPE; STRIPES],
+            }
+        }
+
+        /// Construct a new context with each stripe initialized with a random value.
+        #[cfg(feature = "rng")]
+        pub fn new_random() -> Self {
+            let context = Self::new(0);
+
+            for stripe in context.stripes.iter() {
+                stripe.count.store(crate::rng::u16(), Ordering::Release);
+            }
+
+            context
+        }
+
+        /// Get a striped context that's shared by the whole process.
+        ///
+        /// Each stripe of the shared context is initialized with a random value
+        /// the first time it's used. When the `striped-context` feature is enabled,
+        /// this is the context used by [`Uuid::now_v1`] and [`Uuid::now_v6`].
+        ///
+        /// [`Uuid::now_v1`]: ../../struct.Uuid.html#method.now_v1
+        /// [`Uuid::now_v6`]: ../../struct.Uuid.html#method.now_v6
+        #[cfg(feature = "rng")]
+        pub fn shared() -> &'static StripedContext {
+            // If the context is in its initial state then assign its stripes random values
+            // It doesn't matter if multiple threads observe `false` here and initialize the context
+            if STRIPED_CONTEXT_INITIALIZED
+                .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+            {
+                for stripe in STRIPED_CONTEXT.stripes.iter() {
+                    stripe.count.store(crate::rng::u16(), Ordering::Release);
+                }
+            }
+
+            &STRIPED_CONTEXT
+        }
+    }
+
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std"))]
+    impl ClockSequence for StripedContext {
+        type Output = u16;
+
+        fn generate_sequence(&self, _seconds: u64, _nanos: u32) -> Self::Output {
+            use std::sync::atomic;
+
+            static NEXT_STRIPE: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
+
+            std::thread_local! {
+                static STRIPE: usize = NEXT_STRIPE.fetch_add(1, atomic::Ordering::Relaxed) % STRIPES;
+            }
+
+            // Threads can't generate sequences while they're being torn down,
+            // so fall back to a stripe that's shared between them
+            let stripe = STRIPE.try_with(|stripe| *stripe).unwrap_or(0);
+
+            let count = self.stripes[stripe].count.fetch_add(1, Ordering::AcqRel);
+
+            let count = self.start.wrapping_add(count);
+
+            ((stripe as u16) << STRIPE_COUNT_BITS) | (count & ((1 << STRIPE_COUNT_BITS) - 1))
+        }
+
+        fn usable_bits(&self) -> usize {
+            14
+        }
+    }
+
+    /// A counter that keeps a separate 14-bit count for each thread.
+    ///
+    /// Each thread's count starts at a random value the first time it
+    /// generates a clock sequence, and is only ever incremented by that
+    /// thread, so threads never contend with each other.
+    ///
+    /// The trade-off is that threads that generate UUIDs with the same
+    /// timestamp and node ID can produce the same clock sequence, in the
+    /// same way as separate processes with their own random [`Context`]s
+    /// can. Use a [`StripedContext`] if clock sequences need to be unique
+    /// across threads.
+    ///
+    /// This type can be used in place of [`Context`] for version 1 and
+    /// version 6 UUIDs in services that generate them on many threads.
+    #[derive(Debug, Clone, Copy, Default)]
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+    pub struct ThreadLocalContext;
+
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+    impl ClockSequence for ThreadLocalContext {
+        type Output = u16;
+
+        fn generate_sequence(&self, _seconds: u64, _nanos: u32) -> Self::Output {
+            use std::cell::Cell;
+
+            std::thread_local! {
+                static COUNT: Cell<u16> = Cell::new(crate::rng::u16());
+            }
+
+            // Threads can't generate sequences while they're being torn down,
+            // so fall back to a random value
+            let count = COUNT
+                .try_with(|count| {
+                    let prev = count.get();
+                    count.set(prev.wrapping_add(1));
+
+                    prev
+                })
+                .unwrap_or_else(|_| crate::rng::u16());
+
+            // Wrap in the same way as `Context` so the sequence fits in 14 bits
+            count % (u16::MAX >> 2)
+        }
+
+        fn usable_bits(&self) -> usize {
+            14
+        }
+    }
+
+    /// A counter that gives each thread its own node ID, as well as its own clock sequence.
+    ///
+    /// Like a [`ThreadLocalContext`], each thread keeps a separate 14-bit
+    /// count, so threads never contend with each other. Each thread also gets
+    /// a synthetic node ID from [`ShardedContext::node_id`] that no other
+    /// thread in the process shares, so UUIDs generated on different threads
+    /// can't collide even if they have the same timestamp and clock sequence.
+    ///
+    /// Synthetic node IDs are derived from a base node ID by mixing the thread's
+    /// index into its last 3 bytes, so up to 16,777,216 threads get unique node
+    /// IDs. They're always multicast, so they can't conflict with a node ID
+    /// from a real MAC address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, ShardedContext, Timestamp};
+    /// let context = ShardedContext::new_random();
+    ///
+    /// let (ticks, counter) = Timestamp::now(&context).to_rfc4122();
+    /// let uuid = Builder::from_rfc4122_timestamp(ticks, counter, context.node_id().as_bytes())
+    ///     .into_uuid();
+    ///
+    /// assert_eq!(context.node_id().as_bytes(), &uuid.as_bytes()[10..]);
+    ///
+    /// // Other threads get their own node ID
+    /// let other = std::thread::spawn(move || context.node_id()).join().unwrap();
+    ///
+    /// assert_ne!(context.node_id(), other);
+    /// ```
+    #[derive(Debug, Clone, Copy)]
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+    pub struct ShardedContext {
+        base: [u8; 6],
+    }
+
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+    impl ShardedContext {
+        /// Construct a new context that derives node IDs from the given one.
+        ///
+        /// Contexts in different processes should use different base node IDs,
+        /// since threads are indexed from zero in each process.
+        pub fn new(base: impl Into<crate::NodeId>) -> Self {
+            ShardedContext {
+                base: base.into().into_bytes(),
+            }
+        }
+
+        /// Construct a new context that derives node IDs from a random one.
+        pub fn new_random() -> Self {
+            Self::new(crate::NodeId::random())
+        }
+
+        /// Get the node ID for the current thread.
+        pub fn node_id(&self) -> crate::NodeId {
+            use std::sync::atomic;
+
+            static NEXT_THREAD: atomic::AtomicU32 = atomic::AtomicU32::new(0);
+
+            std::thread_local! {
+                static THREAD: u32 = NEXT_THREAD.fetch_add(1, atomic::Ordering::Relaxed);
+            }
+
+            // Threads can't generate node IDs while they're being torn down,
+            // so fall back to a fresh index
+            let thread = THREAD
+                .try_with(|thread| *thread)
+                .unwrap_or_else(|_| NEXT_THREAD.fetch_add(1, atomic::Ordering::Relaxed))
+                .to_be_bytes();
+
+            let [a, b, c, d, e, f] = self.base;
+
+            crate::NodeId::from_bytes([a | 0x01, b, c, d ^ thread[1], e ^ thread[2], f ^ thread[3]])
+        }
+    }
+
+    #[cfg(all(any(feature = "v1", feature = "v6"), feature = "std", feature = "rng"))]
+    impl ClockSequence for ShardedContext {
+        type Output = u16;
+
+        fn generate_sequence(&self, seconds: u64, nanos: u32) -> Self::Output {
+            ThreadLocalContext.generate_sequence(seconds, nanos)
+        }
+
+        fn usable_bits(&self) -> usize {
+            14
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "v1", feature = "v6")))]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn rfc4122_unix_does_not_panic() {
+        // Ensure timestamp conversions never panic
+        Timestamp::unix_to_rfc4122_ticks(u64::MAX, 0);
+        Timestamp::unix_to_rfc4122_ticks(0, u32::MAX);
+        Timestamp::unix_to_rfc4122_ticks(u64::MAX, u32::MAX);
+
+        Timestamp::rfc4122_to_unix(u64::MAX);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn rfc4122_saturates() {
+        assert_eq!((0, 0), Timestamp::from_rfc4122(0, 0).to_unix());
+        assert_eq!(
+            (0, 100),
+            Timestamp::from_rfc4122(UUID_TICKS_BETWEEN_EPOCHS + 1, 0).to_unix()
+        );
+
+        let ts = Timestamp::from_unix(context::NoContext, u64::MAX, u32::MAX);
+        assert_eq!(u64::MAX, ts.to_rfc4122().0);
+
+        // Ticks that fit in a `u64` roundtrip, even if they don't fit in 60 bits
+        let ts = Timestamp::from_rfc4122(u64::MAX - 1, 0);
+        assert_eq!(u64::MAX - 1, ts.to_rfc4122().0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn gregorian_ticks() {
+        let ts = Timestamp::from_rfc4122(0x1e7_4ba2_2061_6934, 42);
+        assert_eq!(0x1e7_4ba2_2061_6934, ts.to_gregorian_ticks());
+
+        let ts = Timestamp::from_unix(context::NoContext, 0, 0);
+        assert_eq!(UUID_TICKS_BETWEEN_EPOCHS, ts.to_gregorian_ticks());
+
+        let ts = Timestamp::from_unix(context::NoContext, u64::MAX, u32::MAX);
+        assert_eq!(u64::MAX, ts.to_gregorian_ticks());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn rfc4122_try_conversions() {
+        let ts = Timestamp::try_from_rfc4122(MAX_RFC4122_TICKS, 42).unwrap();
+        assert_eq!((MAX_RFC4122_TICKS, 42), ts.try_to_rfc4122().unwrap());
+
+        let ts = Timestamp::try_from_rfc4122(UUID_TICKS_BETWEEN_EPOCHS, 0).unwrap();
+        assert_eq!((0, 0), ts.to_unix());
+
+        let err = Timestamp::try_from_rfc4122(UUID_TICKS_BETWEEN_EPOCHS - 1, 0).unwrap_err();
+        assert_eq!(ErrorKind::ClockError, err.kind());
+
+        let err = Timestamp::try_from_rfc4122(MAX_RFC4122_TICKS + 1, 0).unwrap_err();
+        assert_eq!(ErrorKind::OutOfRange, err.kind());
+
+        let (seconds, nanos) = ts_after_max();
+        let err = Timestamp::from_unix(context::NoContext, seconds, nanos)
+            .try_to_rfc4122()
+            .unwrap_err();
+        assert_eq!(ErrorKind::OutOfRange, err.kind());
+    }
+
+    // The Unix time just after the largest RFC4122 timestamp
+    fn ts_after_max() -> (u64, u32) {
+        let ticks = MAX_RFC4122_TICKS - UUID_TICKS_BETWEEN_EPOCHS + 1;
+
+        (ticks / 10_000_000, (ticks % 10_000_000) as u32 * 100)
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn ordering() {
+        let a = Timestamp::from_rfc4122(0x1e7_4ba2_2061_6934, 1);
+        let b = Timestamp::from_rfc4122(0x1e7_4ba2_2061_6935, 0);
+        let c = Timestamp::from_rfc4122(0x1e7_4ba2_2061_6935, 1);
+        let d = Timestamp::from_rfc4122(0x1e8_4ba2_2061_6934, 0);
+
+        let mut timestamps = [d, c, a, b];
+        timestamps.sort();
+
+        assert_eq!([a, b, c, d], timestamps);
+        assert_eq!(Some(crate::std::cmp::Ordering::Equal), a.partial_cmp(&a));
+
+        // The usable counter bits don't affect equality or hashing
+        let wide = Timestamp {
+            usable_counter_bits: 64,
+            ..a
+        };
+        assert_eq!(a, wide);
+        assert_eq!(Some(crate::std::cmp::Ordering::Equal), a.partial_cmp(&wide));
+
+        #[cfg(feature = "std")]
+        {
+            let set: crate::std::collections::HashSet<_> = [a, wide].iter().copied().collect();
+            assert_eq!(1, set.len());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn checked_add_sub() {
+        let ts = Timestamp::from_rfc4122(0x1e7_4ba2_2061_6934, 42);
+
+        let added = ts.checked_add(Duration::new(1, 999_999_950)).unwrap();
+        assert_eq!(ts.to_rfc4122().1, added.to_rfc4122().1);
+        assert_eq!(ts.to_rfc4122().0 + 19_999_999, added.to_rfc4122().0);
+
+        let subtracted = added.checked_sub(Duration::new(1, 999_999_950)).unwrap();
+        assert_eq!(ts, subtracted);
+
+        // Overflow and underflow
+        let max = Timestamp {
+            seconds: u64::MAX,
+            nanos: 999_999_999,
+            counter: 0,
+            usable_counter_bits: 0,
+        };
+        assert!(max.checked_add(Duration::from_nanos(1)).is_none());
+        assert!(ts.checked_sub(Duration::from_secs(u64::MAX)).is_none());
+
+        // Nanoseconds outside of a single second are carried over
+        let unnormalized = Timestamp {
+            seconds: 1,
+            nanos: u32::MAX,
+            counter: 0,
+            usable_counter_bits: 0,
+        };
+        assert_eq!(
+            (5, 294_967_295),
+            unnormalized
+                .checked_add(Duration::from_secs(0))
+                .unwrap()
+                .to_unix()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn generate_timestamp_sequence_adjusts_time() {
+        use crate::std::cell::Cell;
+
+        // A context with a 1 bit counter that moves time forward when it runs out
+        struct Advancing(Cell<u8>);
+
+        impl ClockSequence for Advancing {
+            type Output = u8;
+
+            fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
+                self.generate_timestamp_sequence(seconds, subsec_nanos).0
+            }
+
+            fn generate_timestamp_sequence(
+                &self,
+                seconds: u64,
+                subsec_nanos: u32,
+            ) -> (Self::Output, u64, u32) {
+                let count = self.0.get();
+                self.0.set(count + 1);
+
+                (count % 2, seconds, subsec_nanos + (count / 2) as u32 * 100)
+            }
+
+            fn usable_bits(&self) -> usize {
+                1
+            }
+        }
+
+        let context = Advancing(Cell::new(0));
+
+        let timestamps = [
+            Timestamp::from_unix(&context, 1_497_624_119, 0),
+            Timestamp::from_unix(&context, 1_497_624_119, 0),
+            Timestamp::from_unix(&context, 1_497_624_119, 0),
+        ];
+
+        assert_eq!((1_497_624_119, 0), timestamps[0].to_unix());
+        assert_eq!((1_497_624_119, 0), timestamps[1].to_unix());
+        assert_eq!((1_497_624_119, 100), timestamps[2].to_unix());
+        assert_eq!(0, timestamps[2].to_rfc4122().1);
+        assert!(timestamps[0] < timestamps[1] && timestamps[1] < timestamps[2]);
+
+        // Contexts that don't override it keep the time as-is
+        assert_eq!(
+            (1_497_624_119, 1),
+            Timestamp::from_unix(context::NoContext, 1_497_624_119, 1).to_unix()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn duration_since() {
+        let a = Timestamp::from_unix(context::NoContext, 1_497_624_119, 999_999_900);
+        let b = Timestamp::from_unix(context::NoContext, 1_497_624_121, 100);
+
+        assert_eq!(Ok(Duration::new(1, 200)), b.duration_since(&a));
+        assert_eq!(Err(Duration::new(1, 200)), a.duration_since(&b));
+        assert_eq!(Ok(Duration::from_secs(0)), a.duration_since(&a));
+
+        // Counters aren't considered
+        let c = Timestamp::from_rfc4122(a.to_rfc4122().0, 42);
+        assert_eq!(Ok(Duration::from_secs(0)), c.duration_since(&a));
+
+        // Differences larger than a `Duration` saturate
+        let max = Timestamp {
+            seconds: u64::MAX,
+            nanos: u32::MAX,
+            counter: 0,
+            usable_counter_bits: 0,
+        };
+        let min = Timestamp::from_unix(context::NoContext, 0, 0);
+        assert_eq!(Ok(Duration::MAX), max.duration_since(&min));
+        assert_eq!(Err(Duration::MAX), min.duration_since(&max));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn truncated_and_jittered() {
+        let ts = Timestamp::from_rfc4122(0x1e7_4ba2_2061_6934, 42);
+        let (seconds, _) = ts.to_unix();
+
+        assert_eq!(
+            (seconds, 812_000_000),
+            ts.truncated(Precision::Milliseconds).to_unix()
+        );
+        assert_eq!((seconds, 0), ts.truncated(Precision::Seconds).to_unix());
+        assert_eq!(
+            (1_496_854_500, 0),
+            ts.truncated(Precision::Minutes).to_unix()
+        );
+        assert_eq!((1_496_851_200, 0), ts.truncated(Precision::Hours).to_unix());
+        assert_eq!((1_496_793_600, 0), ts.truncated(Precision::Days).to_unix());
+
+        // The counter is kept
+        assert_eq!(42, ts.truncated(Precision::Days).to_rfc4122().1);
+
+        // Nanoseconds outside of a single second are carried over
+        let unnormalized = Timestamp::from_unix(context::NoContext, 1, u32::MAX);
+        assert_eq!((5, 0), unnormalized.truncated(Precision::Seconds).to_unix());
+
+        // Timestamps past the latest one that fits saturate to it
+        let unnormalized = Timestamp::from_unix(context::NoContext, u64::MAX, u32::MAX);
+        assert_eq!(
+            (u64::MAX, 999_999_999),
+            unnormalized.truncated(Precision::Seconds).to_unix()
+        );
+
+        #[cfg(feature = "rng")]
+        {
+            for _ in 0..100 {
+                let jittered = ts.jittered(Precision::Minutes);
+
+                assert!(jittered >= ts.truncated(Precision::Minutes));
+                assert!(jittered.to_unix().0 < 1_496_854_560);
+                assert_eq!(42, jittered.to_rfc4122().1);
+            }
+
+            let latest = Timestamp::from_unix(context::NoContext, u64::MAX, 999_999_999);
+            for _ in 0..100 {
+                let (seconds, _) = latest.jittered(Precision::Hours).to_unix();
+
+                assert!(seconds > u64::MAX - 3_600);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn time_scale_to_unix() {
+        assert_eq!((42, 7), TimeScale::Unix.to_unix(42, 7));
+
+        // TAI before, during, and after the leap second at the end of 2016
+        assert_eq!(
+            (1_483_228_799, 5),
+            TimeScale::Tai.to_unix(1_483_228_799 + 36, 5)
+        );
+        assert_eq!(
+            (1_483_228_799, 999_999_999),
+            TimeScale::Tai.to_unix(1_483_228_800 + 36, 5)
+        );
+        assert_eq!(
+            (1_483_228_800, 5),
+            TimeScale::Tai.to_unix(1_483_228_800 + 37, 5)
+        );
+        assert_eq!((0, 0), TimeScale::Tai.to_unix(3, 5));
+
+        // UTC-SLS is unchanged outside of a smear, and stretched within one
+        assert_eq!(
+            (1_483_227_799, 5),
+            TimeScale::UtcSls.to_unix(1_483_227_799, 5)
+        );
+        assert_eq!(
+            (1_483_227_800, 0),
+            TimeScale::UtcSls.to_unix(1_483_227_800, 0)
+        );
+        assert_eq!(
+            (1_483_228_300, 500_000_000),
+            TimeScale::UtcSls.to_unix(1_483_228_300, 0)
+        );
+        assert_eq!(
+            (1_483_228_799, 999_999_999),
+            TimeScale::UtcSls.to_unix(1_483_228_799, 999_999_999)
+        );
+        assert_eq!(
+            (1_483_228_800, 0),
+            TimeScale::UtcSls.to_unix(1_483_228_800, 0)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn now_with_tai_clock() {
+        struct TaiClock;
+
+        impl ClockSource for TaiClock {
+            fn now(&self) -> (u64, u32) {
+                (1_497_624_119 + 37, 812_000_000)
+            }
+
+            fn time_scale(&self) -> TimeScale {
+                TimeScale::Tai
+            }
+        }
+
+        let ts = Timestamp::now_with(TaiClock, context::NoContext);
+
+        assert_eq!((1_497_624_119, 812_000_000), ts.to_unix());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn unix_millis_and_micros() {
+        let ts = Timestamp::from_unix_millis(context::NoContext, 1_497_624_119_812);
+        assert_eq!((1_497_624_119, 812_000_000), ts.to_unix());
+        assert_eq!(1_497_624_119_812, ts.to_unix_millis());
+        assert_eq!(1_497_624_119_812_000, ts.to_unix_micros());
+
+        let ts = Timestamp::from_unix_micros(context::NoContext, 1_497_624_119_812_946);
+        assert_eq!((1_497_624_119, 812_946_000), ts.to_unix());
+        assert_eq!(1_497_624_119_812, ts.to_unix_millis());
+        assert_eq!(1_497_624_119_812_946, ts.to_unix_micros());
+
+        // Sub-microsecond precision is truncated
+        let ts = Timestamp::from_unix(context::NoContext, 1_497_624_119, 812_946_999);
+        assert_eq!(1_497_624_119_812_946, ts.to_unix_micros());
+
+        let ts = Timestamp::from_unix(context::NoContext, u64::MAX, 999_999_999);
+        assert_eq!(u64::MAX, ts.to_unix_millis());
+        assert_eq!(u64::MAX, ts.to_unix_micros());
+
+        let ts = Timestamp::from_unix_millis(context::NoContext, u64::MAX);
+        assert_eq!(u64::MAX, ts.to_unix_millis());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(not(uuid_core_only))]
+    fn parse_rfc3339() {
+        for (input, expected) in [
+            ("1970-01-01T00:00:00Z", (0, 0)),
+            ("2017-06-16T14:41:59Z", (1_497_624_119, 0)),
+            ("2017-06-16t14:41:59.812z", (1_497_624_119, 812_000_000)),
+            ("2017-06-16 14:41:59.000812Z", (1_497_624_119, 812_000)),
+            (
+                "2017-06-16T14:41:59.1234567891Z",
+                (1_497_624_119, 123_456_789),
+            ),
+            ("2017-06-16T04:41:59-10:00", (1_497_624_119, 0)),
+            ("2017-06-17T00:11:59+09:30", (1_497_624_119, 0)),
+            ("2000-02-29T00:00:00Z", (951_782_400, 0)),
+            ("9999-12-31T23:59:59Z", (253_402_300_799, 0)),
+        ] {
+            assert_eq!(
+                expected,
+                Timestamp::parse_rfc3339(input).unwrap().to_unix(),
+                "{}",
+                input
+            );
+        }
+
+        for (input, index) in [
+            ("", 1),
+            ("2017-06-16", 11),
+            ("2017/06/16T14:41:59Z", 5),
+            ("2017-13-16T14:41:59Z", 6),
+            ("2017-02-29T14:41:59Z", 9),
+            ("2017-06-16T24:41:59Z", 12),
+            ("2017-06-16T14:41:60Z", 18),
+            ("2017-06-16T14:41:59.Z", 21),
+            ("2017-06-16T14:41:59", 20),
+            ("2017-06-16T14:41:59+1000", 23),
+            ("2017-06-16T14:41:59Z ", 21),
+        ] {
+            assert_eq!(
+                ErrorKind::InvalidCharacter { index },
+                Timestamp::parse_rfc3339(input).unwrap_err().kind(),
+                "{}",
+                input
+            );
+        }
+
+        assert_eq!(
+            Error(ErrorRepr::TimestampBeforeEpoch),
+            Timestamp::parse_rfc3339("1970-01-01T00:00:00+00:01").unwrap_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "alloc", not(uuid_core_only)))]
+    fn to_rfc3339() {
+        for (expected, seconds, nanos) in [
+            ("1970-01-01T00:00:00Z", 0, 0),
+            ("2017-06-16T14:41:59.812Z", 1_497_624_119, 812_000_000),
+            ("2017-06-16T14:41:59.000812Z", 1_497_624_119, 812_000),
+            ("2017-06-16T14:41:59.123456789Z", 1_497_624_119, 123_456_789),
+            ("2000-02-29T00:00:00Z", 951_782_400, 0),
+            ("10000-01-01T00:00:00Z", 253_402_300_800, 0),
+            // Nanoseconds outside of a single second are carried over
+            ("1970-01-01T00:00:05.294967295Z", 1, u32::MAX),
+        ] {
+            let ts = Timestamp::from_unix(context::NoContext, seconds, nanos);
+
+            assert_eq!(expected, ts.to_rfc3339());
+
+            if nanos < 1_000_000_000 && seconds < 253_402_300_800 {
+                assert_eq!(ts, Timestamp::parse_rfc3339(expected).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn now_with_clock() {
+        struct FixedClock;
+
+        impl ClockSource for FixedClock {
+            fn now(&self) -> (u64, u32) {
+                (1_497_624_119, 1234)
+            }
+        }
+
+        let context = context::Context::new(42);
+
+        let ts = Timestamp::now_with(FixedClock, &context);
+        assert_eq!((1_497_624_119, 1234), ts.to_unix());
+        assert_eq!(42, ts.to_rfc4122().1);
+
+        let ts = Timestamp::now_with(&FixedClock, &context);
+        assert_eq!(43, ts.to_rfc4122().1);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn rollback_context() {
+        let context = context::RollbackContext::new(u16::MAX >> 2);
+
+        // The clock sequence only changes when the clock doesn't move forwards
+        assert_eq!(0, context.generate_sequence(10, 0));
+        assert_eq!(0, context.generate_sequence(10, 100));
+        assert_eq!(1, context.generate_sequence(10, 100));
+        assert_eq!(1, context.generate_sequence(11, 0));
+        assert_eq!(2, context.generate_sequence(10, 199));
+        assert_eq!(2, context.generate_sequence(10, 200));
+
+        // The count wraps at 14 bits
+        let context = context::RollbackContext::new((u16::MAX >> 2) - 1);
+
+        assert_eq!(16382, context.generate_sequence(10, 0));
+        assert_eq!(0, context.generate_sequence(10, 0));
+
+        // Timestamps are compared on their low bits, which wrap
+        let context = context::RollbackContext::new(0);
+
+        assert_eq!(0, context.generate_sequence(1_497_624_119, 0));
+        assert_eq!(0, context.generate_sequence(1_497_624_120, 0));
+        assert_eq!(1, context.generate_sequence(1_497_624_119, 0));
+        assert_eq!(1, context.generate_sequence(u64::MAX, 0));
+        assert_eq!(2, context.generate_sequence(u64::MAX, 0));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn checked_context_overflow() {
+        use context::{CheckedContext, OverflowPolicy};
+
+        let max = u16::MAX >> 2;
+
+        // Every clock sequence can be used once per timestamp
+        let context = CheckedContext::new(max - 1, OverflowPolicy::Error);
+
+        assert_eq!(Ok(max - 1), context.try_generate_sequence(10, 0));
+        for count in 0..max - 1 {
+            assert_eq!(Ok(count), context.try_generate_sequence(10, 0));
+        }
+        assert_eq!(
+            Err(Error(ErrorRepr::ClockSequenceOverflow)),
+            context.try_generate_sequence(10, 99)
+        );
+
+        // A different timestamp, even an earlier one, starts counting again
+        assert_eq!(Ok(max - 1), context.try_generate_sequence(10, 100));
+        assert_eq!(Ok(0), context.try_generate_sequence(9, 0));
+
+        // Wrapping reuses clock sequences
+        let context = CheckedContext::new(0, OverflowPolicy::Wrap);
+
+        for count in 0..max {
+            assert_eq!(count, context.generate_sequence(10, 0));
+        }
+        assert_eq!(0, context.generate_sequence(10, 0));
+        assert_eq!(1, context.generate_sequence(10, 0));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn checked_context_stalls() {
+        use crate::std::cell::Cell;
+        use context::{CheckedContext, OverflowPolicy};
+
+        // A clock that only moves forwards every 20000 reads
+        struct SlowClock(Cell<u32>);
+
+        impl ClockSource for SlowClock {
+            fn now(&self) -> (u64, u32) {
+                let reads = self.0.get();
+                self.0.set(reads + 1);
+
+                (10, reads / 20_000 * 100)
+            }
+        }
+
+        let clock = SlowClock(Cell::new(0));
+        let context = CheckedContext::new(0, OverflowPolicy::StallUntilNextTick);
+
+        let mut last = context.try_now_with(&clock).unwrap();
+        for _ in 0..20_000 {
+            let ts = context.try_now_with(&clock).unwrap();
+
+            assert_ne!(last, ts);
+            last = ts;
+        }
+        assert_eq!((10, 100), last.to_unix());
+
+        // Without stalling, the context gives up instead
+        let clock = SlowClock(Cell::new(0));
+        let context = CheckedContext::new(0, OverflowPolicy::Error);
+
+        for _ in 0..u16::MAX >> 2 {
+            context.try_now_with(&clock).unwrap();
+        }
+        assert!(context.try_now_with(&clock).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn context_from_machine_id_bytes() {
+        let id = b"3d1219c7c4c5404aaa1f6d2a48adfda4";
+
+        let a = context::Context::from_machine_id_bytes(id);
+        let b = context::Context::from_machine_id_bytes(id);
+        let other = context::Context::from_machine_id_bytes(b"7a0c1e2b3d4f5a6b7c8d9e0f1a2b3c4d");
+
+        let seed = a.generate_sequence(0, 0);
+        assert_eq!(seed, b.generate_sequence(0, 0));
+        assert_ne!(seed, other.generate_sequence(0, 0));
+        assert!(seed < u16::MAX >> 2);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    fn context_from_machine_id() {
+        if let Ok(id) = std::fs::read_to_string("/etc/machine-id") {
+            let context = context::Context::from_machine_id().unwrap();
+
+            assert_eq!(
+                context::Context::from_machine_id_bytes(id.trim().as_bytes()).to_state(),
+                context.to_state()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn coarse_clock() {
+        use crate::std::{thread, time::Duration};
+
+        let clock = clock::CoarseClock::new(Duration::from_millis(1));
+
+        let before = Timestamp::now_with(clock::SystemClock, context::NoContext);
+        thread::sleep(Duration::from_millis(20));
+        let coarse = Timestamp::now_with(&clock, context::NoContext);
+        let after = Timestamp::now_with(clock::SystemClock, context::NoContext);
+
+        // The background thread has refreshed the time since the clock started
+        assert!(coarse > before);
+        assert!(coarse <= after);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn sharded_context_node_ids_are_unique_across_threads() {
+        use crate::std::{collections::HashSet, thread};
+
+        let context = context::ShardedContext::new([0x42; 6]);
+
+        let node_ids = (0..8)
+            .map(|_| thread::spawn(move || (context.node_id(), context.node_id())))
+            .collect::<crate::std::vec::Vec<_>>()
+            .into_iter()
+            .map(|handle| {
+                let (first, second) = handle.join().unwrap();
+
+                // A thread always gets the same node ID
+                assert_eq!(first, second);
+                assert!(first.is_multicast());
+                assert_eq!(&[0x43, 0x42, 0x42], &first.as_bytes()[..3]);
+
+                first
+            })
+            .collect::<HashSet<_>>();
+
+        assert_eq!(8, node_ids.len());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn striped_context_is_unique_across_threads() {
+        use crate::{
+            std::{sync::Arc, thread, vec::Vec},
+            StripedContext,
+        };
+
+        let context = Arc::new(StripedContext::new(u16::MAX - 10));
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let context = context.clone();
+
+                thread::spawn(move || {
+                    (0..200)
+                        .map(|_| context.generate_sequence(0, 0))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut sequences: Vec<u16> = threads
+            .into_iter()
+            .flat_map(|thread| thread.join().unwrap())
+            .collect();
+
+        assert!(sequences.iter().all(|seq| *seq <= u16::MAX >> 2));
+
+        sequences.sort_unstable();
+        sequences.dedup();
+        assert_eq!(sequences.len(), 8 * 200);
+    }
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn thread_local_context_counts_per_thread() {
+        use crate::{
+            std::{thread, vec::Vec},
+            ThreadLocalContext,
+        };
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                thread::spawn(|| {
+                    (0..200)
+                        .map(|_| ThreadLocalContext.generate_sequence(0, 0))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            let sequences = thread.join().unwrap();
+
+            assert!(sequences.iter().all(|seq| *seq < u16::MAX >> 2));
+
+            // Each thread's sequences increment without gaps, apart from wrapping
+            let steps = sequences
+                .windows(2)
+                .filter(|pair| pair[1] != (pair[0] + 1) % (u16::MAX >> 2))
+                .count();
+            assert!(steps <= 1);
+        }
     }
 }
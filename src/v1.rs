@@ -3,7 +3,7 @@
 //! This module is soft-deprecated. Instead of using the `Context` type re-exported here,
 //! use the one from the crate root.
 
-use crate::{Builder, Uuid};
+use crate::{Builder, NodeId, Uuid};
 
 #[deprecated(note = "use types from the crate root instead")]
 pub use crate::{timestamp::context::Context, Timestamp};
@@ -17,14 +17,61 @@ impl Uuid {
     /// as the source timestamp.
     ///
     /// Note that usage of this method requires the `v1`, `std`, and `rng` features of this crate
-    /// to be enabled.
-    #[cfg(all(feature = "std", feature = "rng"))]
-    pub fn now_v1(node_id: &[u8; 6]) -> Self {
+    /// to be enabled. On targets without the standard library, the `custom-clock` feature can
+    /// be enabled instead of `std`.
+    #[cfg(all(any(feature = "std", feature = "custom-clock"), feature = "rng"))]
+    pub fn now_v1(node_id: impl Into<NodeId>) -> Self {
         let ts = Timestamp::now(crate::timestamp::context::shared_context());
 
         Self::new_v1(ts, node_id)
     }
 
+    /// Create a new version 1 UUID using the given Unix timestamp and node ID.
+    ///
+    /// This method is a convenient alternative to [`Uuid::new_v1`] that uses a
+    /// clock sequence from the context shared by the whole process, so one doesn't
+    /// need to be passed around. See [`Context::shared`](crate::Context::shared).
+    ///
+    /// Note that usage of this method requires the `v1`, `std`, and `rng` features of this crate
+    /// to be enabled. On targets without the standard library, the `custom-clock` feature can
+    /// be enabled instead of `std`.
+    #[cfg(all(any(feature = "std", feature = "custom-clock"), feature = "rng"))]
+    pub fn new_v1_from_unix(seconds: u64, nanos: u32, node_id: impl Into<NodeId>) -> Self {
+        let ts = Timestamp::from_unix(crate::timestamp::context::shared_context(), seconds, nanos);
+
+        Self::new_v1(ts, node_id)
+    }
+
+    /// Create a new version 1 UUID using the given system time and node ID.
+    ///
+    /// This method is a convenient alternative to [`Uuid::new_v1`] for times that
+    /// come from somewhere else, like the modification time of a file. Like
+    /// [`Uuid::new_v1_from_unix`], it uses a clock sequence from the context shared
+    /// by the whole process. Times before the Unix epoch are treated as the epoch.
+    ///
+    /// Note that usage of this method requires the `v1`, `std`, and `rng` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// # use uuid::Uuid;
+    /// let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_497_624_119);
+    ///
+    /// let uuid = Uuid::new_v1_at(modified, &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!((1_497_624_119, 0), uuid.get_timestamp().unwrap().to_unix());
+    /// ```
+    #[cfg(all(feature = "std", feature = "rng"))]
+    pub fn new_v1_at(time: std::time::SystemTime, node_id: impl Into<NodeId>) -> Self {
+        let dur = time
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self::new_v1_from_unix(dur.as_secs(), dur.subsec_nanos(), node_id)
+    }
+
     /// Create a new version 1 UUID using the given timestamp and node ID.
     ///
     /// Also see [`Uuid::now_v1`] for a convenient way to generate version 1
@@ -42,6 +89,8 @@ impl Uuid {
     ///    purpose. However you can create your own [`ClockSequence`]
     ///    implementation, if [`Context`] does not meet your needs).
     ///
+    /// The node ID can be a [`NodeId`] or its 6 bytes.
+    ///
     /// Note that usage of this method requires the `v1` feature of this crate
     /// to be enabled.
     ///
@@ -88,10 +137,10 @@ impl Uuid {
     /// [`Timestamp`]: v1/struct.Timestamp.html
     /// [`ClockSequence`]: v1/trait.ClockSequence.html
     /// [`Context`]: v1/struct.Context.html
-    pub fn new_v1(ts: Timestamp, node_id: &[u8; 6]) -> Self {
+    pub fn new_v1(ts: Timestamp, node_id: impl Into<NodeId>) -> Self {
         let (ticks, counter) = ts.to_rfc4122();
 
-        Builder::from_rfc4122_timestamp(ticks, counter, node_id).into_uuid()
+        Builder::from_rfc4122_timestamp(ticks, counter, node_id.into().as_bytes()).into_uuid()
     }
 }
 
@@ -122,7 +171,7 @@ mod tests {
         let node = [1, 2, 3, 4, 5, 6];
         let context = Context::new(0);
 
-        let uuid = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), &node);
+        let uuid = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), node);
 
         assert_eq!(uuid.get_version(), Some(Version::Mac));
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
@@ -157,12 +206,68 @@ mod tests {
     fn test_now() {
         let node = [1, 2, 3, 4, 5, 6];
 
-        let uuid = Uuid::now_v1(&node);
+        let uuid = Uuid::now_v1(node);
 
         assert_eq!(uuid.get_version(), Some(Version::Mac));
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_new_from_unix() {
+        let time: u64 = 1_496_854_535;
+        let time_fraction: u32 = 812_946_000;
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let uuid1 = Uuid::new_v1_from_unix(time, time_fraction, node);
+        let uuid2 = Uuid::new_v1_from_unix(time, time_fraction, node);
+
+        assert_eq!(uuid1.get_version(), Some(Version::Mac));
+        assert_eq!(
+            uuid1.get_timestamp().unwrap().to_unix(),
+            uuid2.get_timestamp().unwrap().to_unix()
+        );
+        assert_ne!(uuid1, uuid2);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_new_at() {
+        use std::time::{Duration, SystemTime};
+
+        let node = [1, 2, 3, 4, 5, 6];
+        let time = SystemTime::UNIX_EPOCH + Duration::new(1_496_854_535, 812_946_000);
+
+        let uuid = Uuid::new_v1_at(time, node);
+
+        assert_eq!(uuid.get_version(), Some(Version::Mac));
+        assert_eq!(
+            (1_496_854_535, 812_946_000),
+            uuid.get_timestamp().unwrap().to_unix()
+        );
+
+        // Times before the Unix epoch saturate
+        let uuid = Uuid::new_v1_at(SystemTime::UNIX_EPOCH - Duration::from_secs(1), node);
+
+        assert_eq!((0, 0), uuid.get_timestamp().unwrap().to_unix());
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -180,19 +285,19 @@ mod tests {
         // This context will wrap
         let context = Context::new((u16::MAX >> 2) - 1);
 
-        let uuid1 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), &node);
+        let uuid1 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), node);
 
         let time: u64 = 1_496_854_536;
 
-        let uuid2 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), &node);
+        let uuid2 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), node);
 
         assert_eq!(uuid1.get_timestamp().unwrap().to_rfc4122().1, 16382);
         assert_eq!(uuid2.get_timestamp().unwrap().to_rfc4122().1, 0);
 
         let time = 1_496_854_535;
 
-        let uuid3 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), &node);
-        let uuid4 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), &node);
+        let uuid3 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), node);
+        let uuid4 = Uuid::new_v1(Timestamp::from_unix(&context, time, time_fraction), node);
 
         assert_eq!(uuid3.get_timestamp().unwrap().to_rfc4122().1, 1);
         assert_eq!(uuid4.get_timestamp().unwrap().to_rfc4122().1, 2);
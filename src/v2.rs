@@ -0,0 +1,116 @@
+//! The implementation for Version 2 UUIDs.
+
+use crate::{Builder, Domain, Timestamp, Uuid};
+
+impl Uuid {
+    /// Create a new version 2 DCE Security UUID using a domain, local ID,
+    /// timestamp, and node ID.
+    ///
+    /// Version 2 UUIDs are like version 1 UUIDs, except that the low 32 bits
+    /// of the timestamp are replaced with a local ID, such as a POSIX user or
+    /// group ID, and the low 8 bits of the clock sequence are replaced with
+    /// the [`Domain`] of that ID. This means only one version 2 UUID can be
+    /// generated for each local ID roughly every 7 minutes with the same
+    /// node ID and clock sequence, so they're not suitable as general
+    /// unique identifiers.
+    ///
+    /// Note that usage of this method requires the `v2` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Domain, Timestamp, Uuid, Version};
+    /// let ts = Timestamp::from_rfc4122(0x1e7_52a1_f3b4_9a52, 42);
+    ///
+    /// let uuid = Uuid::new_v2(Domain::Person, 1000, ts, &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(Some(Version::Dce), uuid.get_version());
+    /// assert_eq!(Some(Domain::Person), uuid.get_domain());
+    /// assert_eq!(Some(1000), uuid.get_local_id());
+    /// assert_eq!(
+    ///     "000003e8-52a1-21e7-aa00-010203040506",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [DCE 1.1: Authentication and Security Services](https://pubs.opengroup.org/onlinepubs/9696989899/chap5.htm#tagcjh_08_02_01_01)
+    pub fn new_v2(domain: Domain, local_id: u32, ts: Timestamp, node_id: &[u8; 6]) -> Self {
+        let (ticks, counter) = ts.to_rfc4122();
+
+        Builder::from_dce_timestamp(domain, local_id, ticks, counter & 0x3F, node_id).into_uuid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Variant, Version};
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new() {
+        let node_id = [1, 2, 3, 4, 5, 6];
+        let ts = Timestamp::from_rfc4122(0x1e7_52a1_f3b4_9a52, 0x3FFF);
+
+        let uuid = Uuid::new_v2(Domain::Group, 0xfffe, ts, &node_id);
+
+        assert_eq!(Some(Version::Dce), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(Some(Domain::Group), uuid.get_domain());
+        assert_eq!(Some(0xfffe), uuid.get_local_id());
+
+        assert_eq!(
+            Uuid::from_u128(0x0000fffe_52a1_21e7_bf01_010203040506),
+            uuid
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_dce_timestamp_range() {
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        assert!(
+            Builder::from_dce_timestamp(Domain::Org, 0, 1 << 60, 0, &node_id)
+                .try_build()
+                .is_err()
+        );
+        assert!(
+            Builder::from_dce_timestamp(Domain::Org, 0, 0, 1 << 6, &node_id)
+                .try_build()
+                .is_err()
+        );
+
+        let uuid = Builder::from_dce_timestamp(Domain::Org, 0, 0, 0x3F, &node_id)
+            .try_build()
+            .unwrap();
+
+        assert_eq!(Some(Domain::Org), uuid.get_domain());
+        assert_eq!(None, Uuid::nil().get_domain());
+        assert_eq!(None, Uuid::nil().get_local_id());
+    }
+}
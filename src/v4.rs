@@ -1,14 +1,21 @@
-use crate::Uuid;
+use crate::{Builder, Uuid};
+
+#[cfg(feature = "v4")]
+use crate::Timestamp;
 
 impl Uuid {
     /// Creates a random UUID.
     ///
     /// This uses the [`getrandom`] crate to utilise the operating system's RNG
-    /// as the source of random numbers. If you'd like to use a custom
-    /// generator, don't use this method: generate random bytes using your
-    /// custom generator and pass them to the
+    /// as the source of random numbers. Unless the `fast-rng` or `buffered-rng`
+    /// features are enabled, the 16 bytes of each UUID are filled by a single
+    /// call to `getrandom`, without any userspace generator in between. If
+    /// you'd like to use a custom generator, don't use this method: generate
+    /// random bytes using your custom generator and pass them to the
     /// [`uuid::Builder::from_random_bytes`][from_random_bytes] function
-    /// instead.
+    /// instead. When the `custom-generator` feature is enabled, UUIDs come
+    /// from the source passed to [`set_generator`](crate::set_generator)
+    /// instead, if there is one.
     ///
     /// Note that usage of this method requires the `v4` feature of this crate
     /// to be enabled.
@@ -30,9 +37,163 @@ impl Uuid {
     ///
     /// [`getrandom`]: https://crates.io/crates/getrandom
     /// [from_random_bytes]: struct.Builder.html#method.from_random_bytes
+    #[cfg(feature = "v4")]
     pub fn new_v4() -> Uuid {
+        #[cfg(feature = "custom-generator")]
+        {
+            if let Some(uuid) = crate::generator::generate(crate::Version::Random) {
+                return uuid;
+            }
+        }
+
         crate::Builder::from_random_bytes(crate::rng::bytes()).into_uuid()
     }
+
+    /// Fills a slice with random UUIDs.
+    ///
+    /// This is like calling [`Uuid::new_v4`] for each UUID in the slice, but
+    /// the random bytes for many UUIDs are requested from the operating
+    /// system at once, instead of in one call per UUID. This makes it much
+    /// cheaper for bulk jobs, like seeding a database with millions of rows.
+    /// The slice is filled in blocks, so this method doesn't allocate.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let mut uuids = [Uuid::nil(); 1024];
+    ///
+    /// Uuid::fill_v4(&mut uuids);
+    ///
+    /// assert!(uuids.iter().all(|uuid| uuid.get_version() == Some(Version::Random)));
+    /// ```
+    #[cfg(feature = "v4")]
+    pub fn fill_v4(uuids: &mut [Uuid]) {
+        #[cfg(feature = "custom-generator")]
+        {
+            if crate::generator::is_set() {
+                for uuid in uuids {
+                    *uuid = Self::new_v4();
+                }
+
+                return;
+            }
+        }
+
+        // The random bytes for this many UUIDs are requested at once
+        const BLOCK: usize = 256;
+
+        let mut bytes = [0; BLOCK * 16];
+
+        for block in uuids.chunks_mut(BLOCK) {
+            let bytes = &mut bytes[..block.len() * 16];
+            crate::rng::fill(bytes);
+
+            for (uuid, chunk) in block.iter_mut().zip(bytes.chunks_exact(16)) {
+                let mut random_bytes = [0; 16];
+                random_bytes.copy_from_slice(chunk);
+
+                *uuid = Builder::from_random_bytes(random_bytes).into_uuid();
+            }
+        }
+    }
+
+    /// Creates a vector of `n` random UUIDs.
+    ///
+    /// This is a convenient alternative to [`Uuid::fill_v4`] that allocates
+    /// the UUIDs.
+    ///
+    /// Note that usage of this method requires the `v4` and `alloc` features
+    /// of this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuids = Uuid::new_v4_many(1024);
+    ///
+    /// assert_eq!(1024, uuids.len());
+    /// ```
+    #[cfg(all(feature = "v4", feature = "alloc"))]
+    pub fn new_v4_many(n: usize) -> alloc::vec::Vec<Uuid> {
+        let mut uuids = alloc::vec![Uuid::nil(); n];
+        Self::fill_v4(&mut uuids);
+
+        uuids
+    }
+
+    /// Creates a random UUID using the given random number generator.
+    ///
+    /// This is like [`Uuid::new_v4`], but draws its random bytes from `rng`
+    /// instead of the operating system, so it can be used with seeded or
+    /// hardware-backed generators. The crate's own source of randomness
+    /// isn't used at all. The version and variant bits are set on the
+    /// random bytes, so `rng` is responsible for the rest of the UUID being
+    /// unpredictable.
+    ///
+    /// Note that usage of this method requires the `rand` feature of this
+    /// crate to be enabled. The `v4` feature isn't needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # let mut rng = rand::rngs::mock::StepRng::new(0, 1);
+    /// let uuid = Uuid::new_v4_with(&mut rng);
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn new_v4_with<R: rand::RngCore + ?Sized>(rng: &mut R) -> Uuid {
+        let mut bytes = [0; 16];
+        rng.fill_bytes(&mut bytes);
+
+        Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    /// Creates a sequential COMB UUID for SQL Server using a timestamp and
+    /// random bytes.
+    ///
+    /// SQL Server orders `uniqueidentifier` values by their last 6 bytes
+    /// first, so random UUIDs used as clustered keys are inserted all over
+    /// the index. A COMB UUID puts the number of milliseconds since the
+    /// Unix epoch in those bytes instead, so values created later sort
+    /// later and new rows are appended to the end of the index. The rest of
+    /// the UUID is random, and it's otherwise a valid version 4 UUID.
+    ///
+    /// Note that this is a different layout to version 7 UUIDs, which put
+    /// the timestamp in their first bytes. COMB UUIDs only sort by time
+    /// using SQL Server's ordering, and version 7 UUIDs only sort by time
+    /// using the byte ordering most other databases use.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext, Version};
+    /// let ts = Timestamp::from_unix(NoContext, 1_497_624_119, 812_946_000);
+    ///
+    /// let uuid = Uuid::new_comb(ts);
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert!(uuid.hyphenated().to_string().ends_with("-015cb15a8a04"));
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [The Cost of GUIDs as Primary Keys](https://www.informit.com/articles/article.aspx?p=25862)
+    #[cfg(feature = "v4")]
+    pub fn new_comb(ts: Timestamp) -> Uuid {
+        let mut random_bytes = [0; 10];
+        random_bytes.copy_from_slice(&crate::rng::bytes()[..10]);
+
+        Builder::from_comb_timestamp_millis(ts.to_unix_millis(), &random_bytes).into_uuid()
+    }
 }
 
 #[cfg(test)]
@@ -56,6 +217,7 @@ mod tests {
         ),
         wasm_bindgen_test
     )]
+    #[cfg(feature = "v4")]
     fn test_new() {
         let uuid = Uuid::new_v4();
 
@@ -72,10 +234,105 @@ mod tests {
         ),
         wasm_bindgen_test
     )]
+    #[cfg(feature = "v4")]
     fn test_get_version() {
         let uuid = Uuid::new_v4();
 
         assert_eq!(uuid.get_version(), Some(Version::Random));
         assert_eq!(uuid.get_version_num(), 4)
     }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "v4", feature = "std"))]
+    fn test_fill() {
+        use crate::std::collections::HashSet;
+
+        // Enough UUIDs to need more than one block, with a partial one at the end
+        let uuids = Uuid::new_v4_many(1000);
+        assert_eq!(1000, uuids.len());
+
+        let mut more = [Uuid::nil(); 3];
+        Uuid::fill_v4(&mut more);
+
+        let unique = uuids.iter().chain(&more).collect::<HashSet<_>>();
+        assert_eq!(1003, unique.len());
+
+        for uuid in uuids.iter().chain(&more) {
+            assert_eq!(Some(Version::Random), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+        }
+
+        Uuid::fill_v4(&mut []);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "v4")]
+    fn test_new_comb() {
+        use crate::{NoContext, Timestamp};
+
+        let earlier = Uuid::new_comb(Timestamp::from_unix(NoContext, 1_497_624_119, 812_946_000));
+        let later = Uuid::new_comb(Timestamp::from_unix(NoContext, 1_497_624_119, 813_000_000));
+
+        for uuid in &[earlier, later] {
+            assert_eq!(Some(Version::Random), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+        }
+
+        assert_eq!(
+            [0x01, 0x5c, 0xb1, 0x5a, 0x8a, 0x04],
+            earlier.as_bytes()[10..]
+        );
+        assert_eq!([0x01, 0x5c, 0xb1, 0x5a, 0x8a, 0x05], later.as_bytes()[10..]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "rand")]
+    fn test_new_with() {
+        use rand::rngs::mock::StepRng;
+
+        let uuid = Uuid::new_v4_with(&mut StepRng::new(0, 0));
+
+        assert_eq!(Some(Version::Random), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(Uuid::from_u128(0x00000000_0000_4000_8000_000000000000), uuid);
+
+        // The same seed produces the same UUIDs
+        let mut a = StepRng::new(42, 7);
+        let mut b = StepRng::new(42, 7);
+
+        assert_eq!(Uuid::new_v4_with(&mut a), Uuid::new_v4_with(&mut b));
+        assert_ne!(
+            Uuid::new_v4_with(&mut a),
+            Uuid::new_v4_with(&mut StepRng::new(42, 7))
+        );
+
+        // Trait objects can be used too
+        let rng: &mut dyn rand::RngCore = &mut StepRng::new(42, 7);
+
+        assert_eq!(Some(Version::Random), Uuid::new_v4_with(rng).get_version());
+    }
 }
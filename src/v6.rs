@@ -3,7 +3,7 @@
 //! Note that you need to enable the `v6` Cargo feature
 //! in order to use this module.
 
-use crate::{Builder, Timestamp, Uuid};
+use crate::{Builder, NodeId, Timestamp, Uuid};
 
 impl Uuid {
     /// Create a new version 6 UUID using the current system time and node ID.
@@ -14,17 +14,84 @@ impl Uuid {
     /// as the source timestamp.
     ///
     /// Note that usage of this method requires the `v6`, `std`, and `rng` features of this crate
-    /// to be enabled.
-    #[cfg(all(feature = "std", feature = "rng"))]
-    pub fn now_v6(node_id: &[u8; 6]) -> Self {
+    /// to be enabled. On targets without the standard library, the `custom-clock` feature can
+    /// be enabled instead of `std`.
+    ///
+    /// UUIDs are generated through the context shared by the whole process, so
+    /// UUIDs generated with the same node ID are unique, even when the time hasn't
+    /// changed. See [`Context::shared`](crate::Context::shared).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let node_id = [1, 2, 3, 4, 5, 6];
+    ///
+    /// let first = Uuid::now_v6(&node_id);
+    /// let second = Uuid::now_v6(&node_id);
+    ///
+    /// assert_eq!(Some(Version::SortMac), first.get_version());
+    /// assert_ne!(first, second);
+    /// ```
+    #[cfg(all(any(feature = "std", feature = "custom-clock"), feature = "rng"))]
+    pub fn now_v6(node_id: impl Into<NodeId>) -> Self {
         let ts = Timestamp::now(crate::timestamp::context::shared_context());
 
         Self::new_v6(ts, node_id)
     }
 
+    /// Create a new version 6 UUID using the given Unix timestamp and node ID.
+    ///
+    /// This method is a convenient alternative to [`Uuid::new_v6`] that uses a
+    /// clock sequence from the context shared by the whole process, so one doesn't
+    /// need to be passed around. See [`Context::shared`](crate::Context::shared).
+    ///
+    /// Note that usage of this method requires the `v6`, `std`, and `rng` features of this crate
+    /// to be enabled. On targets without the standard library, the `custom-clock` feature can
+    /// be enabled instead of `std`.
+    #[cfg(all(any(feature = "std", feature = "custom-clock"), feature = "rng"))]
+    pub fn new_v6_from_unix(seconds: u64, nanos: u32, node_id: impl Into<NodeId>) -> Self {
+        let ts = Timestamp::from_unix(crate::timestamp::context::shared_context(), seconds, nanos);
+
+        Self::new_v6(ts, node_id)
+    }
+
+    /// Create a new version 6 UUID using the given system time and node ID.
+    ///
+    /// This method is a convenient alternative to [`Uuid::new_v6`] for times that
+    /// come from somewhere else, like the modification time of a file. Like
+    /// [`Uuid::new_v6_from_unix`], it uses a clock sequence from the context shared
+    /// by the whole process. Times before the Unix epoch are treated as the epoch.
+    ///
+    /// Note that usage of this method requires the `v6`, `std`, and `rng` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// # use uuid::Uuid;
+    /// let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_497_624_119);
+    ///
+    /// let uuid = Uuid::new_v6_at(modified, &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!((1_497_624_119, 0), uuid.get_timestamp().unwrap().to_unix());
+    /// ```
+    #[cfg(all(feature = "std", feature = "rng"))]
+    pub fn new_v6_at(time: std::time::SystemTime, node_id: impl Into<NodeId>) -> Self {
+        let dur = time
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self::new_v6_from_unix(dur.as_secs(), dur.subsec_nanos(), node_id)
+    }
+
     /// Create a new version 6 UUID using the given timestamp and a node ID.
     ///
     /// This is similar to version 1 UUIDs, except that it is lexicographically sortable by timestamp.
+    /// The 60 bit timestamp is stored with its most significant bits first, instead of being split
+    /// into low, middle, and high fields, so comparing the bytes of two UUIDs compares their
+    /// timestamps.
     ///
     /// Also see [`Uuid::now_v6`] for a convenient way to generate version 6
     /// UUIDs using the current system time.
@@ -41,7 +108,7 @@ impl Uuid {
     ///    purpose. However you can create your own [`ClockSequence`]
     ///    implementation, if [`Context`] does not meet your needs).
     ///
-    /// The NodeID must be exactly 6 bytes long.
+    /// The node ID can be a [`NodeId`] or its 6 bytes.
     ///
     /// Note that usage of this method requires the `v6` feature of this crate
     /// to be enabled.
@@ -84,15 +151,15 @@ impl Uuid {
     ///
     /// # References
     ///
-    /// * [Version 6 UUIDs in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-5.1)
+    /// * [UUID Version 6 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.6)
     ///
-    /// [`Timestamp`]: v1/struct.Timestamp.html
-    /// [`ClockSequence`]: v1/trait.ClockSequence.html
-    /// [`Context`]: v1/struct.Context.html
-    pub fn new_v6(ts: Timestamp, node_id: &[u8; 6]) -> Self {
+    /// [`ClockSequence`]: crate::ClockSequence
+    /// [`Context`]: crate::Context
+    pub fn new_v6(ts: Timestamp, node_id: impl Into<NodeId>) -> Self {
         let (ticks, counter) = ts.to_rfc4122();
 
-        Builder::from_sorted_rfc4122_timestamp(ticks, counter, node_id).into_uuid()
+        Builder::from_sorted_rfc4122_timestamp(ticks, counter, node_id.into().as_bytes())
+            .into_uuid()
     }
 }
 
@@ -124,7 +191,7 @@ mod tests {
         let node = [1, 2, 3, 4, 5, 6];
         let context = Context::new(0);
 
-        let uuid = Uuid::new_v6(Timestamp::from_unix(context, time, time_fraction), &node);
+        let uuid = Uuid::new_v6(Timestamp::from_unix(context, time, time_fraction), node);
 
         assert_eq!(uuid.get_version(), Some(Version::SortMac));
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
@@ -146,6 +213,45 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_sortable() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        // Ticks that differ in each part of the timestamp, which are stored
+        // in reverse order in version 1 UUIDs
+        let epoch = 0x01B2_1DD2_1381_4000;
+        let ticks = [
+            epoch,
+            epoch + 0x0000_0000_0000_0001,
+            epoch + 0x0000_0000_0000_1000,
+            epoch + 0x0000_0000_0001_0000,
+            epoch + 0x0000_0001_0000_0000,
+            epoch + 0x0001_0000_0000_0000,
+            0x0FFF_FFFF_FFFF_FFFF,
+        ];
+
+        let uuids = ticks
+            .iter()
+            .map(|ticks| Uuid::new_v6(Timestamp::from_rfc4122(*ticks, 0), node))
+            .collect::<std::vec::Vec<_>>();
+
+        for pair in uuids.windows(2) {
+            assert!(pair[0].as_bytes() < pair[1].as_bytes());
+        }
+
+        for (uuid, ticks) in uuids.iter().zip(ticks.iter()) {
+            assert_eq!(*ticks, uuid.get_timestamp().unwrap().to_rfc4122().0);
+        }
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -159,12 +265,68 @@ mod tests {
     fn test_now() {
         let node = [1, 2, 3, 4, 5, 6];
 
-        let uuid = Uuid::now_v6(&node);
+        let uuid = Uuid::now_v6(node);
 
         assert_eq!(uuid.get_version(), Some(Version::SortMac));
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_new_from_unix() {
+        let time: u64 = 1_496_854_535;
+        let time_fraction: u32 = 812_946_000;
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let uuid1 = Uuid::new_v6_from_unix(time, time_fraction, node);
+        let uuid2 = Uuid::new_v6_from_unix(time, time_fraction, node);
+
+        assert_eq!(uuid1.get_version(), Some(Version::SortMac));
+        assert_eq!(
+            uuid1.get_timestamp().unwrap().to_unix(),
+            uuid2.get_timestamp().unwrap().to_unix()
+        );
+        assert_ne!(uuid1, uuid2);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_new_at() {
+        use std::time::{Duration, SystemTime};
+
+        let node = [1, 2, 3, 4, 5, 6];
+        let time = SystemTime::UNIX_EPOCH + Duration::new(1_496_854_535, 812_946_000);
+
+        let uuid = Uuid::new_v6_at(time, node);
+
+        assert_eq!(uuid.get_version(), Some(Version::SortMac));
+        assert_eq!(
+            (1_496_854_535, 812_946_000),
+            uuid.get_timestamp().unwrap().to_unix()
+        );
+
+        // Times before the Unix epoch saturate
+        let uuid = Uuid::new_v6_at(SystemTime::UNIX_EPOCH - Duration::from_secs(1), node);
+
+        assert_eq!((0, 0), uuid.get_timestamp().unwrap().to_unix());
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -182,19 +344,19 @@ mod tests {
         // This context will wrap
         let context = Context::new((u16::MAX >> 2) - 1);
 
-        let uuid1 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), &node);
+        let uuid1 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), node);
 
         let time: u64 = 1_496_854_536;
 
-        let uuid2 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), &node);
+        let uuid2 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), node);
 
         assert_eq!(uuid1.get_timestamp().unwrap().to_rfc4122().1, 16382);
         assert_eq!(uuid2.get_timestamp().unwrap().to_rfc4122().1, 0);
 
         let time = 1_496_854_535;
 
-        let uuid3 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), &node);
-        let uuid4 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), &node);
+        let uuid3 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), node);
+        let uuid4 = Uuid::new_v6(Timestamp::from_unix(&context, time, time_fraction), node);
 
         assert_eq!(uuid3.get_timestamp().unwrap().counter, 1);
         assert_eq!(uuid4.get_timestamp().unwrap().counter, 2);
@@ -3,20 +3,111 @@
 //! Note that you need to enable the `v7` Cargo feature
 //! in order to use this module.
 
-use crate::{rng, std::convert::TryInto, timestamp::Timestamp, Builder, Uuid};
+use crate::{
+    rng,
+    std::{cmp, convert::TryInto},
+    timestamp::{context::ContextV7, Timestamp},
+    Builder, ClockSource, NoContext, Uuid,
+};
+
+// The latest millisecond timestamp that fits in a version 7 UUID
+const MAX_MILLIS: u64 = 0xFFFF_FFFF_FFFF;
 
 impl Uuid {
     /// Create a new version 7 UUID using the current time value and random bytes.
     ///
     /// This method is a convenient alternative to [`Uuid::new_v7`] that uses the current system time
     /// as the source timestamp.
-    #[cfg(feature = "std")]
+    ///
+    /// UUIDs are generated through a shared [`ContextV7`], so each one sorts
+    /// after the one before it, even within the same millisecond.
+    /// When the `custom-generator` feature is enabled, UUIDs come from the
+    /// source passed to [`set_generator`](crate::set_generator) instead, if
+    /// there is one.
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of this crate
+    /// to be enabled. On targets without the standard library, the `custom-clock` feature can
+    /// be enabled instead of `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let first = Uuid::now_v7();
+    /// let second = Uuid::now_v7();
+    ///
+    /// assert_eq!(Some(Version::SortRand), first.get_version());
+    /// assert!(first < second);
+    /// ```
+    #[cfg(any(
+        feature = "std",
+        all(feature = "uefi", target_os = "uefi"),
+        feature = "custom-clock"
+    ))]
     pub fn now_v7() -> Self {
-        Self::new_v7(Timestamp::now(crate::NoContext))
+        #[cfg(feature = "custom-generator")]
+        {
+            if let Some(uuid) = crate::generator::generate(crate::Version::SortRand) {
+                return uuid;
+            }
+        }
+
+        let ts = Timestamp::now(crate::NoContext);
+
+        #[cfg(feature = "test-util")]
+        {
+            if let Some(uuid) = crate::test_util::with_frozen_context_v7(|context| {
+                Self::new_v7_with_context(ts, context)
+            }) {
+                return uuid;
+            }
+        }
+
+        Self::new_v7_with_context(ts, crate::timestamp::context::shared_context_v7())
+    }
+
+    /// Create a new version 7 UUID using the given system time and random bytes.
+    ///
+    /// This method is a convenient alternative to [`Uuid::new_v7`] for times that
+    /// come from somewhere else, like the modification time of a file. Unlike
+    /// [`Uuid::now_v7`], the time isn't adjusted to keep UUIDs in order, so the
+    /// UUID always has the given time. Times before the Unix epoch are treated
+    /// as the epoch.
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// # use uuid::Uuid;
+    /// let modified = SystemTime::UNIX_EPOCH + Duration::from_millis(1_497_624_119_812);
+    ///
+    /// let uuid = Uuid::new_v7_at(modified);
+    ///
+    /// assert_eq!((1_497_624_119, 812_000_000), uuid.get_timestamp().unwrap().to_unix());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn new_v7_at(time: std::time::SystemTime) -> Self {
+        let dur = time
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self::new_v7(Timestamp::from_unix(
+            crate::NoContext,
+            dur.as_secs(),
+            dur.subsec_nanos(),
+        ))
     }
 
     /// Create a new version 7 UUID using a time value and random bytes.
     ///
+    /// The top 48 bits hold the number of milliseconds since the Unix epoch,
+    /// so UUIDs sort by the time they were created. The rest of the UUID,
+    /// apart from the version and variant, is random. Times that don't fit
+    /// in 48 bits are clamped to the latest time that does.
+    ///
     /// When the `std` feature is enabled, you can also use [`Uuid::now_v7`].
     ///
     /// Note that usage of this method requires the `v7` feature of this crate
@@ -43,20 +134,332 @@ impl Uuid {
     ///
     /// # References
     ///
-    /// * [Version 7 UUIDs in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-5.2)
+    /// * [UUID Version 7 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.7)
     pub fn new_v7(ts: Timestamp) -> Self {
-        let (secs, nanos) = ts.to_unix();
-        let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
+        let millis = ts.to_unix_millis().min(MAX_MILLIS);
 
-        Builder::from_unix_timestamp_millis(millis, &rng::bytes()[..10].try_into().unwrap())
-            .into_uuid()
+        let (counter, counter_bits) = ts.counter();
+
+        Builder::from_unix_timestamp_millis(
+            millis,
+            &counter_and_random_bytes(counter, counter_bits),
+        )
+        .into_uuid()
+    }
+
+    /// Create a new version 7 UUID using a time value, a counter, and random bytes.
+    ///
+    /// The counter comes from the given [`ContextV7`] and fills the 12 bits
+    /// after the version, so UUIDs generated through the same context in
+    /// the same millisecond are strictly increasing. If the counter runs out
+    /// or the time value is earlier than the last one the context saw, the
+    /// timestamp in the UUID will be later than the one given.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::{Uuid, Timestamp, NoContext, ContextV7};
+    /// let context = ContextV7::new();
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// let first = Uuid::new_v7_with_context(ts, &context);
+    /// let second = Uuid::new_v7_with_context(ts, &context);
+    ///
+    /// assert!(first < second);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-6.2)
+    pub fn new_v7_with_context(ts: Timestamp, context: &ContextV7) -> Self {
+        let millis = ts.to_unix_millis().min(MAX_MILLIS);
+
+        let mut random_bytes: [u8; 10] = rng::bytes()[..10].try_into().unwrap();
+
+        let seed = ((random_bytes[0] as u16) << 8) | (random_bytes[1] as u16);
+        let (millis, counter) = context.generate(millis, seed);
+
+        random_bytes[0] = (counter >> 8) as u8;
+        random_bytes[1] = counter as u8;
+
+        Builder::from_unix_timestamp_millis(millis, &random_bytes).into_uuid()
+    }
+
+    /// Fill a slice with new version 7 UUIDs using the current time value and random bytes.
+    ///
+    /// This method is a convenient alternative to [`Uuid::new_v7_batch_with_context`]
+    /// that uses the current system time and the same shared [`ContextV7`] as
+    /// [`Uuid::now_v7`].
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of this crate
+    /// to be enabled. On targets without the standard library, the `custom-clock` feature can
+    /// be enabled instead of `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut batch = [Uuid::nil(); 64];
+    ///
+    /// Uuid::now_v7_batch(&mut batch);
+    ///
+    /// assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    #[cfg(any(
+        feature = "std",
+        all(feature = "uefi", target_os = "uefi"),
+        feature = "custom-clock"
+    ))]
+    pub fn now_v7_batch(uuids: &mut [Uuid]) {
+        #[cfg(feature = "custom-generator")]
+        {
+            if crate::generator::is_set() {
+                for uuid in uuids {
+                    *uuid = Self::now_v7();
+                }
+
+                return;
+            }
+        }
+
+        let ts = Timestamp::now(crate::NoContext);
+
+        #[cfg(feature = "test-util")]
+        {
+            if crate::test_util::with_frozen_context_v7(|context| {
+                Self::new_v7_batch_with_context(ts, context, uuids)
+            })
+            .is_some()
+            {
+                return;
+            }
+        }
+
+        Self::new_v7_batch_with_context(ts, crate::timestamp::context::shared_context_v7(), uuids)
+    }
+
+    /// Fill a slice with new version 7 UUIDs using a time value, a counter, and random bytes.
+    ///
+    /// The counters for the whole batch are taken from the given [`ContextV7`]
+    /// at once, so the UUIDs in the slice are strictly increasing, even if
+    /// other threads are generating UUIDs through the same context at the
+    /// same time. This makes batches suitable for bulk inserts that need a
+    /// stable sort order. Like [`Uuid::new_v7_with_context`], if the counter
+    /// runs out partway through the batch then the timestamp is advanced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::{Uuid, Timestamp, NoContext, ContextV7};
+    /// let context = ContextV7::new();
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// let mut batch = [Uuid::nil(); 16];
+    /// Uuid::new_v7_batch_with_context(ts, &context, &mut batch);
+    ///
+    /// assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+    ///
+    /// // UUIDs generated afterwards sort after the batch
+    /// assert!(batch[15] < Uuid::new_v7_with_context(ts, &context));
+    /// ```
+    pub fn new_v7_batch_with_context(ts: Timestamp, context: &ContextV7, uuids: &mut [Uuid]) {
+        let seed_bytes = rng::bytes();
+        let seed = ((seed_bytes[0] as u16) << 8) | (seed_bytes[1] as u16);
+
+        let batch = context.generate_batch(ts.to_unix_millis().min(MAX_MILLIS), seed, uuids.len());
+
+        for (uuid, (millis, counter)) in uuids.iter_mut().zip(batch) {
+            let mut random_bytes: [u8; 10] = rng::bytes()[..10].try_into().unwrap();
+
+            random_bytes[0] = (counter >> 8) as u8;
+            random_bytes[1] = counter as u8;
+
+            *uuid = Builder::from_unix_timestamp_millis(millis, &random_bytes).into_uuid();
+        }
+    }
+
+    /// Create a new version 7 UUID from a Unix timestamp in milliseconds
+    /// and the supplied random bytes.
+    ///
+    /// This is a lower-level alternative to [`Uuid::new_v7`] for callers that
+    /// already have a millisecond timestamp and their own source of random
+    /// bytes. It avoids converting through a [`Timestamp`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::Uuid;
+    /// let random_bytes = [0x42; 10];
+    ///
+    /// let uuid = Uuid::new_v7_from_millis(1497624119000, &random_bytes);
+    ///
+    /// assert_eq!(
+    ///     "015cb15a-86d8-7242-8242-424242424242",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn new_v7_from_millis(millis: u64, random_bytes: &[u8; 10]) -> Self {
+        Builder::from_unix_timestamp_millis(millis, random_bytes).into_uuid()
+    }
+}
+
+/// A generator for strictly increasing version 7 UUIDs.
+///
+/// A `V7Generator` owns the clock it reads the time from and the
+/// [`ContextV7`] counter its UUIDs are generated through, so every UUID it
+/// returns sorts after the one before it, no matter where it was called
+/// from. If the clock goes backwards, the timestamp of the last UUID is
+/// reused and the counter incremented until the clock catches up again.
+///
+/// A generator doesn't own a random number generator. The rest of each
+/// UUID is filled with random bytes from the same crate-wide source as
+/// `Uuid::new_v4`, which is the operating system's RNG or, with the
+/// `fast-rng` feature, a thread-local CSPRNG. This means the random bits
+/// can't be seeded or replaced for an individual generator.
+///
+/// A generator only holds its clock and an atomic counter, so it can be
+/// shared between threads behind an `Arc` or in a `static`.
+///
+/// # Examples
+///
+/// Generating UUIDs from the system clock:
+///
+/// ```
+/// # use uuid::V7Generator;
+/// let generator = V7Generator::new();
+///
+/// let first = generator.generate();
+/// let second = generator.generate();
+///
+/// assert!(first < second);
+/// ```
+///
+/// Generating UUIDs from a clock that goes backwards:
+///
+/// ```
+/// # use std::cell::Cell;
+/// # use uuid::{ClockSource, V7Generator};
+/// struct RewindingClock(Cell<u64>);
+///
+/// impl ClockSource for RewindingClock {
+///     fn now(&self) -> (u64, u32) {
+///         let seconds = self.0.get();
+///         self.0.set(seconds - 1);
+///
+///         (seconds, 0)
+///     }
+/// }
+///
+/// let generator = V7Generator::with_clock(RewindingClock(Cell::new(1_497_624_119)));
+///
+/// let first = generator.generate();
+/// let second = generator.generate();
+///
+/// assert!(first < second);
+/// ```
+#[derive(Debug, Default)]
+pub struct V7Generator<C> {
+    clock: C,
+    context: ContextV7,
+}
+
+#[cfg(any(
+    feature = "std",
+    all(feature = "uefi", target_os = "uefi"),
+    feature = "custom-clock"
+))]
+impl V7Generator<crate::timestamp::clock::SystemClock> {
+    /// Create a generator that reads the time from the system clock.
+    ///
+    /// Note that usage of this method requires the `std` feature of this
+    /// crate to be enabled. On targets without the standard library, the
+    /// `custom-clock` feature can be enabled instead of `std`.
+    pub const fn new() -> Self {
+        Self::with_clock(crate::timestamp::clock::SystemClock)
+    }
+}
+
+impl<C> V7Generator<C> {
+    /// Create a generator that reads the time from the given clock.
+    pub const fn with_clock(clock: C) -> Self {
+        V7Generator {
+            clock,
+            context: ContextV7::new(),
+        }
+    }
+
+    /// Get the clock the generator reads the time from.
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+}
+
+impl<C: ClockSource> V7Generator<C> {
+    /// Generate a new version 7 UUID.
+    ///
+    /// The UUID sorts after every other UUID previously returned by this
+    /// generator.
+    pub fn generate(&self) -> Uuid {
+        Uuid::new_v7_with_context(Timestamp::now_with(&self.clock, NoContext), &self.context)
+    }
+
+    /// Fill a slice with new version 7 UUIDs.
+    ///
+    /// The UUIDs in the slice are strictly increasing, and sort after every
+    /// other UUID previously returned by this generator.
+    pub fn generate_batch(&self, uuids: &mut [Uuid]) {
+        Uuid::new_v7_batch_with_context(
+            Timestamp::now_with(&self.clock, NoContext),
+            &self.context,
+            uuids,
+        )
+    }
+}
+
+// The bits after the version: 12 in `rand_a`, 2 for the variant, and 62 in `rand_b`
+const COUNTER_AND_RANDOM_BITS: u32 = 76;
+
+// Get the bytes to follow the timestamp in a version 7 UUID
+//
+// The counter fills the most significant bits, skipping over the variant,
+// and the rest are random
+fn counter_and_random_bytes(counter: u128, counter_bits: u8) -> [u8; 10] {
+    let random = u128::from_be_bytes(rng::bytes());
+
+    // The variant takes 2 bits, so at most 74 of the counter can be used
+    let mut counter_bits = cmp::min(counter_bits as u32, COUNTER_AND_RANDOM_BITS - 2);
+    let mut counter = counter & mask(counter_bits);
+
+    // If the counter runs past `rand_a` then shift its low bits around the variant
+    if counter_bits > 12 {
+        let low_bits = counter_bits - 12;
+
+        counter = (counter >> low_bits) << (low_bits + 2) | (counter & mask(low_bits));
+        counter_bits += 2;
+    }
+
+    let shift = COUNTER_AND_RANDOM_BITS - counter_bits;
+    let counter_and_random = (counter << shift)
+        | (random & mask(COUNTER_AND_RANDOM_BITS) & !(mask(counter_bits) << shift));
+
+    counter_and_random.to_be_bytes()[6..].try_into().unwrap()
+}
+
+const fn mask(bits: u32) -> u128 {
+    if bits == 0 {
+        0
+    } else {
+        u128::MAX >> (128 - bits)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{std::string::ToString, NoContext, Variant, Version};
+    use crate::{
+        std::string::ToString, ClockSequence, NoContext, SubMillisecondContext, Variant, Version,
+        WideCounterContext,
+    };
     #[cfg(all(
         target_arch = "wasm32",
         target_vendor = "unknown",
@@ -92,6 +495,90 @@ mod tests {
         assert_eq!(uuid, parsed);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_random() {
+        let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 0);
+
+        let uuid1 = Uuid::new_v7(ts);
+        let uuid2 = Uuid::new_v7(ts);
+
+        assert_eq!(uuid1.as_bytes()[..6], uuid2.as_bytes()[..6]);
+        assert_ne!(uuid1, uuid2);
+
+        // Times past the end of the 48 bit timestamp don't panic
+        let uuid = Uuid::new_v7(Timestamp::from_unix(NoContext, u64::MAX, 999_999_999));
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!([0xff; 6], uuid.as_bytes()[..6]);
+
+        // Including times just past it, which would be zero if truncated
+        let ts = Timestamp::from_unix(NoContext, (1 << 48) / 1_000, 656_000_000);
+        assert_eq!(1 << 48, ts.to_unix_millis());
+
+        let context = ContextV7::new();
+
+        for uuid in [Uuid::new_v7(ts), Uuid::new_v7_with_context(ts, &context)].iter() {
+            assert_eq!([0xff; 6], uuid.as_bytes()[..6]);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "std")]
+    fn test_new_at() {
+        use std::time::{Duration, SystemTime};
+
+        let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_645_557_742_000);
+
+        let uuid = Uuid::new_v7_at(time);
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert!(uuid.hyphenated().to_string().starts_with("017f22e2-79b0-7"));
+
+        // Times before the Unix epoch saturate
+        let uuid = Uuid::new_v7_at(SystemTime::UNIX_EPOCH - Duration::from_secs(1));
+
+        assert_eq!([0; 6], uuid.as_bytes()[..6]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_from_millis() {
+        let millis: u64 = 1645557742000;
+        let random_bytes = [0xff; 10];
+
+        let uuid = Uuid::new_v7_from_millis(millis, &random_bytes);
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert!(uuid.hyphenated().to_string().starts_with("017f22e2-79b0-7"));
+
+        let ts = uuid.get_timestamp().unwrap().to_unix();
+        assert_eq!(ts.0 * 1000 + ts.1 as u64 / 1_000_000, millis);
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -109,6 +596,400 @@ mod tests {
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_with_counter() {
+        struct Counter(u128, usize);
+
+        impl ClockSequence for Counter {
+            type Output = u128;
+
+            fn generate_sequence(&self, _: u64, _: u32) -> u128 {
+                self.0
+            }
+
+            fn usable_bits(&self) -> usize {
+                self.1
+            }
+        }
+
+        let uuid = Uuid::new_v7(Timestamp::from_unix(Counter(0xabc, 12), 1_496_854_535, 0));
+        assert_eq!(0x7abc, uuid.as_fields().2);
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+
+        // Bits above the usable ones are masked off
+        let uuid = Uuid::new_v7(Timestamp::from_unix(Counter(0xfabc, 12), 1_496_854_535, 0));
+        assert_eq!(0x7abc, uuid.as_fields().2);
+
+        // Counters wider than `rand_a` continue after the variant
+        let uuid = Uuid::new_v7(Timestamp::from_unix(
+            Counter(0xabc_def, 24),
+            1_496_854_535,
+            0,
+        ));
+        assert_eq!(0x7abc, uuid.as_fields().2);
+        assert_eq!(
+            [0b1011_0111, 0b1011_1100],
+            [uuid.as_bytes()[8], uuid.as_bytes()[9] & 0b1111_1100]
+        );
+
+        // At most 74 bits are used
+        let uuid = Uuid::new_v7(Timestamp::from_unix(
+            Counter(u128::MAX, 128),
+            1_496_854_535,
+            0,
+        ));
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            0x0000_0000_0000_7fff_bfff_ffff_ffff_ffff,
+            uuid.as_u128() & 0xffff_ffff_ffff_ffff_ffff
+        );
+
+        // Timestamps without a counter are random
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 0);
+        assert_ne!(Uuid::new_v7(ts), Uuid::new_v7(ts));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_sub_millisecond() {
+        let ts = |nanos| Timestamp::from_unix(SubMillisecondContext, 1_496_854_535, nanos);
+
+        let uuid = Uuid::new_v7(ts(812_000_000));
+        assert_eq!(0x7000, uuid.as_fields().2);
+
+        let uuid = Uuid::new_v7(ts(812_500_000));
+        assert_eq!(0x7800, uuid.as_fields().2);
+
+        let uuid = Uuid::new_v7(ts(812_999_999));
+        assert_eq!(0x7fff, uuid.as_fields().2);
+        assert_eq!(
+            ts(812_000_000).to_unix(),
+            uuid.get_timestamp().unwrap().to_unix()
+        );
+
+        // Timestamps a microsecond apart are ordered
+        let mut last = Uuid::new_v7(ts(812_000_000));
+        for micros in 1..1000 {
+            let uuid = Uuid::new_v7(ts(812_000_000 + micros * 1000));
+
+            assert!(uuid > last);
+            last = uuid;
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_wide_counter() {
+        let context = WideCounterContext::default();
+        let ts = |nanos| Timestamp::from_unix(&context, 1_496_854_535, nanos);
+
+        // UUIDs in the same millisecond are strictly increasing
+        let mut last = Uuid::new_v7(ts(812_000_000));
+        for _ in 0..10_000 {
+            let uuid = Uuid::new_v7(ts(812_000_000));
+
+            assert!(uuid > last);
+            last = uuid;
+        }
+
+        // The counter is reseeded in the next millisecond
+        let uuid = Uuid::new_v7(ts(813_000_000));
+        assert!(uuid > last);
+        assert!(context.generate_sequence(1_496_854_535, 814_000_000) < 1 << 41);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_wide_counter_saturates() {
+        let context = WideCounterContext::new(2);
+
+        let first = context.generate_sequence(1_496_854_535, 0);
+        assert!(first <= 1);
+
+        for _ in 0..4 {
+            context.generate_sequence(1_496_854_535, 0);
+        }
+        assert_eq!(3, context.generate_sequence(1_496_854_535, 0));
+
+        // Earlier timestamps keep counting in the latest millisecond
+        assert_eq!(3, context.generate_sequence(1_496_854_534, 0));
+        assert_eq!(2, context.usable_bits());
+        assert_eq!(2, context.counter_bits());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_wide_counter_threads() {
+        use crate::std::{collections::HashSet, sync::Arc, thread, vec::Vec};
+
+        let context = Arc::new(WideCounterContext::new(42));
+
+        let threads = (0..4)
+            .map(|_| {
+                let context = Arc::clone(&context);
+
+                thread::spawn(move || {
+                    (0..1000)
+                        .map(|_| context.generate_sequence(1_496_854_535, 0))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut counts = HashSet::new();
+        for thread in threads {
+            for count in thread.join().unwrap() {
+                assert!(counts.insert(count));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_with_context() {
+        let context = ContextV7::new();
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_000_000);
+
+        let first = Uuid::new_v7_with_context(ts, &context);
+
+        assert_eq!(first.get_version(), Some(Version::SortRand));
+        assert_eq!(first.get_variant(), Variant::RFC4122);
+        assert_eq!(ts.to_unix(), first.get_timestamp().unwrap().to_unix());
+
+        // The counter is seeded with its top bit clear
+        assert_eq!(0, first.as_bytes()[6] & 0x08);
+
+        // Enough UUIDs to run out of counter in the first millisecond
+        let mut last = first;
+        for _ in 0..5000 {
+            let uuid = Uuid::new_v7_with_context(ts, &context);
+
+            assert!(uuid > last);
+            last = uuid;
+        }
+
+        let (secs, nanos) = last.get_timestamp().unwrap().to_unix();
+        assert!((secs, nanos) > ts.to_unix());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_batch_with_context() {
+        let context = ContextV7::new();
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_000_000);
+
+        let before = Uuid::new_v7_with_context(ts, &context);
+
+        // Enough UUIDs to run out of counter partway through the batch
+        let mut batch = [Uuid::nil(); 5000];
+        Uuid::new_v7_batch_with_context(ts, &context, &mut batch);
+
+        assert!(before < batch[0]);
+        assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(batch
+            .iter()
+            .all(|uuid| uuid.get_version() == Some(Version::SortRand)));
+        assert!(batch[4999].get_timestamp().unwrap().to_unix() > ts.to_unix());
+
+        let after = Uuid::new_v7_with_context(ts, &context);
+        assert!(batch[4999] < after);
+
+        // Empty batches don't use up any counters
+        Uuid::new_v7_batch_with_context(ts, &context, &mut []);
+
+        let (millis, counter) = context.generate(0, 0);
+        let after = after.as_bytes();
+        assert_eq!(
+            ((after[6] as u16 & 0xf) << 8 | after[7] as u16) + 1,
+            counter
+        );
+        assert_eq!(after.as_ref()[..6], millis.to_be_bytes()[2..]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "std")]
+    fn test_now_batch() {
+        let mut batch = [Uuid::nil(); 100];
+        Uuid::now_v7_batch(&mut batch);
+
+        assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(batch[99] < Uuid::now_v7());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_with_context_clock_backwards() {
+        let context = ContextV7::new();
+
+        let later = Uuid::new_v7_with_context(
+            Timestamp::from_unix(NoContext, 1_496_854_535, 812_000_000),
+            &context,
+        );
+        let earlier =
+            Uuid::new_v7_with_context(Timestamp::from_unix(NoContext, 1_496_854_534, 0), &context);
+
+        assert!(earlier > later);
+        assert_eq!(
+            later.get_timestamp().unwrap().to_unix(),
+            earlier.get_timestamp().unwrap().to_unix()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "std")]
+    fn test_now_is_monotonic() {
+        let mut last = Uuid::now_v7();
+
+        for _ in 0..1000 {
+            let uuid = Uuid::now_v7();
+
+            assert!(uuid > last);
+            last = uuid;
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_clock_backwards() {
+        struct SteppingClock(crate::std::cell::Cell<u64>);
+
+        impl ClockSource for SteppingClock {
+            // Jumps forwards and backwards by a second each time it's read
+            fn now(&self) -> (u64, u32) {
+                let seconds = self.0.get();
+                self.0.set(seconds ^ 1);
+
+                (seconds, 0)
+            }
+        }
+
+        let generator =
+            V7Generator::with_clock(SteppingClock(crate::std::cell::Cell::new(1_496_854_535)));
+
+        let mut last = generator.generate();
+
+        for _ in 0..100 {
+            let uuid = generator.generate();
+
+            assert!(uuid > last);
+            last = uuid;
+        }
+
+        let mut batch = [Uuid::nil(); 16];
+        generator.generate_batch(&mut batch);
+
+        assert!(batch[0] > last);
+        assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Time never goes backwards, and is frozen while the clock is behind
+        assert_eq!(
+            (1_496_854_535, 0),
+            batch[15].get_timestamp().unwrap().to_unix()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_generator_shared() {
+        use crate::std::{sync::Arc, thread, vec::Vec};
+
+        let generator = Arc::new(V7Generator::new());
+
+        let threads = (0..4)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+
+                thread::spawn(move || (0..1000).map(|_| generator.generate()).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+
+        let mut all = Vec::new();
+        for thread in threads {
+            let uuids = thread.join().unwrap();
+
+            assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+            all.extend(uuids);
+        }
+
+        all.sort();
+        all.dedup();
+        assert_eq!(4000, all.len());
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1,4 +1,7 @@
-use crate::{Builder, Uuid};
+use crate::{
+    error::{Error, ErrorRepr},
+    Builder, Uuid,
+};
 
 impl Uuid {
     /// Creates a custom UUID comprised almost entirely of user-supplied bytes.
@@ -22,12 +25,221 @@ impl Uuid {
     /// assert_eq!(Some(Version::Custom), uuid.get_version());
     /// ```
     ///
+    /// Since this method is `const`, custom UUIDs can also be built at compile time:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// const VENDOR_ID: Uuid = Uuid::new_v8(*b"vendor-specific!");
+    ///
+    /// assert_eq!("76656e64-6f72-8d73-b065-636966696321", VENDOR_ID.hyphenated().to_string());
+    /// ```
+    ///
     /// # References
     ///
-    /// * [Version 8 UUIDs in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-5.3)
-    pub fn new_v8(buf: [u8; 16]) -> Uuid {
+    /// * [UUID Version 8 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.8)
+    pub const fn new_v8(buf: [u8; 16]) -> Uuid {
         Builder::from_custom_bytes(buf).into_uuid()
     }
+
+    /// Creates a custom UUID from a name in a namespace, keyed by a secret.
+    ///
+    /// This is like [`Uuid::new_v5`], except the name is hashed with
+    /// HMAC-SHA1 using `key`. The same key, namespace, and name always
+    /// produce the same UUID, but without the key there's no way to tell
+    /// which name a UUID came from by hashing candidate names. That makes
+    /// these UUIDs useful for pseudonymizing identifiers like email
+    /// addresses, as long as the key is kept secret.
+    ///
+    /// The first 16 bytes of the HMAC are used, with the version and
+    /// variant set like [`Uuid::new_v8`].
+    ///
+    /// Note that usage of this method requires the `v8` and `sha1` features
+    /// of this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v8_hmac(b"secret key", &Uuid::NAMESPACE_DNS, b"user@example.com");
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!("d7604f13-09e6-8dda-8585-52a923c69ed2", uuid.hyphenated().to_string());
+    ///
+    /// // A different key produces an unrelated UUID
+    /// assert_ne!(uuid, Uuid::new_v8_hmac(b"other key", &Uuid::NAMESPACE_DNS, b"user@example.com"));
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [HMAC in RFC 2104](https://www.rfc-editor.org/rfc/rfc2104)
+    #[cfg(feature = "sha1")]
+    pub fn new_v8_hmac(key: &[u8], namespace: &Uuid, name: &[u8]) -> Uuid {
+        Builder::from_custom_bytes(crate::sha1::hmac(key, namespace.as_bytes(), name)).into_uuid()
+    }
+}
+
+// The number of bits in a UUID that aren't taken by the version or variant
+const PAYLOAD_BITS: u32 = 122;
+
+/// The layout of the fields in a custom version 8 UUID.
+///
+/// A layout is a list of named fields and their widths in bits. Fields are
+/// packed in order from the most significant bit of the UUID, skipping over
+/// the version and variant, so each field can use any of the 122 bits that
+/// are left. Any bits after the last field are zero. The number of values
+/// passed to [`encode`](V8Layout::encode) and returned from
+/// [`decode`](V8Layout::decode) is the number of fields, so it's checked at
+/// compile time.
+///
+/// Fields are packed into the payload returned by [`Uuid::get_v8_payload`],
+/// so a layout can also be used to decode payloads produced some other way.
+///
+/// Note that usage of this type requires the `v8` feature of this crate
+/// to be enabled.
+///
+/// # Examples
+///
+/// A layout with a millisecond timestamp, a shard number, and random bits:
+///
+/// ```
+/// # use uuid::{V8Layout, Version};
+/// const LAYOUT: V8Layout<3> = V8Layout::new([("timestamp", 48), ("shard", 10), ("random", 60)]);
+///
+/// let uuid = LAYOUT.encode([1_645_557_742_000, 42, 0x0fed_cba9_8765_4321])?;
+///
+/// assert_eq!(Some(Version::Custom), uuid.get_version());
+/// assert_eq!("017f22e2-79b0-80ab-bedc-ba9876543210", uuid.hyphenated().to_string());
+///
+/// assert_eq!(Some([1_645_557_742_000, 42, 0x0fed_cba9_8765_4321]), LAYOUT.decode(&uuid));
+///
+/// // Values that don't fit in their field are rejected
+/// assert!(LAYOUT.encode([0, 1 << 10, 0]).is_err());
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V8Layout<const N: usize> {
+    fields: [(&'static str, u32); N],
+}
+
+impl<const N: usize> V8Layout<N> {
+    /// Create a layout from the names and widths in bits of its fields.
+    ///
+    /// The widths can add up to at most 122 bits. If they add up to more
+    /// then [`V8Layout::encode`] will return an error and
+    /// [`V8Layout::decode`] will return `None`.
+    pub const fn new(fields: [(&'static str, u32); N]) -> Self {
+        V8Layout { fields }
+    }
+
+    /// Get the names and widths in bits of the fields in this layout.
+    pub const fn fields(&self) -> &[(&'static str, u32); N] {
+        &self.fields
+    }
+
+    /// Pack the values of each field into a version 8 UUID.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if a value has more bits than its
+    /// field, or if the layout has more than 122 bits.
+    pub const fn encode(&self, values: [u128; N]) -> Result<Uuid, Error> {
+        let bits = match self.bits() {
+            Some(bits) => bits,
+            None => {
+                return Err(Error(ErrorRepr::Range {
+                    field: "layout",
+                    bits: PAYLOAD_BITS,
+                }))
+            }
+        };
+
+        let mut payload = 0u128;
+        let mut i = 0;
+
+        while i < N {
+            let (field, field_bits) = self.fields[i];
+
+            if values[i] & !mask(field_bits) != 0 {
+                return Err(Error(ErrorRepr::Range {
+                    field,
+                    bits: field_bits,
+                }));
+            }
+
+            // `payload` has at most 122 bits so far, so a 0-bit shift is the only edge case
+            payload = if field_bits == 0 {
+                payload
+            } else {
+                (payload << field_bits) | values[i]
+            };
+
+            i += 1;
+        }
+
+        if bits < PAYLOAD_BITS {
+            payload <<= PAYLOAD_BITS - bits;
+        }
+
+        Ok(Builder::from_v8_payload(payload).into_uuid())
+    }
+
+    /// Unpack the values of each field from a version 8 UUID.
+    ///
+    /// Returns `None` if the UUID isn't a version 8 UUID, or if the layout
+    /// has more than 122 bits. The UUID isn't otherwise checked against the
+    /// layout, so any version 8 UUID can be decoded.
+    pub const fn decode(&self, uuid: &Uuid) -> Option<[u128; N]> {
+        if self.bits().is_none() {
+            return None;
+        }
+
+        let payload = match uuid.get_v8_payload() {
+            Some(payload) => payload,
+            None => return None,
+        };
+
+        let mut values = [0; N];
+        let mut shift = PAYLOAD_BITS;
+        let mut i = 0;
+
+        while i < N {
+            let (_, field_bits) = self.fields[i];
+
+            shift -= field_bits;
+            values[i] = (payload >> shift) & mask(field_bits);
+
+            i += 1;
+        }
+
+        Some(values)
+    }
+
+    // The total number of bits in the layout, if it fits in a UUID
+    const fn bits(&self) -> Option<u32> {
+        let mut bits = 0u32;
+        let mut i = 0;
+
+        while i < N {
+            bits = bits.saturating_add(self.fields[i].1);
+            i += 1;
+        }
+
+        if bits <= PAYLOAD_BITS {
+            Some(bits)
+        } else {
+            None
+        }
+    }
+}
+
+const fn mask(bits: u32) -> u128 {
+    if bits == 0 {
+        0
+    } else if bits >= 128 {
+        u128::MAX
+    } else {
+        u128::MAX >> (128 - bits)
+    }
 }
 
 #[cfg(test)]
@@ -65,4 +277,120 @@ mod tests {
             "0f0e0d0c-0b0a-8908-8706-050403020100"
         );
     }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_layout_round_trip() {
+        const FULL: V8Layout<3> = V8Layout::new([("a", 48), ("b", 12), ("c", 62)]);
+
+        let values = [mask(48), 0, mask(62)];
+        let uuid = FULL.encode(values).unwrap();
+
+        // The fields line up exactly with the bits around the version and variant
+        assert_eq!(
+            Uuid::from_u128(0xffffffff_ffff_8000_bfff_ffffffffffff),
+            uuid
+        );
+        assert_eq!(Some(values), FULL.decode(&uuid));
+
+        const BITS: V8Layout<5> =
+            V8Layout::new([("a", 1), ("b", 0), ("c", 7), ("d", 64), ("e", 3)]);
+
+        let values = [1, 0, 0x55, 0x0123_4567_89ab_cdef, 0b101];
+        let uuid = BITS.encode(values).unwrap();
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(Some(values), BITS.decode(&uuid));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_layout_errors() {
+        let layout = V8Layout::new([("a", 4), ("b", 0)]);
+
+        assert!(layout.encode([0x10, 0]).is_err());
+        assert!(layout.encode([0, 1]).is_err());
+        assert_eq!(None, layout.decode(&Uuid::nil()));
+        assert_eq!(
+            None,
+            layout.decode(&Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8))
+        );
+
+        let too_big = V8Layout::new([("a", 100), ("b", 23)]);
+
+        assert!(too_big.encode([0, 0]).is_err());
+        assert_eq!(None, too_big.decode(&Uuid::new_v8([0; 16])));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_payload_round_trip() {
+        for payload in &[
+            0,
+            1,
+            0x0123_4567_89ab_cdef_0123_4567_89ab_cdef >> 6,
+            mask(122),
+        ] {
+            let uuid = Builder::from_v8_payload(*payload).try_build().unwrap();
+
+            assert_eq!(Some(Version::Custom), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+            assert_eq!(Some(*payload), uuid.get_v8_payload());
+        }
+
+        assert!(Builder::from_v8_payload(1 << 122).try_build().is_err());
+        assert_eq!(None, Uuid::nil().get_v8_payload());
+        assert_eq!(None, Uuid::max().get_v8_payload());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "sha1")]
+    fn test_new_hmac() {
+        let uuid = Uuid::new_v8_hmac(b"secret key", &Uuid::NAMESPACE_DNS, b"user@example.com");
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(
+            "d7604f13-09e6-8dda-8585-52a923c69ed2",
+            uuid.hyphenated().to_string()
+        );
+
+        // Keys longer than the SHA-1 block size are hashed first
+        let uuid = Uuid::new_v8_hmac(&[b'k'; 100], &Uuid::NAMESPACE_DNS, b"user@example.com");
+
+        assert_eq!(
+            "3f307080-bf7e-884e-81a5-bb9a31a97373",
+            uuid.hyphenated().to_string()
+        );
+    }
 }
@@ -0,0 +1,57 @@
+//! Installing a source replaces the default generators for the whole
+//! process, so this runs in its own test binary where no other tests can
+//! observe it.
+
+#![cfg(all(feature = "custom-generator", feature = "v4"))]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use uuid::{Builder, Uuid, UuidSource, Version};
+
+struct Counter(AtomicU64);
+
+impl UuidSource for Counter {
+    fn generate(&self, version: Version) -> Option<Uuid> {
+        match version {
+            Version::Random => {
+                let n = self.0.fetch_add(1, Ordering::Relaxed) as u128;
+
+                Some(Builder::from_random_bytes((n << 64 | n).to_be_bytes()).into_uuid())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn set_generator() {
+    static COUNTER: Counter = Counter(AtomicU64::new(0x0123_4567_89ab_cdef));
+
+    let expected = |n: u128| Builder::from_random_bytes((n << 64 | n).to_be_bytes()).into_uuid();
+
+    uuid::set_generator(&COUNTER);
+
+    let first = Uuid::new_v4();
+    let second = Uuid::new_v4();
+
+    let mut batch = [Uuid::nil(); 2];
+    Uuid::fill_v4(&mut batch);
+
+    uuid::reset_generator();
+
+    assert_eq!(expected(0x0123_4567_89ab_cdef), first);
+    assert_eq!(expected(0x0123_4567_89ab_cdf0), second);
+    assert_eq!(
+        [
+            expected(0x0123_4567_89ab_cdf1),
+            expected(0x0123_4567_89ab_cdf2)
+        ],
+        batch
+    );
+
+    // The default generator is used again after resetting
+    let after = Uuid::new_v4();
+
+    assert_eq!(Some(Version::Random), after.get_version());
+    assert_ne!(expected(0x0123_4567_89ab_cdf3), after);
+}